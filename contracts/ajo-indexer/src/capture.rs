@@ -0,0 +1,34 @@
+//! Capturing a contract's events from the Soroban test environment.
+
+use soroban_sdk::xdr::{ContractEventBody, ContractEventType, ScAddress};
+use soroban_sdk::{Address, Env};
+
+use crate::event::RawEvent;
+
+/// Returns the events `contract` has published in `env` so far, oldest first.
+///
+/// Events published by calls that failed are left out: the host keeps them, but
+/// their state changes were rolled back, so they never reach the ledger.
+pub fn capture_events(env: &Env, contract: &Address) -> Vec<RawEvent> {
+    let ScAddress::Contract(contract_id) = ScAddress::from(contract) else {
+        return Vec::new();
+    };
+
+    // `Events::all` doesn't say which events came from failed calls, so read the host's log
+    let events = env.host().get_events().expect("host events are readable");
+    events
+        .0
+        .into_iter()
+        .filter(|event| !event.failed_call)
+        .filter_map(|event| {
+            let event = event.event;
+            if event.type_ != ContractEventType::Contract
+                || event.contract_id.as_ref() != Some(&contract_id)
+            {
+                return None;
+            }
+            let ContractEventBody::V0(body) = event.body;
+            Some(RawEvent { topics: body.topics.to_vec(), data: body.data })
+        })
+        .collect()
+}
@@ -0,0 +1,206 @@
+//! Decoding raw contract events into typed [`AjoEvent`]s.
+
+use std::fmt;
+
+use soroban_ajo::{
+    AdminEvent, BountySetEvent, CatchUpEvent, CommissionSetEvent, CrankSkippedEvent, CycleEvent,
+    CycleScheduleSetEvent, EmergencyRefundEvent, FlagSetEvent, GroupCancelledEvent,
+    GroupCreatedEvent, GroupRenewedEvent, GroupSourceEvent, InstallmentEvent, InsuranceSetEvent,
+    IntervalsSetEvent, KeeperCrankedEvent, LimitSetEvent, MandateEvent, MemberAmountEvent,
+    MemberEvent, MemberPairEvent, MetadataSetEvent, PayoutShareEvent, PenaltyEvent,
+    PenaltyPolicySetEvent, PenaltyPooledEvent, PenaltyScheduleSetEvent, PrepaidEvent,
+    RefundApprovedEvent, RefundEvent, RefundRejectedEvent, RefundRequestedEvent, RefundVoteEvent,
+    SavingsCycleClosedEvent, SeatSharedEvent, SeatTierSetEvent, SplitsSetEvent, SponsoredEvent,
+    SwapEvent, TargetSavingsSetEvent, TemplateSavedEvent, TokenSetEvent, UpgradeEvent,
+    VaultAmountEvent, VaultSetEvent, VaultWithdrawalEvent, EVENT_SCHEMA_VERSION,
+};
+use soroban_sdk::xdr::ScVal;
+use soroban_sdk::{Env, TryFromVal};
+
+/// A contract event as XDR, the way it is read from RPC or captured in a test.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RawEvent {
+    /// The event's topics: its name and group id.
+    pub topics: Vec<ScVal>,
+
+    /// The event's payload.
+    pub data: ScVal,
+}
+
+/// Why an event couldn't be decoded.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DecodeError {
+    /// The topics aren't a `(name, group_id)` pair.
+    InvalidTopics,
+    /// The contract doesn't emit an event with this name.
+    UnknownEvent(String),
+    /// The payload was written by a newer schema than [`EVENT_SCHEMA_VERSION`].
+    UnsupportedVersion(u32),
+    /// The payload doesn't match the named event's schema.
+    InvalidPayload(String),
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodeError::InvalidTopics => write!(f, "event topics are not (name, group_id)"),
+            DecodeError::UnknownEvent(name) => write!(f, "unknown event `{name}`"),
+            DecodeError::UnsupportedVersion(version) => {
+                write!(f, "event schema version {version} is newer than {EVENT_SCHEMA_VERSION}")
+            }
+            DecodeError::InvalidPayload(name) => write!(f, "invalid payload for event `{name}`"),
+        }
+    }
+}
+
+impl std::error::Error for DecodeError {}
+
+/// Defines [`AjoEvent`] with one variant per event name, and its decoder.
+macro_rules! ajo_events {
+    ($($name:literal => $variant:ident($payload:ty),)*) => {
+        /// A decoded contract event, one variant per event name.
+        ///
+        /// The payloads are the contract's own event structs, bound to the
+        /// [`Env`] they were decoded with.
+        #[derive(Clone, Debug, Eq, PartialEq)]
+        pub enum AjoEvent {
+            $(
+                #[doc = concat!("`", $name, "`")]
+                $variant($payload),
+            )*
+        }
+
+        impl AjoEvent {
+            /// The event's name, as published in its first topic.
+            pub fn name(&self) -> &'static str {
+                match self {
+                    $(AjoEvent::$variant(_) => $name,)*
+                }
+            }
+
+            /// The group the event belongs to, or zero for events not tied to a group.
+            pub fn group_id(&self) -> u64 {
+                match self {
+                    $(AjoEvent::$variant(payload) => payload.group_id,)*
+                }
+            }
+
+            fn decode_payload(env: &Env, name: &str, data: &ScVal) -> Result<Self, DecodeError> {
+                match name {
+                    $(
+                        $name => <$payload>::try_from_val(env, data)
+                            .map(AjoEvent::$variant)
+                            .map_err(|_| DecodeError::InvalidPayload(name.to_string())),
+                    )*
+                    _ => Err(DecodeError::UnknownEvent(name.to_string())),
+                }
+            }
+        }
+    };
+}
+
+ajo_events! {
+    "init" => Initialized(AdminEvent),
+    "upgraded" => Upgraded(UpgradeEvent),
+    "paused" => Paused(AdminEvent),
+    "unpaused" => Unpaused(AdminEvent),
+    "created" => GroupCreated(GroupCreatedEvent),
+    "joined" => MemberJoined(MemberEvent),
+    "seatshare" => SeatShared(SeatSharedEvent),
+    "contrib" => Contributed(MemberAmountEvent),
+    "install" => InstallmentPaid(InstallmentEvent),
+    "late" => LateContribution(PenaltyEvent),
+    "sponsored" => Sponsored(SponsoredEvent),
+    "premium" => PremiumPaid(MemberAmountEvent),
+    "vaultin" => VaultDeposit(VaultAmountEvent),
+    "vaultfail" => VaultDepositFailed(VaultAmountEvent),
+    "prepaid" => Prepaid(PrepaidEvent),
+    "autoon" => AutoContributeEnabled(MandateEvent),
+    "autooff" => AutoContributeRevoked(MemberEvent),
+    "autopull" => AutoContributionPulled(MemberAmountEvent),
+    "autofail" => AutoContributionFailed(MemberAmountEvent),
+    "insclaim" => InsuranceClaimPaid(MemberAmountEvent),
+    "vaultout" => VaultWithdrawal(VaultWithdrawalEvent),
+    "cranked" => KeeperCranked(KeeperCrankedEvent),
+    "skipped" => CrankSkipped(CrankSkippedEvent),
+    "penshare" => PenaltyShared(MemberAmountEvent),
+    "penpooled" => PenaltyPooled(PenaltyPooledEvent),
+    "commpaid" => CommissionPaid(MemberAmountEvent),
+    "payshare" => PayoutShare(PayoutShareEvent),
+    "pendistr" => PenaltyDistributed(PenaltyEvent),
+    "payout" => PayoutExecuted(MemberAmountEvent),
+    "saveclose" => SavingsCycleClosed(SavingsCycleClosedEvent),
+    "savewdraw" => SavingsWithdrawn(PenaltyEvent),
+    "cycle" => CycleAdvanced(CycleEvent),
+    "catchup" => CycleCatchUp(CatchUpEvent),
+    "complete" => GroupCompleted(CycleEvent),
+    "renewed" => GroupRenewed(GroupRenewedEvent),
+    "tokenset" => TokenSet(TokenSetEvent),
+    "seatappr" => SeatTransferApprovalSet(FlagSetEvent),
+    "seatmax" => MaxSeatsSet(LimitSetEvent),
+    "intervals" => IntervalsSet(IntervalsSetEvent),
+    "tiermax" => MaxTierSet(LimitSetEvent),
+    "payees" => RecipientsPerCycleSet(LimitSetEvent),
+    "renewshuf" => RenewalShuffleSet(FlagSetEvent),
+    "insure" => InsurancePremiumSet(InsuranceSetEvent),
+    "vaultset" => VaultSet(VaultSetEvent),
+    "savings" => TargetSavingsSet(TargetSavingsSetEvent),
+    "bountyset" => KeeperBountySet(BountySetEvent),
+    "commset" => CommissionSet(CommissionSetEvent),
+    "penpolicy" => PenaltyPolicySet(PenaltyPolicySetEvent),
+    "pensched" => PenaltyScheduleSet(PenaltyScheduleSetEvent),
+    "cyclesch" => CycleScheduleSet(CycleScheduleSetEvent),
+    "metadata" => MetadataSet(MetadataSetEvent),
+    "seattier" => SeatTierSet(SeatTierSetEvent),
+    "splitset" => PayoutSplitsSet(SplitsSetEvent),
+    "renewopt" => RenewalOptIn(MemberEvent),
+    "template" => TemplateSaved(TemplateSavedEvent),
+    "fromtmpl" => GroupFromTemplate(GroupSourceEvent),
+    "cloned" => GroupCloned(GroupSourceEvent),
+    "seatxfer" => SeatTransferred(MemberPairEvent),
+    "swapprop" => SwapProposed(MemberPairEvent),
+    "swapped" => PositionsSwapped(SwapEvent),
+    "cancel" => GroupCancelled(GroupCancelledEvent),
+    "refreq" => RefundRequested(RefundRequestedEvent),
+    "refvote" => RefundVoted(RefundVoteEvent),
+    "refund" => Refunded(RefundEvent),
+    "refreject" => RefundRejected(RefundRejectedEvent),
+    "refaccept" => RefundApproved(RefundApprovedEvent),
+    "emrefund" => EmergencyRefund(EmergencyRefundEvent),
+}
+
+impl AjoEvent {
+    /// Decodes a raw event.
+    ///
+    /// # Errors
+    /// * `InvalidTopics` - If the topics aren't a `(name, group_id)` pair
+    /// * `UnsupportedVersion` - If the payload comes from a newer schema version
+    /// * `UnknownEvent` - If the contract doesn't emit an event with this name
+    /// * `InvalidPayload` - If the payload doesn't match the event's schema
+    pub fn decode(env: &Env, event: &RawEvent) -> Result<Self, DecodeError> {
+        let name = match event.topics.as_slice() {
+            [ScVal::Symbol(name), ScVal::U64(_)] => name.to_utf8_string_lossy(),
+            _ => return Err(DecodeError::InvalidTopics),
+        };
+
+        // Check the version first: a newer payload may not decode at all
+        if let Some(version) = schema_version(&event.data) {
+            if version > EVENT_SCHEMA_VERSION {
+                return Err(DecodeError::UnsupportedVersion(version));
+            }
+        }
+
+        Self::decode_payload(env, &name, &event.data)
+    }
+}
+
+/// Reads the `version` field of an event payload, if it has one.
+fn schema_version(data: &ScVal) -> Option<u32> {
+    let ScVal::Map(Some(map)) = data else {
+        return None;
+    };
+    map.iter().find_map(|entry| match (&entry.key, &entry.val) {
+        (ScVal::Symbol(key), ScVal::U32(version)) if key.as_slice() == b"version" => Some(*version),
+        _ => None,
+    })
+}
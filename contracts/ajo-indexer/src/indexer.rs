@@ -0,0 +1,475 @@
+//! Replaying decoded events into [`GroupHistory`] state.
+
+use std::collections::BTreeMap;
+
+use soroban_ajo::{GroupCreatedEvent, GroupKind, GroupState, OrganizerCommission, PenaltyPolicy, YieldPolicy};
+use soroban_sdk::Env;
+
+use crate::event::{AjoEvent, DecodeError, RawEvent};
+use crate::state::{
+    strkey, Contribution, CycleSchedule, GroupHistory, IndexedGroup, PenaltySchedule, Payout,
+    Refund, RefundRequest,
+};
+
+/// Rebuilds every group's state from the contract's events.
+///
+/// Events must be fed in the order they were published, from the contract's
+/// first event on, since each one only describes what changed.
+pub struct Indexer {
+    env: Env,
+    groups: BTreeMap<u64, GroupHistory>,
+}
+
+impl Default for Indexer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Indexer {
+    /// Creates an indexer with no groups.
+    pub fn new() -> Self {
+        Indexer { env: Env::default(), groups: BTreeMap::new() }
+    }
+
+    /// The environment events are decoded in.
+    pub fn env(&self) -> &Env {
+        &self.env
+    }
+
+    /// Decodes a raw event and applies it.
+    ///
+    /// # Errors
+    /// Any [`DecodeError`]; the event is not applied.
+    pub fn ingest(&mut self, event: &RawEvent) -> Result<AjoEvent, DecodeError> {
+        let event = AjoEvent::decode(&self.env, event)?;
+        self.apply(&event);
+        Ok(event)
+    }
+
+    /// Decodes and applies a batch of raw events, stopping at the first that fails.
+    ///
+    /// # Errors
+    /// The first [`DecodeError`], with the events before it applied.
+    pub fn ingest_all<'a>(
+        &mut self,
+        events: impl IntoIterator<Item = &'a RawEvent>,
+    ) -> Result<(), DecodeError> {
+        for event in events {
+            self.ingest(event)?;
+        }
+        Ok(())
+    }
+
+    /// Returns a group's history, if it has been created.
+    pub fn group(&self, group_id: u64) -> Option<&GroupHistory> {
+        self.groups.get(&group_id)
+    }
+
+    /// Returns every group seen so far, by id.
+    pub fn groups(&self) -> &BTreeMap<u64, GroupHistory> {
+        &self.groups
+    }
+
+    /// Applies a decoded event.
+    ///
+    /// Events for groups that haven't been created are ignored, as are events that
+    /// don't change group state (premiums, vault movements, keeper cranks, mandates,
+    /// metadata and admin events).
+    pub fn apply(&mut self, event: &AjoEvent) {
+        match event {
+            AjoEvent::GroupCreated(created) => {
+                let group = new_group(created);
+                self.groups.insert(group.id, GroupHistory::new(group));
+            }
+            AjoEvent::GroupRenewed(renewed) => {
+                self.copy_terms(renewed.group_id, renewed.next_group_id, true);
+            }
+            AjoEvent::GroupCloned(cloned) => {
+                self.copy_terms(cloned.source_id, cloned.group_id, false);
+            }
+            _ => {
+                if let Some(history) = self.groups.get_mut(&event.group_id()) {
+                    apply_to_group(history, event);
+                }
+            }
+        }
+    }
+
+    /// Carries a group's terms over to a renewed or cloned successor, which
+    /// `created` has already listed with its members.
+    fn copy_terms(&mut self, source_id: u64, group_id: u64, renewal: bool) {
+        let Some(source) = self.groups.get(&source_id).cloned() else {
+            return;
+        };
+        let Some(history) = self.groups.get_mut(&group_id) else {
+            return;
+        };
+
+        // Each member keeps the tiers of the seats they held, in order
+        let seat_tiers = if renewal {
+            let mut seats: Vec<(String, u32)> = source
+                .group
+                .members
+                .iter()
+                .enumerate()
+                .map(|(seat, member)| (member.clone(), seat_tier(&source.group, seat)))
+                .collect();
+            history
+                .group
+                .members
+                .iter()
+                .map(|member| match seats.iter().position(|(holder, _)| holder == member) {
+                    Some(index) => seats.remove(index).1,
+                    None => 1,
+                })
+                .collect()
+        } else {
+            vec![1; history.group.members.len()]
+        };
+
+        let target = &mut history.group;
+        *target = IndexedGroup {
+            id: target.id,
+            creator: target.creator.clone(),
+            members: target.members.clone(),
+            seat_tiers,
+            current_cycle: 1,
+            payout_index: 0,
+            created_at: target.created_at,
+            cycle_start_time: target.cycle_start_time,
+            is_complete: false,
+            state: GroupState::Active,
+            cycle_schedule: match source.group.cycle_schedule {
+                CycleSchedule::Calendar(_) => CycleSchedule::Rolling,
+                ref schedule => schedule.clone(),
+            },
+            catch_up_end: 0,
+            previous_group_id: renewal.then_some(source_id),
+            next_group_id: None,
+            ..source.group.clone()
+        };
+
+        if renewal {
+            // Penalty records follow opted-in members into the next round
+            for member in &source.renewal_opt_ins {
+                if let Some(record) = source.penalties.get(member) {
+                    history.penalties.insert(member.clone(), record.clone());
+                }
+            }
+            if let Some(source) = self.groups.get_mut(&source_id) {
+                source.group.next_group_id = Some(group_id);
+            }
+        }
+    }
+}
+
+fn new_group(created: &GroupCreatedEvent) -> IndexedGroup {
+    let members: Vec<String> = created.members.iter().map(|member| strkey(&member)).collect();
+    IndexedGroup {
+        id: created.group_id,
+        creator: strkey(&created.creator),
+        contribution_amount: created.contribution_amount,
+        cycle_duration: created.cycle_duration,
+        contribution_interval: 0,
+        max_members: created.max_members,
+        seat_tiers: vec![1; members.len()],
+        members,
+        current_cycle: 1,
+        payout_index: 0,
+        created_at: created.created_at,
+        cycle_start_time: created.created_at,
+        is_complete: false,
+        grace_period: created.grace_period,
+        penalty_rate: created.penalty_rate,
+        state: GroupState::Active,
+        commission: OrganizerCommission::None,
+        penalty_policy: PenaltyPolicy::NextRecipient,
+        penalty_schedule: PenaltySchedule::Flat,
+        cycle_schedule: CycleSchedule::Rolling,
+        catch_up_end: 0,
+        keeper_bounty: 0,
+        token: None,
+        seat_transfer_needs_approval: false,
+        max_seats_per_member: 1,
+        max_contribution_tier: 1,
+        recipients_per_cycle: 1,
+        insurance_premium_bps: 0,
+        vault: None,
+        yield_policy: YieldPolicy::Recipient,
+        kind: GroupKind::Rotating,
+        savings_cycles: 0,
+        early_withdrawal_bps: 0,
+        previous_group_id: None,
+        next_group_id: None,
+        shuffle_on_renewal: false,
+    }
+}
+
+fn seat_tier(group: &IndexedGroup, seat: usize) -> u32 {
+    group.seat_tiers.get(seat).copied().unwrap_or(1)
+}
+
+fn contribution<'a>(history: &'a mut GroupHistory, cycle: u32, member: &str) -> &'a mut Contribution {
+    history.contributions.entry((cycle, member.to_string())).or_default()
+}
+
+fn apply_to_group(history: &mut GroupHistory, event: &AjoEvent) {
+    match event {
+        AjoEvent::MemberJoined(joined) => {
+            history.group.members.push(strkey(&joined.member));
+            history.group.seat_tiers.push(1);
+        }
+        AjoEvent::SeatShared(shared) => {
+            let holders: Vec<String> = shared.holders.iter().map(|holder| strkey(&holder)).collect();
+            history.seat_holders.insert(holders[0].clone(), holders);
+        }
+
+        // Contributions
+        AjoEvent::InstallmentPaid(paid) => {
+            contribution(history, paid.cycle, &strkey(&paid.member)).amount_paid += paid.amount;
+        }
+        AjoEvent::Sponsored(sponsored) => {
+            // The amount is counted by the `install`, `contrib` or `late` that follows
+            let sponsor = strkey(&sponsored.payer);
+            contribution(history, sponsored.cycle, &strkey(&sponsored.member)).sponsor = Some(sponsor);
+        }
+        AjoEvent::Contributed(paid) => {
+            let member = strkey(&paid.member);
+            let record = contribution(history, paid.cycle, &member);
+            record.amount_paid = paid.amount;
+            record.has_paid = true;
+            history.record_punctuality(&member, false, 0);
+        }
+        AjoEvent::LateContribution(paid) => {
+            let member = strkey(&paid.member);
+            let record = contribution(history, paid.cycle, &member);
+            record.amount_paid = paid.amount;
+            record.has_paid = true;
+            record.is_late = true;
+            record.penalty_amount = paid.penalty;
+            *history.penalty_pools.entry(paid.cycle).or_default() += paid.penalty;
+            history.record_punctuality(&member, true, paid.penalty);
+        }
+        AjoEvent::Prepaid(prepaid) => {
+            let member = strkey(&prepaid.member);
+            let cycles = (prepaid.last_cycle - prepaid.first_cycle + 1) as i128;
+            for cycle in prepaid.first_cycle..=prepaid.last_cycle {
+                let record = contribution(history, cycle, &member);
+                record.amount_paid = prepaid.amount / cycles;
+                record.is_prepaid = true;
+            }
+        }
+        AjoEvent::InsuranceClaimPaid(claim) => {
+            let member = strkey(&claim.member);
+            let record = contribution(history, claim.cycle, &member);
+            record.amount_paid += claim.amount;
+            record.has_paid = true;
+            record.is_late = true;
+            history.record_punctuality(&member, true, 0);
+        }
+
+        // Payouts
+        AjoEvent::CommissionPaid(paid) => {
+            *history.commissions.entry(paid.cycle).or_default() += paid.amount;
+        }
+        AjoEvent::PayoutShare(share) => {
+            let member = strkey(&share.member);
+            let payout = pending_payout(history, share.cycle, &member);
+            payout.shares.push((strkey(&share.recipient), share.amount));
+        }
+        AjoEvent::PenaltyDistributed(distributed) => {
+            let member = strkey(&distributed.member);
+            pending_payout(history, distributed.cycle, &member).penalty_bonus = distributed.penalty;
+        }
+        AjoEvent::PayoutExecuted(paid) => {
+            let member = strkey(&paid.member);
+            pending_payout(history, paid.cycle, &member).amount = paid.amount;
+            history.paid_out.extend(history.holders(&member));
+            history.group.payout_index += 1;
+        }
+        AjoEvent::SavingsCycleClosed(closed) => {
+            // Savers who haven't paid in full are recorded as late
+            for saver in history.contributors() {
+                if !history.paid_out.contains(&saver) && !history.has_contributed(closed.cycle, &saver) {
+                    history.record_punctuality(&saver, true, 0);
+                }
+            }
+        }
+        AjoEvent::SavingsWithdrawn(withdrawn) => {
+            let member = strkey(&withdrawn.member);
+            history.payouts.push(Payout {
+                cycle: withdrawn.cycle,
+                member: member.clone(),
+                amount: withdrawn.amount,
+                penalty_bonus: 0,
+                shares: Vec::new(),
+            });
+            history.paid_out.insert(member);
+        }
+
+        // Cycles
+        AjoEvent::CycleAdvanced(cycle) => {
+            history.group.current_cycle = cycle.cycle;
+            history.group.cycle_start_time = cycle.timestamp;
+            history.group.catch_up_end = 0;
+        }
+        AjoEvent::CycleCatchUp(catch_up) => history.group.catch_up_end = catch_up.catch_up_end,
+        AjoEvent::GroupCompleted(_) => {
+            history.group.is_complete = true;
+            history.group.state = GroupState::Complete;
+        }
+
+        // Settings
+        AjoEvent::TokenSet(set) => history.group.token = Some(strkey(&set.token)),
+        AjoEvent::SeatTransferApprovalSet(set) => {
+            history.group.seat_transfer_needs_approval = set.enabled
+        }
+        AjoEvent::MaxSeatsSet(set) => history.group.max_seats_per_member = set.value,
+        AjoEvent::IntervalsSet(set) => {
+            history.group.contribution_interval = set.contribution_interval;
+            history.group.cycle_duration = set.payout_interval;
+        }
+        AjoEvent::MaxTierSet(set) => history.group.max_contribution_tier = set.value,
+        AjoEvent::SeatTierSet(set) => {
+            if let Some(tier) = history.group.seat_tiers.get_mut(set.seat as usize) {
+                *tier = set.tier;
+            }
+        }
+        AjoEvent::RecipientsPerCycleSet(set) => history.group.recipients_per_cycle = set.value,
+        AjoEvent::RenewalShuffleSet(set) => history.group.shuffle_on_renewal = set.enabled,
+        AjoEvent::InsurancePremiumSet(set) => {
+            history.group.token = Some(strkey(&set.token));
+            history.group.insurance_premium_bps = set.premium_bps;
+        }
+        AjoEvent::VaultSet(set) => {
+            history.group.vault = Some(strkey(&set.vault));
+            history.group.yield_policy = set.yield_policy;
+        }
+        AjoEvent::TargetSavingsSet(set) => {
+            history.group.kind = GroupKind::TargetSavings;
+            history.group.savings_cycles = set.savings_cycles;
+            history.group.early_withdrawal_bps = set.early_withdrawal_bps;
+        }
+        AjoEvent::KeeperBountySet(set) => history.group.keeper_bounty = set.bounty,
+        AjoEvent::CommissionSet(set) => history.group.commission = set.commission.clone(),
+        AjoEvent::PenaltyPolicySet(set) => history.group.penalty_policy = set.policy,
+        AjoEvent::PenaltyScheduleSet(set) => history.group.penalty_schedule = (&set.schedule).into(),
+        AjoEvent::CycleScheduleSet(set) => {
+            let schedule = CycleSchedule::from(&set.schedule);
+            // The first cycle moves to its scheduled start
+            history.group.cycle_start_time = match &schedule {
+                CycleSchedule::Calendar(starts) => {
+                    starts.first().copied().unwrap_or(history.group.cycle_start_time)
+                }
+                _ => history.group.created_at,
+            };
+            history.group.cycle_schedule = schedule;
+        }
+
+        // Seats
+        AjoEvent::RenewalOptIn(opt_in) => history.renewal_opt_ins.push(strkey(&opt_in.member)),
+        AjoEvent::SeatTransferred(transfer) => {
+            transfer_seat(history, &strkey(&transfer.from), &strkey(&transfer.to))
+        }
+        AjoEvent::PositionsSwapped(swap) => {
+            // `from` now pays out in `from_cycle`, the seat `to` held
+            let from_index = (swap.to_cycle - 1) as usize;
+            let to_index = (swap.from_cycle - 1) as usize;
+            history.group.members.swap(from_index, to_index);
+            history.group.seat_tiers.swap(from_index, to_index);
+        }
+
+        // Cancellation and refunds
+        AjoEvent::RefundRequested(requested) => {
+            history.refund_request = Some(RefundRequest {
+                requester: strkey(&requested.requester),
+                voting_deadline: requested.voting_deadline,
+                votes_for: 0,
+                votes_against: 0,
+                executed: false,
+                approved: false,
+            });
+        }
+        AjoEvent::RefundVoted(vote) => {
+            if let Some(request) = history.refund_request.as_mut() {
+                if vote.in_favor {
+                    request.votes_for += 1;
+                } else {
+                    request.votes_against += 1;
+                }
+            }
+        }
+        AjoEvent::RefundRejected(_) => {
+            if let Some(request) = history.refund_request.as_mut() {
+                request.executed = true;
+            }
+        }
+        AjoEvent::RefundApproved(_) => {
+            if let Some(request) = history.refund_request.as_mut() {
+                request.executed = true;
+                request.approved = true;
+            }
+            history.group.state = GroupState::Cancelled;
+        }
+        AjoEvent::Refunded(refund) => history.refunds.push(Refund {
+            member: strkey(&refund.member),
+            amount: refund.amount,
+            reason: refund.reason,
+        }),
+        AjoEvent::GroupCancelled(_) | AjoEvent::EmergencyRefund(_) => {
+            history.group.state = GroupState::Cancelled;
+        }
+
+        _ => {}
+    }
+}
+
+/// Returns the payout being made to `member` this cycle, starting it if needed.
+///
+/// `payshare` and `pendistr` come before the `payout` that completes it.
+fn pending_payout<'a>(history: &'a mut GroupHistory, cycle: u32, member: &str) -> &'a mut Payout {
+    let started = history
+        .payouts
+        .last()
+        .is_some_and(|payout| payout.cycle == cycle && payout.member == member && payout.amount == 0);
+    if !started {
+        history.payouts.push(Payout {
+            cycle,
+            member: member.to_string(),
+            amount: 0,
+            penalty_bonus: 0,
+            shares: Vec::new(),
+        });
+    }
+    history.payouts.last_mut().expect("payout just pushed")
+}
+
+/// Hands every seat `from` holds, with its records, over to `to`.
+fn transfer_seat(history: &mut GroupHistory, from: &str, to: &str) {
+    for member in history.group.members.iter_mut().filter(|member| *member == from) {
+        *member = to.to_string();
+    }
+
+    let moved: Vec<(u32, String)> = history
+        .contributions
+        .keys()
+        .filter(|(_, member)| member == from)
+        .cloned()
+        .collect();
+    for (cycle, member) in moved {
+        if let Some(record) = history.contributions.remove(&(cycle, member)) {
+            history.contributions.insert((cycle, to.to_string()), record);
+        }
+    }
+    if let Some(record) = history.penalties.remove(from) {
+        history.penalties.insert(to.to_string(), record);
+    }
+    if history.paid_out.remove(from) {
+        history.paid_out.insert(to.to_string());
+    }
+    for payout in history.payouts.iter_mut().filter(|payout| payout.member == from) {
+        payout.member = to.to_string();
+    }
+    history.renewal_opt_ins.retain(|member| member != from);
+}
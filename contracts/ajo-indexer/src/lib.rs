@@ -0,0 +1,52 @@
+//! # Ajo event indexer
+//!
+//! Rebuilds Ajo group state from the contract's events, so a backend doesn't
+//! have to re-implement the contract to interpret them.
+//!
+//! - [`AjoEvent::decode`] turns a [`RawEvent`] (topics and payload as XDR) into
+//!   the contract's typed payload struct, rejecting payloads from a newer schema.
+//! - [`Indexer`] replays decoded events into a [`GroupHistory`] per group: the
+//!   group itself, contributions, penalty records, payouts and refunds.
+//! - [`GroupHistory::verify`] compares the result with `get_group` and
+//!   `get_group_status` snapshots.
+//! - [`capture_events`] collects a contract's events from a Soroban test `Env`,
+//!   so all of the above can be exercised offline.
+//!
+//! ```
+//! use ajo_indexer::{capture_events, Indexer};
+//! use soroban_ajo::{AjoContract, AjoContractClient};
+//! use soroban_sdk::{testutils::Address as _, Address, Env};
+//!
+//! let env = Env::default();
+//! env.mock_all_auths();
+//! let contract_id = env.register_contract(None, AjoContract);
+//! let client = AjoContractClient::new(&env, &contract_id);
+//!
+//! let creator = Address::generate(&env);
+//! let group_id = client.create_group(&creator, &100_000_000, &604_800, &3, &86_400, &5);
+//! client.join_group(&Address::generate(&env), &group_id);
+//! client.contribute(&creator, &group_id);
+//!
+//! let mut indexer = Indexer::new();
+//! indexer.ingest_all(&capture_events(&env, &contract_id)).unwrap();
+//! let history = indexer.group(group_id).unwrap();
+//! assert_eq!(history.group.members.len(), 2);
+//! assert!(history
+//!     .verify(&client.get_group(&group_id), &client.get_group_status(&group_id))
+//!     .is_empty());
+//! ```
+
+mod capture;
+mod event;
+mod indexer;
+mod state;
+mod verify;
+
+pub use capture::capture_events;
+pub use event::{AjoEvent, DecodeError, RawEvent};
+pub use indexer::Indexer;
+pub use state::{
+    strkey, Contribution, CycleSchedule, GroupHistory, IndexedGroup, Payout, PenaltyRecord,
+    PenaltySchedule, Refund, RefundRequest,
+};
+pub use verify::Mismatch;
@@ -0,0 +1,330 @@
+//! Group state rebuilt from events, in plain Rust types.
+//!
+//! Addresses are kept as strkeys (`G...` for accounts, `C...` for contracts), so
+//! the state outlives the [`Env`](soroban_sdk::Env) its events were decoded with.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use soroban_ajo::{
+    GroupKind, GroupState, OrganizerCommission, PenaltyPolicy, RefundReason, YieldPolicy,
+};
+use soroban_sdk::xdr::ScAddress;
+use soroban_sdk::Address;
+
+/// Returns an address's strkey.
+pub fn strkey(address: &Address) -> String {
+    ScAddress::from(address).to_string()
+}
+
+/// How a group's cycles are scheduled; mirrors [`soroban_ajo::CycleSchedule`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum CycleSchedule {
+    /// Each cycle starts when the previous payout is made.
+    Rolling,
+    /// Cycle N starts `(N - 1) × cycle_duration` after the group was created.
+    Anchored,
+    /// Cycle N starts at the N-th timestamp.
+    Calendar(Vec<u64>),
+}
+
+impl From<&soroban_ajo::CycleSchedule> for CycleSchedule {
+    fn from(schedule: &soroban_ajo::CycleSchedule) -> Self {
+        match schedule {
+            soroban_ajo::CycleSchedule::Rolling => CycleSchedule::Rolling,
+            soroban_ajo::CycleSchedule::Anchored => CycleSchedule::Anchored,
+            soroban_ajo::CycleSchedule::Calendar(starts) => {
+                CycleSchedule::Calendar(starts.iter().collect())
+            }
+        }
+    }
+}
+
+/// How late penalties grow; mirrors [`soroban_ajo::PenaltySchedule`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PenaltySchedule {
+    /// `penalty_rate` percent, however late.
+    Flat,
+    /// Grows by `bps_per_hour` up to `cap_bps`.
+    Linear { bps_per_hour: u32, cap_bps: u32 },
+    /// `(late_after, rate_bps)` tiers, capped at `cap_bps`.
+    Stepped { tiers: Vec<(u64, u32)>, cap_bps: u32 },
+}
+
+impl From<&soroban_ajo::PenaltySchedule> for PenaltySchedule {
+    fn from(schedule: &soroban_ajo::PenaltySchedule) -> Self {
+        match schedule {
+            soroban_ajo::PenaltySchedule::Flat => PenaltySchedule::Flat,
+            soroban_ajo::PenaltySchedule::Linear(linear) => PenaltySchedule::Linear {
+                bps_per_hour: linear.bps_per_hour,
+                cap_bps: linear.cap_bps,
+            },
+            soroban_ajo::PenaltySchedule::Stepped(stepped) => PenaltySchedule::Stepped {
+                tiers: stepped.tiers.iter().map(|tier| (tier.late_after, tier.rate_bps)).collect(),
+                cap_bps: stepped.cap_bps,
+            },
+        }
+    }
+}
+
+/// A group's configuration and progress; mirrors [`soroban_ajo::Group`].
+///
+/// Every field of `Group` is rebuilt except `pot_surplus`, which no event reports.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct IndexedGroup {
+    pub id: u64,
+    pub creator: String,
+    pub contribution_amount: i128,
+    pub cycle_duration: u64,
+    pub contribution_interval: u64,
+    pub max_members: u32,
+    pub members: Vec<String>,
+    pub current_cycle: u32,
+    pub payout_index: u32,
+    pub created_at: u64,
+    pub cycle_start_time: u64,
+    pub is_complete: bool,
+    pub grace_period: u64,
+    pub penalty_rate: u32,
+    pub state: GroupState,
+    pub commission: OrganizerCommission,
+    pub penalty_policy: PenaltyPolicy,
+    pub penalty_schedule: PenaltySchedule,
+    pub cycle_schedule: CycleSchedule,
+    pub catch_up_end: u64,
+    pub keeper_bounty: i128,
+    pub token: Option<String>,
+    pub seat_transfer_needs_approval: bool,
+    pub max_seats_per_member: u32,
+    pub max_contribution_tier: u32,
+    pub seat_tiers: Vec<u32>,
+    pub recipients_per_cycle: u32,
+    pub insurance_premium_bps: u32,
+    pub vault: Option<String>,
+    pub yield_policy: YieldPolicy,
+    pub kind: GroupKind,
+    pub savings_cycles: u32,
+    pub early_withdrawal_bps: u32,
+    pub previous_group_id: Option<u64>,
+    pub next_group_id: Option<u64>,
+    pub shuffle_on_renewal: bool,
+}
+
+impl From<&soroban_ajo::Group> for IndexedGroup {
+    fn from(group: &soroban_ajo::Group) -> Self {
+        IndexedGroup {
+            id: group.id,
+            creator: strkey(&group.creator),
+            contribution_amount: group.contribution_amount,
+            cycle_duration: group.cycle_duration,
+            contribution_interval: group.contribution_interval,
+            max_members: group.max_members,
+            members: group.members.iter().map(|member| strkey(&member)).collect(),
+            current_cycle: group.current_cycle,
+            payout_index: group.payout_index,
+            created_at: group.created_at,
+            cycle_start_time: group.cycle_start_time,
+            is_complete: group.is_complete,
+            grace_period: group.grace_period,
+            penalty_rate: group.penalty_rate,
+            state: group.state,
+            commission: group.commission.clone(),
+            penalty_policy: group.penalty_policy,
+            penalty_schedule: (&group.penalty_schedule).into(),
+            cycle_schedule: (&group.cycle_schedule).into(),
+            catch_up_end: group.catch_up_end,
+            keeper_bounty: group.keeper_bounty,
+            token: group.token.as_ref().map(strkey),
+            seat_transfer_needs_approval: group.seat_transfer_needs_approval,
+            max_seats_per_member: group.max_seats_per_member,
+            max_contribution_tier: group.max_contribution_tier,
+            seat_tiers: group.seat_tiers.iter().collect(),
+            recipients_per_cycle: group.recipients_per_cycle,
+            insurance_premium_bps: group.insurance_premium_bps,
+            vault: group.vault.as_ref().map(strkey),
+            yield_policy: group.yield_policy,
+            kind: group.kind,
+            savings_cycles: group.savings_cycles,
+            early_withdrawal_bps: group.early_withdrawal_bps,
+            previous_group_id: group.previous_group_id,
+            next_group_id: group.next_group_id,
+            shuffle_on_renewal: group.shuffle_on_renewal,
+        }
+    }
+}
+
+/// What a member has paid towards one cycle.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Contribution {
+    /// Amount paid so far, including installments and prepaid amounts.
+    pub amount_paid: i128,
+
+    /// Whether the cycle is paid in full.
+    pub has_paid: bool,
+
+    /// Whether it was paid late (or covered by the insurance fund).
+    pub is_late: bool,
+
+    /// Late penalty charged, known once the cycle is paid in full.
+    pub penalty_amount: i128,
+
+    /// Whether the cycle was paid ahead with `prepay`.
+    pub is_prepaid: bool,
+
+    /// Who paid, when a third party sponsored the contribution.
+    pub sponsor: Option<String>,
+}
+
+/// A member's punctuality; mirrors [`soroban_ajo::MemberPenaltyRecord`].
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct PenaltyRecord {
+    pub late_count: u32,
+    pub on_time_count: u32,
+    pub total_penalties: i128,
+}
+
+impl PenaltyRecord {
+    /// Percentage of contributions paid on time, 100 before any contribution.
+    pub fn reliability_score(&self) -> u32 {
+        (self.on_time_count * 100)
+            .checked_div(self.on_time_count + self.late_count)
+            .unwrap_or(100)
+    }
+}
+
+/// A payout made to a recipient, or a saver's withdrawal.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Payout {
+    pub cycle: u32,
+    pub member: String,
+
+    /// Total paid, net of commission.
+    pub amount: i128,
+
+    /// Penalties included in the amount.
+    pub penalty_bonus: i128,
+
+    /// Where a split payout was sent, as `(recipient, amount)` pairs.
+    pub shares: Vec<(String, i128)>,
+}
+
+/// A refund paid when a group is cancelled.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Refund {
+    pub member: String,
+    pub amount: i128,
+    pub reason: RefundReason,
+}
+
+/// A members' vote on refunding the group.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RefundRequest {
+    pub requester: String,
+    pub voting_deadline: u64,
+    pub votes_for: u32,
+    pub votes_against: u32,
+    pub executed: bool,
+    pub approved: bool,
+}
+
+/// Everything known about one group from its events.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GroupHistory {
+    pub group: IndexedGroup,
+
+    /// Contributions by `(cycle, member)`.
+    pub contributions: BTreeMap<(u32, String), Contribution>,
+
+    /// Penalty records by member.
+    pub penalties: BTreeMap<String, PenaltyRecord>,
+
+    /// Penalties charged by cycle, as each late contribution completes.
+    pub penalty_pools: BTreeMap<u32, i128>,
+
+    /// Commission paid to the organizer by cycle.
+    pub commissions: BTreeMap<u32, i128>,
+
+    /// Payouts and savings withdrawals, in the order they were made.
+    pub payouts: Vec<Payout>,
+
+    /// Refunds, in the order they were paid.
+    pub refunds: Vec<Refund>,
+
+    /// The latest refund vote, if one was requested.
+    pub refund_request: Option<RefundRequest>,
+
+    /// Co-holders of each shared seat, by the seat's primary holder.
+    pub seat_holders: BTreeMap<String, Vec<String>>,
+
+    /// Members who opted into the next round.
+    pub renewal_opt_ins: Vec<String>,
+
+    /// Addresses that have received a payout or withdrawn their savings.
+    pub paid_out: BTreeSet<String>,
+}
+
+impl GroupHistory {
+    pub(crate) fn new(group: IndexedGroup) -> Self {
+        GroupHistory {
+            group,
+            contributions: BTreeMap::new(),
+            penalties: BTreeMap::new(),
+            penalty_pools: BTreeMap::new(),
+            commissions: BTreeMap::new(),
+            payouts: Vec::new(),
+            refunds: Vec::new(),
+            refund_request: None,
+            seat_holders: BTreeMap::new(),
+            renewal_opt_ins: Vec::new(),
+            paid_out: BTreeSet::new(),
+        }
+    }
+
+    /// Returns a member's contribution to a cycle, if they have paid anything.
+    pub fn contribution(&self, cycle: u32, member: &str) -> Option<&Contribution> {
+        self.contributions.get(&(cycle, member.to_string()))
+    }
+
+    /// Returns whether a member has paid a cycle in full.
+    pub fn has_contributed(&self, cycle: u32, member: &str) -> bool {
+        self.contribution(cycle, member).is_some_and(|contribution| contribution.has_paid)
+    }
+
+    /// Returns the addresses holding a seat: its co-holders, or just the member.
+    pub fn holders(&self, member: &str) -> Vec<String> {
+        self.seat_holders.get(member).cloned().unwrap_or_else(|| vec![member.to_string()])
+    }
+
+    /// Returns whether the seat listed under `member` is paid for the cycle.
+    ///
+    /// A shared seat is paid only once every co-holder has paid their share.
+    pub fn has_seat_contributed(&self, cycle: u32, member: &str) -> bool {
+        self.holders(member).iter().all(|holder| self.has_contributed(cycle, holder))
+    }
+
+    /// Returns everyone who contributes to the group: each member once, with the
+    /// co-holders of shared seats.
+    pub fn contributors(&self) -> Vec<String> {
+        let mut contributors: Vec<String> = Vec::new();
+        for (index, member) in self.group.members.iter().enumerate() {
+            if self.group.members.iter().position(|other| other == member) == Some(index) {
+                contributors.extend(self.holders(member));
+            }
+        }
+        contributors
+    }
+
+    /// Returns the member's penalty record, if they have contributed or defaulted.
+    pub fn penalty_record(&self, member: &str) -> Option<&PenaltyRecord> {
+        self.penalties.get(member)
+    }
+
+    pub(crate) fn record_punctuality(&mut self, member: &str, is_late: bool, penalty: i128) {
+        let record = self.penalties.entry(member.to_string()).or_default();
+        if is_late {
+            record.late_count += 1;
+            record.total_penalties += penalty;
+        } else {
+            record.on_time_count += 1;
+        }
+    }
+}
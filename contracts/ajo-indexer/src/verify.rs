@@ -0,0 +1,138 @@
+//! Checking rebuilt state against the contract's own view of a group.
+
+use std::fmt::{self, Debug};
+
+use soroban_ajo::{Group, GroupKind, GroupStatus};
+
+use crate::state::{strkey, GroupHistory, IndexedGroup};
+
+/// A field where the rebuilt state disagrees with the contract.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Mismatch {
+    /// The field, prefixed with `group.` or `status.`.
+    pub field: String,
+
+    /// The value rebuilt from events.
+    pub indexed: String,
+
+    /// The value the contract returned.
+    pub snapshot: String,
+}
+
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: indexed {}, contract has {}", self.field, self.indexed, self.snapshot)
+    }
+}
+
+/// Collects a [`Mismatch`] for each pair of values that differ.
+#[derive(Default)]
+struct Comparison(Vec<Mismatch>);
+
+impl Comparison {
+    fn check<T: Debug + PartialEq>(&mut self, field: &str, indexed: T, snapshot: T) {
+        if indexed != snapshot {
+            self.0.push(Mismatch {
+                field: field.to_string(),
+                indexed: format!("{indexed:?}"),
+                snapshot: format!("{snapshot:?}"),
+            });
+        }
+    }
+}
+
+/// Compares every field of two groups.
+macro_rules! check_fields {
+    ($comparison:expr, $indexed:expr, $snapshot:expr, [$($field:ident),* $(,)?]) => {
+        $(
+            $comparison.check(
+                concat!("group.", stringify!($field)),
+                &$indexed.$field,
+                &$snapshot.$field,
+            );
+        )*
+    };
+}
+
+impl GroupHistory {
+    /// Compares the rebuilt group with snapshots from `get_group` and `get_group_status`.
+    ///
+    /// Checks every rebuilt [`Group`] field, and the status fields that follow from
+    /// them: the cycle, contributions received and pending, payout order and next
+    /// recipient, and the group's commission, penalty policy, bounty and schedule.
+    /// Time-dependent fields and the penalty reserve aren't checked.
+    ///
+    /// # Returns
+    /// The fields that differ, empty when the rebuilt state matches
+    pub fn verify(&self, group: &Group, status: &GroupStatus) -> Vec<Mismatch> {
+        let mut comparison = Comparison::default();
+        let indexed = &self.group;
+        let snapshot = IndexedGroup::from(group);
+        check_fields!(comparison, indexed, snapshot, [
+            id, creator, contribution_amount, cycle_duration, contribution_interval,
+            max_members, members, current_cycle, payout_index, created_at, cycle_start_time,
+            is_complete, grace_period, penalty_rate, state, commission, penalty_policy,
+            penalty_schedule, cycle_schedule, catch_up_end, keeper_bounty, token,
+            seat_transfer_needs_approval, max_seats_per_member, max_contribution_tier,
+            seat_tiers, recipients_per_cycle, insurance_premium_bps, vault, yield_policy, kind,
+            savings_cycles, early_withdrawal_bps, previous_group_id, next_group_id,
+            shuffle_on_renewal,
+        ]);
+
+        let cycle = indexed.current_cycle;
+        let pending: Vec<String> = indexed
+            .members
+            .iter()
+            .filter(|member| !self.has_seat_contributed(cycle, member))
+            .cloned()
+            .collect();
+        let rotating = !indexed.is_complete && indexed.kind == GroupKind::Rotating;
+        let payout_order: Vec<String> = if rotating {
+            indexed.members.iter().skip(indexed.payout_index as usize).cloned().collect()
+        } else {
+            Vec::new()
+        };
+        let next_recipient = payout_order.first().cloned();
+
+        comparison.check("status.group_id", indexed.id, status.group_id);
+        comparison.check("status.current_cycle", cycle, status.current_cycle);
+        comparison.check(
+            "status.contributions_received",
+            (indexed.members.len() - pending.len()) as u32,
+            status.contributions_received,
+        );
+        comparison.check("status.total_members", indexed.members.len() as u32, status.total_members);
+        comparison.check(
+            "status.pending_contributors",
+            pending,
+            status.pending_contributors.iter().map(|member| strkey(&member)).collect(),
+        );
+        comparison.check("status.is_complete", indexed.is_complete, status.is_complete);
+        comparison.check("status.cycle_start_time", indexed.cycle_start_time, status.cycle_start_time);
+        comparison.check(
+            "status.next_recipient",
+            next_recipient,
+            status.has_next_recipient.then(|| strkey(&status.next_recipient)),
+        );
+        comparison.check(
+            "status.payout_order",
+            payout_order,
+            status.payout_order.iter().map(|member| strkey(&member)).collect(),
+        );
+        comparison.check(
+            "status.organizer_commission",
+            &indexed.commission,
+            &status.organizer_commission,
+        );
+        comparison.check("status.penalty_policy", indexed.penalty_policy, status.penalty_policy);
+        comparison.check("status.keeper_bounty", indexed.keeper_bounty, status.keeper_bounty);
+        comparison.check(
+            "status.cycle_schedule",
+            &indexed.cycle_schedule,
+            &(&status.cycle_schedule).into(),
+        );
+        comparison.check("status.is_catching_up", indexed.catch_up_end > 0, status.is_catching_up);
+
+        comparison.0
+    }
+}
@@ -0,0 +1,450 @@
+#![cfg(test)]
+
+use ajo_indexer::{
+    capture_events, strkey, AjoEvent, DecodeError, GroupHistory, Indexer, RawEvent,
+};
+use soroban_ajo::{
+    AjoContract, AjoContractClient, CycleSchedule, GroupKind, GroupState, GroupTemplate,
+    GroupTerms, OrganizerCommission, PenaltyPolicy, PenaltySchedule, RefundReason, YieldPolicy,
+    EVENT_SCHEMA_VERSION,
+};
+use soroban_sdk::{
+    testutils::{Address as _, Events, Ledger},
+    xdr::{ScMap, ScMapEntry, ScSymbol, ScVal},
+    Address, BytesN, Env,
+};
+
+const CONTRIBUTION: i128 = 100_000_000;
+const CYCLE: u64 = 604_800;
+const DAY: u64 = 86400;
+
+/// Helper function to create a test environment and contract
+fn setup_test_env() -> (Env, AjoContractClient<'static>, Address, Address, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, AjoContract);
+    let client = AjoContractClient::new(&env, &contract_id);
+
+    // Generate test addresses
+    let creator = Address::generate(&env);
+    let member2 = Address::generate(&env);
+    let member3 = Address::generate(&env);
+
+    (env, client, creator, member2, member3)
+}
+
+/// The terms `create_group` gives a three-member group
+fn default_terms() -> GroupTerms {
+    GroupTerms {
+        contribution_amount: CONTRIBUTION,
+        cycle_duration: CYCLE,
+        contribution_interval: 0,
+        max_members: 3,
+        grace_period: DAY,
+        penalty_rate: 5,
+        commission: OrganizerCommission::None,
+        penalty_policy: PenaltyPolicy::NextRecipient,
+        penalty_schedule: PenaltySchedule::Flat,
+        cycle_schedule: CycleSchedule::Rolling,
+        keeper_bounty: 0,
+        token: None,
+        seat_transfer_needs_approval: false,
+        max_seats_per_member: 1,
+        max_contribution_tier: 1,
+        recipients_per_cycle: 1,
+        insurance_premium_bps: 0,
+        vault: None,
+        yield_policy: YieldPolicy::Recipient,
+        kind: GroupKind::Rotating,
+        savings_cycles: 0,
+        early_withdrawal_bps: 0,
+        shuffle_on_renewal: false,
+    }
+}
+
+/// Creates a full group with the given members after the creator
+fn create_group(client: &AjoContractClient, creator: &Address, others: &[&Address]) -> u64 {
+    let max_members = others.len() as u32 + 1;
+    let group_id = client.create_group(creator, &CONTRIBUTION, &CYCLE, &max_members, &DAY, &5u32);
+    for member in others {
+        client.join_group(member, &group_id);
+    }
+    group_id
+}
+
+/// Advances past the grace period and executes the payout
+fn close_cycle(env: &Env, client: &AjoContractClient, group_id: u64) {
+    env.ledger().with_mut(|li| {
+        li.timestamp += CYCLE + DAY + 1;
+    });
+    client.execute_payout(&group_id);
+}
+
+/// Replays every event the contract has published and checks the group against
+/// the contract's snapshots
+fn replay(env: &Env, client: &AjoContractClient, group_id: u64) -> Indexer {
+    let mut indexer = Indexer::new();
+    indexer.ingest_all(&capture_events(env, &client.address)).expect("events decode");
+
+    let history = indexer.group(group_id).expect("group indexed");
+    let mismatches =
+        history.verify(&client.get_group(&group_id), &client.get_group_status(&group_id));
+    assert!(mismatches.is_empty(), "group {group_id} differs: {mismatches:?}");
+    indexer
+}
+
+/// Checks each member's rebuilt penalty record against the contract's
+fn assert_penalty_records(client: &AjoContractClient, history: &GroupHistory, members: &[&Address]) {
+    for member in members {
+        let expected = client.get_member_penalty_record(&history.group.id, member);
+        let record = history.penalty_record(&strkey(member)).expect("penalty record indexed");
+        assert_eq!(record.late_count, expected.late_count);
+        assert_eq!(record.on_time_count, expected.on_time_count);
+        assert_eq!(record.total_penalties, expected.total_penalties);
+        assert_eq!(record.reliability_score(), expected.reliability_score);
+    }
+}
+
+#[test]
+fn test_rotating_group_replays_through_every_cycle() {
+    let (env, client, creator, member2, member3) = setup_test_env();
+    let group_id = client.create_group(&creator, &CONTRIBUTION, &CYCLE, &3u32, &DAY, &5u32);
+    client.set_organizer_commission(&group_id, &OrganizerCommission::BasisPoints(100));
+    client.join_group(&member2, &group_id);
+    replay(&env, &client, group_id);
+    client.join_group(&member3, &group_id);
+
+    // Member3 pays the first cycle late
+    client.contribute(&creator, &group_id);
+    client.contribute(&member2, &group_id);
+    replay(&env, &client, group_id);
+    env.ledger().with_mut(|li| {
+        li.timestamp += CYCLE + 1;
+    });
+    client.contribute(&member3, &group_id);
+    env.ledger().with_mut(|li| {
+        li.timestamp += DAY;
+    });
+    client.execute_payout(&group_id);
+    replay(&env, &client, group_id);
+
+    for _ in 0..2 {
+        client.contribute(&creator, &group_id);
+        client.contribute(&member2, &group_id);
+        client.contribute(&member3, &group_id);
+        close_cycle(&env, &client, group_id);
+        replay(&env, &client, group_id);
+    }
+
+    let indexer = replay(&env, &client, group_id);
+    let history = indexer.group(group_id).unwrap();
+    assert!(history.group.is_complete);
+    assert_eq!(history.group.state, GroupState::Complete);
+
+    let late = history.contribution(1, &strkey(&member3)).unwrap();
+    assert!(late.has_paid && late.is_late);
+    assert_eq!(late.penalty_amount, 5_000_000);
+    assert_eq!(history.penalty_pools.get(&1), Some(&5_000_000));
+    assert_penalty_records(&client, history, &[&creator, &member2, &member3]);
+
+    assert_eq!(history.payouts.len(), 3);
+    for (payout, member) in history.payouts.iter().zip([&creator, &member2, &member3]) {
+        let record = client.get_payout_record(&group_id, member);
+        assert_eq!(payout.member, strkey(member));
+        assert_eq!((payout.cycle, payout.amount), (record.cycle, record.amount));
+    }
+    assert_eq!(history.payouts[0].penalty_bonus, 5_000_000);
+    assert_eq!(history.commissions.len(), 3);
+}
+
+#[test]
+fn test_failed_calls_are_not_replayed() {
+    let (env, client, creator, member2, member3) = setup_test_env();
+    client.initialize(&creator);
+    let group_id = create_group(&client, &creator, &[&member2, &member3]);
+    client.contribute(&creator, &group_id);
+
+    // The host keeps the `upgraded` event of an upgrade that failed
+    let result = client.try_upgrade(&BytesN::from_array(&env, &[7; 32]));
+    assert!(result.is_err());
+    let result = client.try_contribute(&creator, &group_id);
+    assert!(result.is_err());
+
+    let events = capture_events(&env, &client.address);
+    assert_eq!(events.len(), env.events().all().len() as usize - 1);
+    let mut indexer = Indexer::new();
+    let names: Vec<&str> = events
+        .iter()
+        .map(|event| indexer.ingest(event).unwrap().name())
+        .collect();
+    assert_eq!(names, ["init", "created", "joined", "joined", "contrib"]);
+
+    let indexer = replay(&env, &client, group_id);
+    let record = indexer.group(group_id).unwrap().penalty_record(&strkey(&creator)).unwrap();
+    assert_eq!(record.on_time_count, 1);
+}
+
+#[test]
+fn test_renewed_group_replays_with_carried_records() {
+    let (env, client, creator, member2, member3) = setup_test_env();
+    let group_id = create_group(&client, &creator, &[&member2, &member3]);
+    client.opt_in_next_round(&member3, &group_id);
+    client.opt_in_next_round(&creator, &group_id);
+
+    // Member3 pays the first cycle late
+    client.contribute(&creator, &group_id);
+    client.contribute(&member2, &group_id);
+    env.ledger().with_mut(|li| {
+        li.timestamp += CYCLE + 1;
+    });
+    client.contribute(&member3, &group_id);
+    env.ledger().with_mut(|li| {
+        li.timestamp += DAY;
+    });
+    client.execute_payout(&group_id);
+    for _ in 0..2 {
+        client.contribute(&creator, &group_id);
+        client.contribute(&member2, &group_id);
+        client.contribute(&member3, &group_id);
+        close_cycle(&env, &client, group_id);
+    }
+
+    let next_group_id = client.get_group(&group_id).next_group_id.unwrap();
+    replay(&env, &client, group_id);
+    let indexer = replay(&env, &client, next_group_id);
+    let successor = indexer.group(next_group_id).unwrap();
+    assert_eq!(successor.group.previous_group_id, Some(group_id));
+    assert_eq!(successor.group.members, [strkey(&creator), strkey(&member3)]);
+    assert_penalty_records(&client, successor, &[&creator, &member3]);
+
+    // The next round carries on from there
+    client.contribute(&creator, &next_group_id);
+    client.contribute(&member3, &next_group_id);
+    close_cycle(&env, &client, next_group_id);
+    let indexer = replay(&env, &client, next_group_id);
+    assert_penalty_records(&client, indexer.group(next_group_id).unwrap(), &[&creator, &member3]);
+}
+
+#[test]
+fn test_seat_changes_replay() {
+    let (env, client, creator, member2, member3) = setup_test_env();
+    let half_a = Address::generate(&env);
+    let half_b = Address::generate(&env);
+    let newcomer = Address::generate(&env);
+
+    let group_id = client.create_group(&creator, &CONTRIBUTION, &CYCLE, &4u32, &DAY, &5u32);
+    client.set_max_contribution_tier(&group_id, &2u32);
+    client.join_group(&member2, &group_id);
+    client.join_group(&member3, &group_id);
+    let holders = soroban_sdk::Vec::from_array(&env, [half_a.clone(), half_b.clone()]);
+    client.join_shared_seat(&group_id, &holders);
+    client.set_seat_tier(&half_a, &group_id, &3u32, &2u32);
+    replay(&env, &client, group_id);
+
+    // Member2 hands their seat over, then swaps turns with member3
+    client.transfer_seat(&group_id, &member2, &newcomer);
+    client.propose_swap(&group_id, &newcomer, &member3);
+    client.accept_swap(&group_id, &newcomer, &member3);
+    replay(&env, &client, group_id);
+
+    // The shared seat stays pending until both co-holders pay
+    client.contribute(&creator, &group_id);
+    client.contribute(&half_a, &group_id);
+    let indexer = replay(&env, &client, group_id);
+    let history = indexer.group(group_id).unwrap();
+    assert!(!history.has_seat_contributed(1, &strkey(&half_a)));
+    client.contribute(&half_b, &group_id);
+    client.contribute(&newcomer, &group_id);
+    client.contribute(&member3, &group_id);
+    close_cycle(&env, &client, group_id);
+
+    let indexer = replay(&env, &client, group_id);
+    let history = indexer.group(group_id).unwrap();
+    assert_eq!(history.group.members[2], strkey(&newcomer));
+    assert_eq!(history.group.seat_tiers, [1, 1, 1, 2]);
+    assert!(history.penalty_record(&strkey(&member2)).is_none());
+    assert_penalty_records(&client, history, &[&creator, &newcomer, &member3, &half_a, &half_b]);
+}
+
+#[test]
+fn test_target_savings_group_replays() {
+    let (env, client, creator, member2, member3) = setup_test_env();
+    let group_id = client.create_group(&creator, &CONTRIBUTION, &CYCLE, &3u32, &DAY, &5u32);
+    client.set_target_savings(&group_id, &3u32, &1_000u32);
+    client.join_group(&member2, &group_id);
+    client.join_group(&member3, &group_id);
+
+    // Member3 misses the first cycle, then member2 withdraws early
+    client.contribute(&creator, &group_id);
+    client.contribute(&member2, &group_id);
+    close_cycle(&env, &client, group_id);
+    client.withdraw_savings(&member2, &group_id);
+    replay(&env, &client, group_id);
+
+    for _ in 0..2 {
+        client.contribute(&creator, &group_id);
+        client.contribute(&member3, &group_id);
+        close_cycle(&env, &client, group_id);
+    }
+    let withdrawn = client.withdraw_savings(&creator, &group_id);
+
+    let indexer = replay(&env, &client, group_id);
+    let history = indexer.group(group_id).unwrap();
+    assert_penalty_records(&client, history, &[&creator, &member3]);
+    assert_eq!(history.payouts.len(), 2);
+    assert_eq!(history.payouts[1].amount, withdrawn);
+    assert!(history.paid_out.contains(&strkey(&member2)));
+}
+
+#[test]
+fn test_refunds_replay() {
+    let (env, client, creator, member2, member3) = setup_test_env();
+
+    // An approved refund vote cancels the group
+    let group_id = create_group(&client, &creator, &[&member2]);
+    client.contribute(&creator, &group_id);
+    client.contribute_for(&member3, &member2, &group_id);
+    env.ledger().with_mut(|li| {
+        li.timestamp += CYCLE + DAY + 1;
+    });
+    client.request_refund(&member2, &group_id);
+    client.vote_refund(&member2, &group_id, &true);
+    client.vote_refund(&creator, &group_id, &true);
+    env.ledger().with_mut(|li| {
+        li.timestamp += CYCLE + 1;
+    });
+    client.execute_refund(&member2, &group_id);
+
+    let indexer = replay(&env, &client, group_id);
+    let history = indexer.group(group_id).unwrap();
+    assert_eq!(history.group.state, GroupState::Cancelled);
+    let request = history.refund_request.as_ref().unwrap();
+    assert_eq!((request.votes_for, request.executed, request.approved), (2, true, true));
+    let sponsored = history.contribution(1, &strkey(&member2)).unwrap();
+    assert_eq!(sponsored.sponsor, Some(strkey(&member3)));
+    assert_eq!(history.refunds.len(), 2);
+    for refund in &history.refunds {
+        assert_eq!((refund.amount, refund.reason), (CONTRIBUTION, RefundReason::MemberVote));
+    }
+    assert_eq!(history.refunds[1].member, strkey(&member3));
+
+    // The creator cancels another
+    let cancelled = create_group(&client, &creator, &[&member2]);
+    client.contribute(&member2, &cancelled);
+    client.cancel_group(&creator, &cancelled);
+    let indexer = replay(&env, &client, cancelled);
+    let history = indexer.group(cancelled).unwrap();
+    assert_eq!(history.refunds[0].reason, RefundReason::CreatorCancellation);
+}
+
+#[test]
+fn test_template_and_cloned_groups_replay() {
+    let (env, client, creator, member2, _member3) = setup_test_env();
+    let group_id = client.create_group(&creator, &CONTRIBUTION, &CYCLE, &3u32, &DAY, &5u32);
+    client.set_seat_transfer_approval(&group_id, &true);
+    client.set_max_seats_per_member(&group_id, &2u32);
+    client.set_keeper_bounty(&group_id, &1_000_000);
+
+    let clone_id = client.clone_group(&member2, &group_id);
+    replay(&env, &client, clone_id);
+
+    let terms = GroupTerms {
+        commission: OrganizerCommission::Fixed(1_000_000),
+        penalty_policy: PenaltyPolicy::OnTimeContributors,
+        cycle_schedule: CycleSchedule::Anchored,
+        seat_transfer_needs_approval: true,
+        max_seats_per_member: 2,
+        ..default_terms()
+    };
+    let template = GroupTemplate { creator: creator.clone(), terms };
+    let template_id = client.save_template(&template, &None);
+    let from_template = client.create_group_from_template(&member2, &template_id);
+    replay(&env, &client, from_template);
+}
+
+#[test]
+fn test_group_created_with_terms_replays() {
+    let (env, client, creator, member2, member3) = setup_test_env();
+    let now = env.ledger().timestamp();
+    let terms = GroupTerms {
+        contribution_interval: DAY,
+        commission: OrganizerCommission::BasisPoints(250),
+        penalty_policy: PenaltyPolicy::GroupReserve,
+        cycle_schedule: CycleSchedule::Calendar(soroban_sdk::vec![
+            &env,
+            now + DAY,
+            now + DAY + CYCLE,
+            now + DAY + 2 * CYCLE,
+        ]),
+        keeper_bounty: 1_000_000,
+        token: Some(Address::generate(&env)),
+        seat_transfer_needs_approval: true,
+        max_seats_per_member: 2,
+        max_contribution_tier: 2,
+        insurance_premium_bps: 100,
+        shuffle_on_renewal: true,
+        ..default_terms()
+    };
+    let group_id = client.create_group_with_terms(&creator, &terms);
+    client.join_group(&member2, &group_id);
+    client.join_group(&member3, &group_id);
+    replay(&env, &client, group_id);
+}
+
+#[test]
+fn test_verify_reports_mismatches() {
+    let (env, client, creator, member2, member3) = setup_test_env();
+    let group_id = create_group(&client, &creator, &[&member2, &member3]);
+
+    // Replaying only part of the history leaves the group behind
+    let events = capture_events(&env, &client.address);
+    let mut indexer = Indexer::new();
+    indexer.ingest_all(&events[..2]).unwrap();
+    let history = indexer.group(group_id).unwrap();
+    let mismatches = history.verify(&client.get_group(&group_id), &client.get_group_status(&group_id));
+    let fields: Vec<&str> = mismatches.iter().map(|mismatch| mismatch.field.as_str()).collect();
+    assert_eq!(
+        fields,
+        [
+            "group.members",
+            "group.seat_tiers",
+            "status.total_members",
+            "status.pending_contributors",
+            "status.payout_order",
+        ]
+    );
+}
+
+#[test]
+fn test_decode_errors() {
+    let (env, client, creator, _member2, _member3) = setup_test_env();
+    client.create_group(&creator, &CONTRIBUTION, &CYCLE, &3u32, &DAY, &5u32);
+    let created = capture_events(&env, &client.address).remove(0);
+    let indexer = Indexer::new();
+    let event = AjoEvent::decode(indexer.env(), &created).unwrap();
+    assert_eq!((event.name(), event.group_id()), ("created", 1));
+
+    let symbol = |name: &str| ScVal::Symbol(ScSymbol(name.try_into().unwrap()));
+
+    let renamed = RawEvent { topics: vec![symbol("mystery"), ScVal::U64(1)], ..created.clone() };
+    let result = AjoEvent::decode(indexer.env(), &renamed);
+    assert_eq!(result, Err(DecodeError::UnknownEvent("mystery".to_string())));
+
+    let untagged = RawEvent { topics: vec![symbol("created")], ..created.clone() };
+    assert_eq!(AjoEvent::decode(indexer.env(), &untagged), Err(DecodeError::InvalidTopics));
+
+    let mismatched = RawEvent { topics: vec![symbol("joined"), ScVal::U64(1)], ..created.clone() };
+    let result = AjoEvent::decode(indexer.env(), &mismatched);
+    assert_eq!(result, Err(DecodeError::InvalidPayload("joined".to_string())));
+
+    // A payload from a newer schema is rejected rather than misread
+    let entries = vec![
+        ScMapEntry { key: symbol("group_id"), val: ScVal::U64(1) },
+        ScMapEntry { key: symbol("version"), val: ScVal::U32(EVENT_SCHEMA_VERSION + 1) },
+    ];
+    let newer = ScVal::Map(Some(ScMap(entries.try_into().unwrap())));
+    let future = RawEvent { data: newer, ..created };
+    let result = AjoEvent::decode(indexer.env(), &future);
+    assert_eq!(result, Err(DecodeError::UnsupportedVersion(EVENT_SCHEMA_VERSION + 1)));
+}
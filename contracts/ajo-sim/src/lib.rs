@@ -0,0 +1,42 @@
+//! # Ajo scenario simulator
+//!
+//! Rehearses a group design before it is deployed. A [`Scenario`], written in
+//! TOML or JSON, lists the members, the group's terms and a timeline of joins,
+//! contributions, payouts, refund votes and ledger time jumps. [`run`] plays it
+//! against `AjoContract` in a Soroban test `Env` and returns a [`Report`] with,
+//! for each cycle, the steps taken, the events they published, their CPU and
+//! memory cost, the penalties charged, the payouts made and every member's
+//! balance.
+//!
+//! A scenario's `expect` table turns it into a regression test: the report lists
+//! every step that failed unexpectedly and every expectation the run missed.
+//!
+//! ```
+//! let scenario = ajo_sim::Scenario::from_toml(r#"
+//!     name = "pair"
+//!     members = ["ada", "ben"]
+//!     group = { contribution = 100, cycle_duration = 604800 }
+//!     timeline = [
+//!         { action = "join", member = "ben" },
+//!         { action = "contribute", member = "ada" },
+//!         { action = "contribute", member = "ben" },
+//!         { action = "advance", days = 7 },
+//!         { action = "payout" },
+//!     ]
+//!     expect = { payouts = [{ cycle = 1, member = "ada", amount = 200 }] }
+//! "#).unwrap();
+//!
+//! let report = ajo_sim::run(&scenario);
+//! assert!(report.passed(), "{report}");
+//! ```
+
+mod report;
+mod runner;
+mod scenario;
+
+pub use report::{Balance, CycleReport, Report, StepReport};
+pub use runner::run;
+pub use scenario::{
+    format_duration, Action, ExpectedPayout, Expectations, GroupParams, PenaltyPolicy, Scenario,
+    ScenarioError, Step,
+};
@@ -0,0 +1,41 @@
+//! `ajo-sim <scenario>...`: runs each scenario file and prints its report.
+//!
+//! Exits with status 1 if any scenario can't be loaded or doesn't pass.
+
+use std::process::ExitCode;
+
+use ajo_sim::{run, Scenario};
+
+const USAGE: &str = "usage: ajo-sim <scenario.toml|scenario.json>...";
+
+fn main() -> ExitCode {
+    let paths: Vec<String> = std::env::args().skip(1).collect();
+    if paths.is_empty() || paths.iter().any(|path| path == "-h" || path == "--help") {
+        eprintln!("{USAGE}");
+        return if paths.is_empty() { ExitCode::FAILURE } else { ExitCode::SUCCESS };
+    }
+
+    let mut passed = true;
+    for (index, path) in paths.iter().enumerate() {
+        if index > 0 {
+            println!();
+        }
+        match Scenario::load(path) {
+            Ok(scenario) => {
+                let report = run(&scenario);
+                print!("{report}");
+                passed &= report.passed();
+            }
+            Err(error) => {
+                eprintln!("{path}: {error}");
+                passed = false;
+            }
+        }
+    }
+
+    if passed {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
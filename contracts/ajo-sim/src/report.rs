@@ -0,0 +1,164 @@
+//! What a run did, cycle by cycle.
+
+use std::fmt;
+
+use crate::scenario::format_duration;
+
+/// The result of running a scenario.
+#[derive(Clone, Debug)]
+pub struct Report {
+    pub scenario: String,
+    pub group_id: u64,
+
+    /// Cycles in the order they ran, each with the steps taken during it.
+    pub cycles: Vec<CycleReport>,
+
+    /// Every way the run broke the scenario's expectations, empty when it passed.
+    pub failures: Vec<String>,
+}
+
+impl Report {
+    /// Whether every step behaved as expected and the outcome matched.
+    pub fn passed(&self) -> bool {
+        self.failures.is_empty()
+    }
+
+    /// All steps of the run, in order.
+    pub fn steps(&self) -> impl Iterator<Item = &StepReport> {
+        self.cycles.iter().flat_map(|cycle| &cycle.steps)
+    }
+}
+
+/// One cycle of the group.
+#[derive(Clone, Debug)]
+pub struct CycleReport {
+    pub cycle: u32,
+    pub steps: Vec<StepReport>,
+
+    /// Payouts made at the end of the cycle, as `(member, amount)` pairs.
+    pub payouts: Vec<(String, i128)>,
+
+    /// Late penalties charged during the cycle.
+    pub penalties: i128,
+
+    /// Each member's balance once the cycle ended, or at the end of the run.
+    pub balances: Vec<Balance>,
+}
+
+/// A single step and what it cost.
+#[derive(Clone, Debug)]
+pub struct StepReport {
+    /// Seconds since the run started.
+    pub elapsed: u64,
+
+    pub description: String,
+
+    /// The contract error the step failed with, if it did.
+    pub error: Option<String>,
+
+    /// Names of the events the step published.
+    pub events: Vec<&'static str>,
+
+    pub cpu_insns: u64,
+    pub mem_bytes: u64,
+}
+
+/// A member's money in and out of the group, per the contract's bookkeeping.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Balance {
+    pub member: String,
+
+    /// Contributions paid, including those paid for others.
+    pub paid: i128,
+
+    /// Late penalties charged.
+    pub penalties: i128,
+
+    /// Payouts, refunds, commission, penalty shares and keeper bounties received.
+    pub received: i128,
+}
+
+impl Balance {
+    /// What the member is up or down: `received - paid - penalties`.
+    pub fn net(&self) -> i128 {
+        self.received - self.paid - self.penalties
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Scenario: {} (group {})", self.scenario, self.group_id)?;
+        for cycle in &self.cycles {
+            writeln!(f)?;
+            write!(f, "{cycle}")?;
+        }
+
+        let (cpu, mem) = self
+            .steps()
+            .fold((0, 0), |(cpu, mem), step| (cpu + step.cpu_insns, mem + step.mem_bytes));
+        writeln!(f)?;
+        writeln!(f, "Resources: {cpu} CPU instructions, {mem} bytes in total")?;
+
+        if self.passed() {
+            writeln!(f, "Result: passed")
+        } else {
+            writeln!(f, "Result: {} failure(s)", self.failures.len())?;
+            for failure in &self.failures {
+                writeln!(f, "  - {failure}")?;
+            }
+            Ok(())
+        }
+    }
+}
+
+impl fmt::Display for CycleReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Cycle {}", self.cycle)?;
+        for step in &self.steps {
+            let outcome = match &step.error {
+                Some(error) => format!("error {error}"),
+                None => "ok".to_string(),
+            };
+            writeln!(
+                f,
+                "  [{:>12}] {:<36} {:<24} cpu {:>10} mem {:>9}",
+                format_duration(step.elapsed),
+                step.description,
+                outcome,
+                step.cpu_insns,
+                step.mem_bytes,
+            )?;
+            if !step.events.is_empty() {
+                writeln!(f, "  {:>14} events: {}", "", step.events.join(", "))?;
+            }
+        }
+
+        writeln!(f, "  Penalties charged: {}", self.penalties)?;
+        if self.payouts.is_empty() {
+            writeln!(f, "  Payouts: none")?;
+        } else {
+            writeln!(f, "  Payouts:")?;
+            for (member, amount) in &self.payouts {
+                writeln!(f, "    {member:<12} {amount:>16}")?;
+            }
+        }
+
+        writeln!(
+            f,
+            "  Balances:    {:>16} {:>16} {:>16} {:>16}",
+            "paid", "penalties", "received", "net"
+        )?;
+        for balance in &self.balances {
+            writeln!(
+                f,
+                "    {:<12} {:>16} {:>16} {:>16} {:>16}",
+                balance.member,
+                balance.paid,
+                balance.penalties,
+                balance.received,
+                balance.net(),
+            )?;
+        }
+        Ok(())
+    }
+}
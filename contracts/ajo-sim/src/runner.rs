@@ -0,0 +1,443 @@
+//! Running a scenario against the contract in a Soroban test `Env`.
+
+use std::collections::BTreeMap;
+use std::fmt::Debug;
+use std::mem;
+
+use ajo_indexer::{capture_events, strkey, AjoEvent, GroupHistory, Indexer};
+use soroban_ajo::{AjoContract, AjoContractClient, AjoError, OrganizerCommission};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{Address, Env, InvokeError};
+
+use crate::report::{Balance, CycleReport, Report, StepReport};
+use crate::scenario::{Action, Scenario, Step};
+
+/// Runs a scenario from a fresh contract and reports each cycle.
+///
+/// Every step is a separate contract call with its own resource budget. A step
+/// that fails doesn't stop the run; it is recorded as a failure unless the
+/// scenario expected that error.
+pub fn run(scenario: &Scenario) -> Report {
+    Simulation::new(scenario).run()
+}
+
+/// Flattens a `try_` client call into the value or the error's name.
+fn outcome<T, E: Debug>(result: Result<Result<T, E>, Result<AjoError, InvokeError>>) -> Result<T, String> {
+    match result {
+        Ok(Ok(value)) => Ok(value),
+        Ok(Err(error)) => Err(format!("{error:?}")),
+        Err(Ok(error)) => Err(format!("{error:?}")),
+        Err(Err(error)) => Err(format!("{error:?}")),
+    }
+}
+
+struct Simulation<'a> {
+    scenario: &'a Scenario,
+    env: Env,
+    client: AjoContractClient<'static>,
+    addresses: BTreeMap<String, Address>,
+    names: BTreeMap<String, String>,
+    indexer: Indexer,
+    events_seen: usize,
+    /// Penalty shares and keeper bounties by strkey, which the indexer doesn't keep.
+    earnings: BTreeMap<String, i128>,
+    group_id: u64,
+    cycles: Vec<CycleReport>,
+    steps: Vec<StepReport>,
+    failures: Vec<String>,
+}
+
+impl<'a> Simulation<'a> {
+    fn new(scenario: &'a Scenario) -> Self {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, AjoContract);
+        let client = AjoContractClient::new(&env, &contract_id);
+
+        let mut addresses = BTreeMap::new();
+        let mut names = BTreeMap::new();
+        for member in &scenario.members {
+            let address = Address::generate(&env);
+            names.insert(strkey(&address), member.clone());
+            addresses.insert(member.clone(), address);
+        }
+
+        Simulation {
+            scenario,
+            env,
+            client,
+            addresses,
+            names,
+            indexer: Indexer::new(),
+            events_seen: 0,
+            earnings: BTreeMap::new(),
+            group_id: 0,
+            cycles: Vec::new(),
+            steps: Vec::new(),
+            failures: Vec::new(),
+        }
+    }
+
+    fn run(mut self) -> Report {
+        let scenario = self.scenario;
+        if self.create_group() {
+            for (index, step) in scenario.timeline.iter().enumerate() {
+                self.step(index + 1, step);
+            }
+        }
+        if !self.steps.is_empty() || self.cycles.is_empty() {
+            let cycle = self.history().map_or(1, |history| history.group.current_cycle);
+            self.close_cycle(cycle);
+        }
+        self.check_expectations();
+
+        Report {
+            scenario: scenario.name.clone(),
+            group_id: self.group_id,
+            cycles: self.cycles,
+            failures: self.failures,
+        }
+    }
+
+    fn address(&self, name: &str) -> &Address {
+        &self.addresses[name]
+    }
+
+    fn name(&self, strkey: &str) -> String {
+        self.names.get(strkey).cloned().unwrap_or_else(|| strkey.to_string())
+    }
+
+    fn history(&self) -> Option<&GroupHistory> {
+        self.indexer.group(self.group_id)
+    }
+
+    /// Creates the group and applies its terms, returning whether it was created.
+    fn create_group(&mut self) -> bool {
+        let scenario = self.scenario;
+        let params = &scenario.group;
+        let creator = scenario.creator();
+        let max_members = params.max_members.unwrap_or(scenario.members.len() as u32);
+        let created = self.call(format!("{creator} creates the group"), None, |sim| {
+            outcome(sim.client.try_create_group(
+                sim.address(creator),
+                &params.contribution.into(),
+                &params.cycle_duration,
+                &max_members,
+                &params.grace_period,
+                &params.penalty_rate,
+            ))
+        });
+        let Some(group_id) = created else {
+            return false;
+        };
+        self.group_id = group_id;
+
+        if let Some(policy) = params.penalty_policy {
+            self.call(format!("penalty policy {policy:?}"), None, |sim| {
+                outcome(sim.client.try_set_penalty_policy(&sim.group_id, &policy.into()))
+            });
+        }
+        if let Some(bps) = params.commission_bps {
+            self.call(format!("commission {bps} bps"), None, |sim| {
+                let commission = OrganizerCommission::BasisPoints(bps);
+                outcome(sim.client.try_set_organizer_commission(&sim.group_id, &commission))
+            });
+        }
+        if let Some(bounty) = params.keeper_bounty {
+            self.call(format!("keeper bounty {bounty}"), None, |sim| {
+                outcome(sim.client.try_set_keeper_bounty(&sim.group_id, &bounty.into()))
+            });
+        }
+        if let Some(recipients) = params.recipients_per_cycle {
+            self.call(format!("{recipients} recipients per cycle"), None, |sim| {
+                outcome(sim.client.try_set_recipients_per_cycle(&sim.group_id, &recipients))
+            });
+        }
+        true
+    }
+
+    fn step(&mut self, index: usize, step: &Step) {
+        let description = format!("#{index} {}", step.action);
+        let expect_error = step.expect_error.as_deref();
+        match &step.action {
+            Action::Join { member } => self.call(description, expect_error, |sim| {
+                outcome(sim.client.try_join_group(sim.address(member), &sim.group_id))
+            }),
+            Action::Contribute { member, amount: None } => {
+                self.call(description, expect_error, |sim| {
+                    outcome(sim.client.try_contribute(sim.address(member), &sim.group_id))
+                })
+            }
+            Action::Contribute { member, amount: Some(amount) } => {
+                self.call(description, expect_error, |sim| {
+                    outcome(sim.client.try_contribute_partial(
+                        sim.address(member),
+                        &sim.group_id,
+                        &(*amount).into(),
+                    ))
+                })
+            }
+            Action::ContributeFor { payer, member } => self.call(description, expect_error, |sim| {
+                outcome(sim.client.try_contribute_for(
+                    sim.address(payer),
+                    sim.address(member),
+                    &sim.group_id,
+                ))
+            }),
+            Action::Prepay { member, cycles } => self.call(description, expect_error, |sim| {
+                outcome(sim.client.try_prepay(sim.address(member), &sim.group_id, cycles))
+                    .map(|_| ())
+            }),
+            Action::Advance { days, hours, seconds } => {
+                let duration = days * 86_400 + hours * 3_600 + seconds;
+                self.call(description, expect_error, |sim| {
+                    sim.env.ledger().with_mut(|ledger| ledger.timestamp += duration);
+                    Ok(())
+                })
+            }
+            Action::Payout => self.call(description, expect_error, |sim| {
+                outcome(sim.client.try_execute_payout(&sim.group_id))
+            }),
+            Action::Crank { keeper } => self.call(description, expect_error, |sim| {
+                outcome(sim.client.try_crank(sim.address(keeper), &sim.group_id)).map(|_| ())
+            }),
+            Action::RequestRefund { member } => self.call(description, expect_error, |sim| {
+                outcome(sim.client.try_request_refund(sim.address(member), &sim.group_id))
+            }),
+            Action::VoteRefund { member, in_favor } => {
+                self.call(description, expect_error, |sim| {
+                    outcome(sim.client.try_vote_refund(
+                        sim.address(member),
+                        &sim.group_id,
+                        in_favor,
+                    ))
+                })
+            }
+            Action::ExecuteRefund { member } => self.call(description, expect_error, |sim| {
+                outcome(sim.client.try_execute_refund(sim.address(member), &sim.group_id))
+            }),
+            Action::Cancel => self.call(description, expect_error, |sim| {
+                let creator = sim.address(sim.scenario.creator());
+                outcome(sim.client.try_cancel_group(creator, &sim.group_id))
+            }),
+            Action::OptIn { member } => self.call(description, expect_error, |sim| {
+                outcome(sim.client.try_opt_in_next_round(sim.address(member), &sim.group_id))
+            }),
+        };
+    }
+
+    /// Runs one contract call with a fresh budget, records it as a step, and
+    /// closes the cycle if the call ended it.
+    fn call<T>(
+        &mut self,
+        description: String,
+        expect_error: Option<&str>,
+        invoke: impl FnOnce(&Self) -> Result<T, String>,
+    ) -> Option<T> {
+        let before = self.history().map(|history| history.group.current_cycle);
+
+        self.env.budget().reset_default();
+        let result = invoke(self);
+        let budget = self.env.budget();
+        let (cpu_insns, mem_bytes) = (budget.cpu_instruction_cost(), budget.memory_bytes_cost());
+
+        let raw_events = capture_events(&self.env, &self.client.address);
+        let mut events = Vec::new();
+        for raw in raw_events.iter().skip(self.events_seen) {
+            match self.indexer.ingest(raw) {
+                Ok(event) => {
+                    let earned = match &event {
+                        AjoEvent::PenaltyShared(share) => Some((&share.member, share.amount)),
+                        AjoEvent::KeeperCranked(crank) => Some((&crank.keeper, crank.bounty)),
+                        _ => None,
+                    };
+                    if let Some((address, amount)) = earned {
+                        *self.earnings.entry(strkey(address)).or_default() += amount;
+                    }
+                    events.push(event.name());
+                }
+                Err(error) => self.failures.push(format!("{description}: {error}")),
+            }
+        }
+        self.events_seen = raw_events.len();
+
+        let error = result.as_ref().err().cloned();
+        match (expect_error, &error) {
+            (None, Some(error)) => self.failures.push(format!("{description}: failed with {error}")),
+            (Some(expected), None) => {
+                self.failures.push(format!("{description}: expected {expected}, but it succeeded"))
+            }
+            (Some(expected), Some(error)) if expected != error => self
+                .failures
+                .push(format!("{description}: expected {expected}, but it failed with {error}")),
+            _ => {}
+        }
+
+        self.steps.push(StepReport {
+            elapsed: self.env.ledger().timestamp(),
+            description,
+            error,
+            events,
+            cpu_insns,
+            mem_bytes,
+        });
+
+        let after = self.history().map(|history| history.group.current_cycle);
+        if let (Some(before), Some(after)) = (before, after) {
+            if before != after {
+                self.close_cycle(before);
+            }
+        }
+        result.ok()
+    }
+
+    fn close_cycle(&mut self, cycle: u32) {
+        let mut payouts = Vec::new();
+        let mut penalties = 0;
+        if let Some(history) = self.history() {
+            for payout in history.payouts.iter().filter(|payout| payout.cycle == cycle) {
+                if payout.shares.is_empty() {
+                    payouts.push((self.name(&payout.member), payout.amount));
+                } else {
+                    for (recipient, amount) in &payout.shares {
+                        payouts.push((self.name(recipient), *amount));
+                    }
+                }
+            }
+            penalties = history.penalty_pools.get(&cycle).copied().unwrap_or(0);
+        }
+
+        let balances = self.balances();
+        self.cycles.push(CycleReport {
+            cycle,
+            steps: mem::take(&mut self.steps),
+            payouts,
+            penalties,
+            balances,
+        });
+    }
+
+    /// Each scenario member's balance so far.
+    fn balances(&self) -> Vec<Balance> {
+        let mut balances: Vec<Balance> = self
+            .scenario
+            .members
+            .iter()
+            .map(|member| Balance { member: member.clone(), ..Balance::default() })
+            .collect();
+        let Some(history) = self.history() else {
+            return balances;
+        };
+
+        let mut credit = |strkey: &str, update: &dyn Fn(&mut Balance)| {
+            let name = self.name(strkey);
+            if let Some(balance) = balances.iter_mut().find(|balance| balance.member == name) {
+                update(balance);
+            }
+        };
+        for ((_, member), contribution) in &history.contributions {
+            let payer = contribution.sponsor.as_deref().unwrap_or(member);
+            credit(payer, &|balance| balance.paid += contribution.amount_paid);
+        }
+        for (member, record) in &history.penalties {
+            credit(member, &|balance| balance.penalties += record.total_penalties);
+        }
+        for payout in &history.payouts {
+            if payout.shares.is_empty() {
+                credit(&payout.member, &|balance| balance.received += payout.amount);
+            }
+            for (recipient, amount) in &payout.shares {
+                credit(recipient, &|balance| balance.received += amount);
+            }
+        }
+        for refund in &history.refunds {
+            credit(&refund.member, &|balance| balance.received += refund.amount);
+        }
+        let commission: i128 = history.commissions.values().sum();
+        credit(&history.group.creator, &|balance| balance.received += commission);
+        for (address, amount) in &self.earnings {
+            credit(address, &|balance| balance.received += amount);
+        }
+        balances
+    }
+
+    fn check_expectations(&mut self) {
+        let expect = &self.scenario.expect;
+        let mut failures = Vec::new();
+
+        if let Some(max) = expect.max_step_cpu_insns {
+            for step in self.cycles.iter().flat_map(|cycle| &cycle.steps) {
+                if step.cpu_insns > max {
+                    failures.push(format!(
+                        "{}: used {} CPU instructions, over {max}",
+                        step.description, step.cpu_insns
+                    ));
+                }
+            }
+        }
+
+        let Some(history) = self.history() else {
+            self.failures.extend(failures);
+            return;
+        };
+        let group = &history.group;
+
+        if let Some(is_complete) = expect.is_complete {
+            if group.is_complete != is_complete {
+                failures.push(format!("is_complete: expected {is_complete}, got {}", group.is_complete));
+            }
+        }
+        if let Some(cycle) = expect.current_cycle {
+            if group.current_cycle != cycle {
+                failures.push(format!("current_cycle: expected {cycle}, got {}", group.current_cycle));
+            }
+        }
+        if let Some(state) = &expect.state {
+            let actual = format!("{:?}", group.state);
+            if &actual != state {
+                failures.push(format!("state: expected {state}, got {actual}"));
+            }
+        }
+        if let Some(expected) = &expect.payouts {
+            let expected: Vec<(u32, String, i128)> = expected
+                .iter()
+                .map(|payout| (payout.cycle, payout.member.clone(), payout.amount.into()))
+                .collect();
+            let actual: Vec<(u32, String, i128)> = history
+                .payouts
+                .iter()
+                .map(|payout| (payout.cycle, self.name(&payout.member), payout.amount))
+                .collect();
+            if actual != expected {
+                failures.push(format!("payouts: expected {expected:?}, got {actual:?}"));
+            }
+        }
+        for (member, expected) in &expect.penalties {
+            let actual = history
+                .penalty_record(&strkey(self.address(member)))
+                .map_or(0, |record| record.total_penalties);
+            if actual != i128::from(*expected) {
+                failures.push(format!("penalties of {member}: expected {expected}, got {actual}"));
+            }
+        }
+        let balances = self.balances();
+        for (member, expected) in &expect.balances {
+            let actual = balances
+                .iter()
+                .find(|balance| &balance.member == member)
+                .map_or(0, Balance::net);
+            if actual != i128::from(*expected) {
+                failures.push(format!("balance of {member}: expected {expected}, got {actual}"));
+            }
+        }
+
+        // The report is built from events, so make sure they tell the same story as the contract
+        let mismatches = history.verify(
+            &self.client.get_group(&self.group_id),
+            &self.client.get_group_status(&self.group_id),
+        );
+        failures.extend(mismatches.iter().map(|mismatch| format!("event replay: {mismatch}")));
+
+        self.failures.extend(failures);
+    }
+}
@@ -0,0 +1,337 @@
+//! Scenario files: who takes part, the group's terms, and what happens when.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+use serde::Deserialize;
+
+/// A group design and the timeline to rehearse it with.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Scenario {
+    /// Shown at the top of the report.
+    pub name: String,
+
+    #[serde(default)]
+    pub description: String,
+
+    /// Everyone the timeline refers to, by name. The first creates the group;
+    /// the others only become members through a `join` step.
+    pub members: Vec<String>,
+
+    pub group: GroupParams,
+
+    /// Steps run in order, each as its own contract call.
+    pub timeline: Vec<Step>,
+
+    /// What the run must end with for the scenario to pass.
+    #[serde(default)]
+    pub expect: Expectations,
+}
+
+/// The group's terms, set by the creator before anyone joins.
+///
+/// Amounts here and elsewhere in a scenario are `i64` stroops, since TOML
+/// integers are 64-bit.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct GroupParams {
+    /// Contribution per member per cycle, in stroops.
+    pub contribution: i64,
+
+    /// Cycle length in seconds.
+    pub cycle_duration: u64,
+
+    /// Defaults to the number of scenario members.
+    pub max_members: Option<u32>,
+
+    /// Seconds after the cycle ends that late contributions are still accepted.
+    #[serde(default)]
+    pub grace_period: u64,
+
+    /// Late penalty in percent.
+    #[serde(default)]
+    pub penalty_rate: u32,
+
+    pub penalty_policy: Option<PenaltyPolicy>,
+
+    /// Organizer commission in basis points of each payout.
+    pub commission_bps: Option<u32>,
+
+    /// Bounty paid to keepers who crank an overdue payout.
+    pub keeper_bounty: Option<i64>,
+
+    pub recipients_per_cycle: Option<u32>,
+}
+
+/// Mirrors [`soroban_ajo::PenaltyPolicy`], by snake_case name.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum PenaltyPolicy {
+    NextRecipient,
+    OnTimeContributors,
+    GroupReserve,
+    InsurancePool,
+    Treasury,
+}
+
+impl From<PenaltyPolicy> for soroban_ajo::PenaltyPolicy {
+    fn from(policy: PenaltyPolicy) -> Self {
+        match policy {
+            PenaltyPolicy::NextRecipient => soroban_ajo::PenaltyPolicy::NextRecipient,
+            PenaltyPolicy::OnTimeContributors => soroban_ajo::PenaltyPolicy::OnTimeContributors,
+            PenaltyPolicy::GroupReserve => soroban_ajo::PenaltyPolicy::GroupReserve,
+            PenaltyPolicy::InsurancePool => soroban_ajo::PenaltyPolicy::InsurancePool,
+            PenaltyPolicy::Treasury => soroban_ajo::PenaltyPolicy::Treasury,
+        }
+    }
+}
+
+/// One entry of the timeline.
+#[derive(Clone, Debug, Deserialize)]
+pub struct Step {
+    #[serde(flatten)]
+    pub action: Action,
+
+    /// The `AjoError` the step must fail with, e.g. `"GracePeriodExpired"`.
+    /// Without it the step must succeed.
+    pub expect_error: Option<String>,
+}
+
+/// What a step does, selected by its `action` field.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(tag = "action", rename_all = "snake_case")]
+pub enum Action {
+    /// `join_group`.
+    Join { member: String },
+
+    /// `contribute`, or `contribute_partial` when an amount is given.
+    Contribute { member: String, amount: Option<i64> },
+
+    /// `contribute_for`: `payer` covers `member`'s contribution.
+    ContributeFor { payer: String, member: String },
+
+    /// `prepay` for the next `cycles` cycles.
+    Prepay { member: String, cycles: u32 },
+
+    /// Moves the ledger clock forward; the parts are added together.
+    Advance {
+        #[serde(default)]
+        days: u64,
+        #[serde(default)]
+        hours: u64,
+        #[serde(default)]
+        seconds: u64,
+    },
+
+    /// `execute_payout`.
+    Payout,
+
+    /// `crank` as `keeper`.
+    Crank { keeper: String },
+
+    /// `request_refund`.
+    RequestRefund { member: String },
+
+    /// `vote_refund`.
+    VoteRefund { member: String, in_favor: bool },
+
+    /// `execute_refund`.
+    ExecuteRefund { member: String },
+
+    /// `cancel_group` by the creator.
+    Cancel,
+
+    /// `opt_in_next_round`.
+    OptIn { member: String },
+}
+
+impl Action {
+    /// The scenario members the step refers to.
+    pub fn participants(&self) -> Vec<&str> {
+        match self {
+            Action::Join { member }
+            | Action::Contribute { member, .. }
+            | Action::Prepay { member, .. }
+            | Action::RequestRefund { member }
+            | Action::VoteRefund { member, .. }
+            | Action::ExecuteRefund { member }
+            | Action::OptIn { member } => vec![member],
+            Action::ContributeFor { payer, member } => vec![payer, member],
+            Action::Crank { keeper } => vec![keeper],
+            Action::Advance { .. } | Action::Payout | Action::Cancel => Vec::new(),
+        }
+    }
+}
+
+impl fmt::Display for Action {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Action::Join { member } => write!(f, "{member} joins"),
+            Action::Contribute { member, amount: None } => write!(f, "{member} contributes"),
+            Action::Contribute { member, amount: Some(amount) } => {
+                write!(f, "{member} contributes {amount}")
+            }
+            Action::ContributeFor { payer, member } => write!(f, "{payer} contributes for {member}"),
+            Action::Prepay { member, cycles } => write!(f, "{member} prepays {cycles} cycles"),
+            Action::Advance { days, hours, seconds } => {
+                write!(f, "advance {}", format_duration(days * 86_400 + hours * 3_600 + seconds))
+            }
+            Action::Payout => write!(f, "payout"),
+            Action::Crank { keeper } => write!(f, "{keeper} cranks"),
+            Action::RequestRefund { member } => write!(f, "{member} requests a refund"),
+            Action::VoteRefund { member, in_favor: true } => write!(f, "{member} votes for refund"),
+            Action::VoteRefund { member, in_favor: false } => {
+                write!(f, "{member} votes against refund")
+            }
+            Action::ExecuteRefund { member } => write!(f, "{member} executes the refund"),
+            Action::Cancel => write!(f, "creator cancels"),
+            Action::OptIn { member } => write!(f, "{member} opts into the next round"),
+        }
+    }
+}
+
+/// Formats seconds as `3d 04:05:06`, leaving out zero days.
+pub fn format_duration(seconds: u64) -> String {
+    let (days, rest) = (seconds / 86_400, seconds % 86_400);
+    let clock = format!("{:02}:{:02}:{:02}", rest / 3_600, rest % 3_600 / 60, rest % 60);
+    if days > 0 {
+        format!("{days}d {clock}")
+    } else {
+        clock
+    }
+}
+
+/// The outcome a scenario is checked against; unset fields aren't checked.
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Expectations {
+    pub is_complete: Option<bool>,
+
+    pub current_cycle: Option<u32>,
+
+    /// The group's `GroupState`, e.g. `"Active"` or `"Cancelled"`.
+    pub state: Option<String>,
+
+    /// Every payout, in order.
+    pub payouts: Option<Vec<ExpectedPayout>>,
+
+    /// Total late penalties charged, by member.
+    #[serde(default)]
+    pub penalties: BTreeMap<String, i64>,
+
+    /// Net balance at the end of the run, by member.
+    #[serde(default)]
+    pub balances: BTreeMap<String, i64>,
+
+    /// Upper bound on the CPU instructions of any single step.
+    pub max_step_cpu_insns: Option<u64>,
+}
+
+/// A payout the run must make.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct ExpectedPayout {
+    pub cycle: u32,
+    pub member: String,
+    pub amount: i64,
+}
+
+/// Why a scenario couldn't be loaded.
+#[derive(Debug)]
+pub enum ScenarioError {
+    Io(std::io::Error),
+    /// The file isn't `.toml` or `.json`.
+    UnknownFormat(String),
+    Parse(String),
+    /// The scenario refers to a name that isn't one of its members.
+    UnknownMember(String),
+    /// The scenario has no members to create the group.
+    NoMembers,
+}
+
+impl fmt::Display for ScenarioError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScenarioError::Io(error) => write!(f, "{error}"),
+            ScenarioError::UnknownFormat(path) => {
+                write!(f, "{path}: expected a .toml or .json scenario")
+            }
+            ScenarioError::Parse(message) => write!(f, "{message}"),
+            ScenarioError::UnknownMember(name) => write!(f, "unknown member `{name}`"),
+            ScenarioError::NoMembers => write!(f, "a scenario needs at least one member"),
+        }
+    }
+}
+
+impl std::error::Error for ScenarioError {}
+
+impl Scenario {
+    /// Reads a scenario, choosing the format by the file's extension.
+    ///
+    /// # Errors
+    /// `Io` if the file can't be read, `UnknownFormat` for other extensions, and
+    /// any error from [`from_toml`](Self::from_toml) or [`from_json`](Self::from_json).
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, ScenarioError> {
+        let path = path.as_ref();
+        let source = fs::read_to_string(path).map_err(ScenarioError::Io)?;
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some("toml") => Self::from_toml(&source),
+            Some("json") => Self::from_json(&source),
+            _ => Err(ScenarioError::UnknownFormat(path.display().to_string())),
+        }
+    }
+
+    /// Parses and validates a TOML scenario.
+    ///
+    /// # Errors
+    /// `Parse` for malformed scenarios, or any error from [`validate`](Self::validate).
+    pub fn from_toml(source: &str) -> Result<Self, ScenarioError> {
+        let scenario: Scenario =
+            toml::from_str(source).map_err(|error| ScenarioError::Parse(error.to_string()))?;
+        scenario.validate()?;
+        Ok(scenario)
+    }
+
+    /// Parses and validates a JSON scenario.
+    ///
+    /// # Errors
+    /// `Parse` for malformed scenarios, or any error from [`validate`](Self::validate).
+    pub fn from_json(source: &str) -> Result<Self, ScenarioError> {
+        let scenario: Scenario = serde_json::from_str(source)
+            .map_err(|error| ScenarioError::Parse(error.to_string()))?;
+        scenario.validate()?;
+        Ok(scenario)
+    }
+
+    /// Checks that the scenario only refers to its own members.
+    ///
+    /// # Errors
+    /// * `NoMembers` - If no member is listed
+    /// * `UnknownMember` - If a step or expectation names someone else
+    pub fn validate(&self) -> Result<(), ScenarioError> {
+        if self.members.is_empty() {
+            return Err(ScenarioError::NoMembers);
+        }
+        let names = self
+            .timeline
+            .iter()
+            .flat_map(|step| step.action.participants())
+            .chain(self.expect.payouts.iter().flatten().map(|payout| payout.member.as_str()))
+            .chain(self.expect.penalties.keys().map(String::as_str))
+            .chain(self.expect.balances.keys().map(String::as_str));
+        for name in names {
+            if !self.members.iter().any(|member| member == name) {
+                return Err(ScenarioError::UnknownMember(name.to_string()));
+            }
+        }
+        Ok(())
+    }
+
+    /// The member who creates the group.
+    pub fn creator(&self) -> &str {
+        &self.members[0]
+    }
+}
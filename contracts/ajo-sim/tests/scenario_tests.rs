@@ -0,0 +1,132 @@
+use std::fs;
+use std::path::Path;
+
+use ajo_sim::{run, Scenario, ScenarioError};
+
+/// A two-member group paid out once, with `expect` appended
+fn pair_scenario(expect: &str) -> String {
+    format!(
+        r#"
+        name = "pair"
+        members = ["ada", "ben", "cy"]
+        group = {{ contribution = 100, cycle_duration = 604800, max_members = 2 }}
+        timeline = [
+            {{ action = "join", member = "ben" }},
+            {{ action = "contribute", member = "ada" }},
+            {{ action = "contribute", member = "ben" }},
+            {{ action = "advance", days = 7 }},
+            {{ action = "payout" }},
+        ]
+        {expect}
+        "#
+    )
+}
+
+#[test]
+fn test_bundled_scenarios_pass() {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("scenarios");
+    let mut count = 0;
+    for entry in fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        let scenario = Scenario::load(&path).unwrap_or_else(|error| panic!("{path:?}: {error}"));
+        let report = run(&scenario);
+        assert!(report.passed(), "{path:?}\n{report}");
+        count += 1;
+    }
+    assert!(count >= 3);
+}
+
+#[test]
+fn test_report_is_split_by_cycle() {
+    let scenario = Scenario::from_toml(&pair_scenario("")).unwrap();
+    let report = run(&scenario);
+    assert!(report.passed(), "{report}");
+
+    // The payout ends cycle 1, and cycle 2 has no steps to report
+    assert_eq!(report.cycles.len(), 1);
+    let cycle = &report.cycles[0];
+    assert_eq!(cycle.cycle, 1);
+    assert_eq!(cycle.steps.len(), 6);
+    assert_eq!(cycle.payouts, vec![("ada".to_string(), 200)]);
+    assert_eq!(cycle.balances[0].net(), 100);
+    assert_eq!(cycle.balances[1].net(), -100);
+    assert_eq!(cycle.balances[2].net(), 0);
+    assert_eq!(cycle.steps[5].events, vec!["payout", "cycle"]);
+    assert!(cycle.steps[5].cpu_insns > 0);
+    assert_eq!(cycle.steps[4].cpu_insns, 0);
+}
+
+#[test]
+fn test_unexpected_errors_fail_the_run() {
+    let scenario = Scenario::from_json(
+        r#"{
+            "name": "full",
+            "members": ["ada", "ben", "cy"],
+            "group": { "contribution": 100, "cycle_duration": 604800, "max_members": 2 },
+            "timeline": [
+                { "action": "join", "member": "ben" },
+                { "action": "join", "member": "cy" },
+                { "action": "payout", "expect_error": "IncompleteContributions" },
+                { "action": "contribute", "member": "ada", "expect_error": "NotMember" }
+            ]
+        }"#,
+    )
+    .unwrap();
+    let report = run(&scenario);
+
+    assert!(!report.passed());
+    assert_eq!(
+        report.failures,
+        vec![
+            "#2 cy joins: failed with MaxMembersExceeded".to_string(),
+            "#4 ada contributes: expected NotMember, but it succeeded".to_string(),
+        ]
+    );
+    let step = report.steps().find(|step| step.description == "#2 cy joins").unwrap();
+    assert_eq!(step.error.as_deref(), Some("MaxMembersExceeded"));
+}
+
+#[test]
+fn test_missed_expectations_are_reported() {
+    let scenario = Scenario::from_toml(&pair_scenario(
+        r#"
+        [expect]
+        is_complete = true
+        current_cycle = 2
+        payouts = [{ cycle = 1, member = "ben", amount = 200 }]
+        balances = { ada = 100, ben = 0 }
+        max_step_cpu_insns = 1
+        "#,
+    ))
+    .unwrap();
+    let report = run(&scenario);
+
+    let failures: Vec<&str> = report
+        .failures
+        .iter()
+        .map(|failure| failure.split(':').next().unwrap())
+        .collect();
+    assert_eq!(failures[0], "ada creates the group");
+    assert!(failures.contains(&"is_complete"));
+    assert!(failures.contains(&"payouts"));
+    assert!(failures.contains(&"balance of ben"));
+    assert!(!failures.contains(&"current_cycle"));
+    assert!(!failures.contains(&"balance of ada"));
+}
+
+#[test]
+fn test_invalid_scenarios_are_rejected() {
+    let unknown = pair_scenario(r#"expect = { penalties = { dan = 0 } }"#);
+    assert!(matches!(
+        Scenario::from_toml(&unknown),
+        Err(ScenarioError::UnknownMember(name)) if name == "dan"
+    ));
+
+    let typo = pair_scenario(r#"expect = { payout = [] }"#);
+    assert!(matches!(Scenario::from_toml(&typo), Err(ScenarioError::Parse(_))));
+
+    assert!(matches!(
+        Scenario::load("scenarios/missing.yaml"),
+        Err(ScenarioError::Io(_))
+    ));
+}
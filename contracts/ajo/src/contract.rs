@@ -1,11 +1,19 @@
 use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, Vec};
 
+use crate::contribution;
 use crate::errors::AjoError;
 use crate::events;
+use crate::insurance;
 use crate::pausable;
+use crate::payout;
 use crate::storage;
-use crate::types::{Group, GroupMetadata, GroupStatus};
+use crate::types::{
+    AutoContributeMandate, CycleSchedule, Group, GroupKind, GroupMetadata, GroupStatus, GroupTemplate, GroupTerms, InsuranceClaim,
+    OrganizerCommission, PayoutRecord, PayoutShare, PayoutSplit, PenaltyPolicy, PenaltySchedule,
+    SwapProposal, VaultPosition, YieldPolicy, MAX_SEAT_HOLDERS,
+};
 use crate::utils;
+use crate::vault;
 
 /// The main Ajo contract
 #[contract]
@@ -32,6 +40,7 @@ impl AjoContract {
             return Err(AjoError::AlreadyInitialized);
         }
         storage::store_admin(&env, &admin);
+        events::emit_initialized(&env, &admin);
         Ok(())
     }
 
@@ -52,6 +61,7 @@ impl AjoContract {
     pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) -> Result<(), AjoError> {
         let admin = storage::get_admin(&env).ok_or(AjoError::Unauthorized)?;
         admin.require_auth();
+        events::emit_upgraded(&env, &admin, &new_wasm_hash);
         env.deployer().update_current_contract_wasm(new_wasm_hash);
         Ok(())
     }
@@ -143,10 +153,43 @@ impl AjoContract {
         max_members: u32,
         grace_period: u64,
         penalty_rate: u32,
+    ) -> Result<u64, AjoError> {
+        let terms = utils::default_terms(
+            contribution_amount,
+            cycle_duration,
+            max_members,
+            grace_period,
+            penalty_rate,
+        );
+        Self::create_group_with_terms(env, creator, terms)
+    }
+
+    /// Create a new Ajo group with all of its terms set at once.
+    ///
+    /// Works like [`create_group`](Self::create_group), but instead of starting from
+    /// the defaults and configuring each term with its setter before anyone joins,
+    /// the creator passes every term up front, such as the organizer commission,
+    /// a calendar cycle schedule or an insurance premium. Each term is validated
+    /// the same way as by its setter.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `creator` - Address of the group creator (automatically becomes first member)
+    /// * `terms` - The group's terms
+    ///
+    /// # Returns
+    /// The unique group ID assigned to the new group
+    ///
+    /// # Errors
+    /// The errors of `create_group`, plus those of the setter of each term (see
+    /// [`utils::validate_group_terms`]).
+    pub fn create_group_with_terms(
+        env: Env,
+        creator: Address,
+        terms: GroupTerms,
     ) -> Result<u64, AjoError> {
         // Validate parameters
-        utils::validate_group_params(contribution_amount, cycle_duration, max_members)?;
-        utils::validate_penalty_params(grace_period, penalty_rate)?;
+        utils::validate_group_terms(&terms)?;
 
         // Check if paused
         pausable::ensure_not_paused(&env)?;
@@ -160,33 +203,21 @@ impl AjoContract {
         // Initialize members list with creator
         let mut members = Vec::new(&env);
         members.push_back(creator.clone());
+        let mut seat_tiers = Vec::new(&env);
+        seat_tiers.push_back(1);
 
         // Get current timestamp
         let now = utils::get_current_timestamp(&env);
 
         // Create group
-        let group = Group {
-            id: group_id,
-            creator: creator.clone(),
-            contribution_amount,
-            cycle_duration,
-            max_members,
-            members,
-            current_cycle: 1,
-            payout_index: 0,
-            created_at: now,
-            cycle_start_time: now,
-            is_complete: false,
-            grace_period,
-            penalty_rate,
-            state: crate::types::GroupState::Active,
-        };
+        let group = utils::new_group(&terms, group_id, creator, members, seat_tiers, now);
 
         // Store group
         storage::store_group(&env, group_id, &group);
 
-        // Emit event
-        events::emit_group_created(&env, group_id, &creator, contribution_amount, max_members);
+        // Emit events
+        events::emit_group_created(&env, &group);
+        events::emit_group_terms(&env, &group);
 
         Ok(group_id)
     }
@@ -213,6 +244,7 @@ impl AjoContract {
     ///
     /// Returns the ordered list of all member addresses currently in the group.
     /// Members are ordered by join time, with the creator being the first member.
+    /// A member holding several seats is listed once per seat.
     ///
     /// # Arguments
     /// * `env` - The Soroban contract environment
@@ -234,6 +266,11 @@ impl AjoContract {
     /// The member's authentication is required. The member cannot join if they
     /// are already a member, the group is full, or the group has completed all cycles.
     ///
+    /// When the group allows more than one seat per member, an existing member can
+    /// call this again to take another seat, with its own payout turn at the end of
+    /// the current order. Extra seats can't be taken once the member has paid toward
+    /// the current cycle or prepaid later ones.
+    ///
     /// # Arguments
     /// * `env` - The Soroban contract environment
     /// * `member` - Address of the member joining (must authenticate)
@@ -245,8 +282,10 @@ impl AjoContract {
     /// # Errors
     /// * `GroupNotFound` - If the group does not exist
     /// * `MaxMembersExceeded` - If the group has reached max members
-    /// * `AlreadyMember` - If the address is already a member
+    /// * `AlreadyMember` - If the address already holds the maximum number of seats, or shares a seat
+    /// * `AlreadyContributed` - If a member taking another seat has already paid this cycle
     /// * `GroupComplete` - If the group has completed all cycles
+    /// * `InsufficientBalance` - If the new seat would leave a tiered payout unfunded
     pub fn join_group(env: Env, member: Address, group_id: u64) -> Result<(), AjoError> {
         // Check if paused
         pausable::ensure_not_paused(&env)?;
@@ -262,18 +301,33 @@ impl AjoContract {
             return Err(AjoError::GroupComplete);
         }
 
-        // Check if already a member
-        if utils::is_member(&group.members, &member) {
+        // Check if already holding every seat allowed, or sharing a seat
+        let seats = utils::member_seats(&env, &group.members, &member).len();
+        if seats >= group.max_seats_per_member
+            || storage::get_shared_seat(&env, group_id, &member).is_some()
+        {
             return Err(AjoError::AlreadyMember);
         }
 
+        // An extra seat raises what the member owes, so it can't follow a payment
+        if seats > 0 {
+            let cycle = group.current_cycle;
+            if storage::get_contribution_detail(&env, group_id, cycle, &member).is_some()
+                || storage::get_prepaid_through(&env, group_id, &member) > cycle
+            {
+                return Err(AjoError::AlreadyContributed);
+            }
+        }
+
         // Check if group is full
         if group.members.len() >= group.max_members {
             return Err(AjoError::MaxMembersExceeded);
         }
 
-        // Add member
+        // Add member at the base tier
         group.members.push_back(member.clone());
+        group.seat_tiers.push_back(1);
+        utils::validate_tier_funding(&group)?;
 
         // Update storage
         storage::store_group(&env, group_id, &group);
@@ -284,9 +338,106 @@ impl AjoContract {
         Ok(())
     }
 
+    /// Join a group as co-holders of one shared seat.
+    ///
+    /// The co-holders split the seat's contribution equally each cycle, and
+    /// the seat only counts as contributed once every co-holder has paid their share.
+    /// The seat takes one payout turn, listed under the first holder, and its payout
+    /// is split among the co-holders in proportion to their shares. Co-holders can't
+    /// hold any other seat in the group.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `group_id` - The group to join
+    /// * `holders` - The co-holders (each must authenticate), from 2 up to
+    ///   [`MAX_SEAT_HOLDERS`](crate::types::MAX_SEAT_HOLDERS)
+    ///
+    /// # Returns
+    /// The seat's position in the payout order
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    /// * `GroupComplete` - If the group has completed all cycles
+    /// * `MaxMembersBelowMinimum` - If fewer than two co-holders are given
+    /// * `MaxMembersAboveLimit` - If more than `MAX_SEAT_HOLDERS` co-holders are given
+    /// * `AlreadyMember` - If a co-holder is already a member or is listed twice
+    /// * `MaxMembersExceeded` - If the group has reached max members
+    /// * `InsufficientBalance` - If the new seat would leave a tiered payout unfunded
+    pub fn join_shared_seat(env: Env, group_id: u64, holders: Vec<Address>) -> Result<u32, AjoError> {
+        // Check if paused
+        pausable::ensure_not_paused(&env)?;
+
+        if holders.len() < 2 {
+            return Err(AjoError::MaxMembersBelowMinimum);
+        }
+        if holders.len() > MAX_SEAT_HOLDERS {
+            return Err(AjoError::MaxMembersAboveLimit);
+        }
+        for (index, holder) in holders.iter().enumerate() {
+            if holders.first_index_of(&holder) != Some(index as u32) {
+                return Err(AjoError::AlreadyMember);
+            }
+        }
+
+        // Every co-holder signs up for their share
+        for holder in holders.iter() {
+            holder.require_auth();
+        }
+
+        let mut group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+
+        if group.is_complete {
+            return Err(AjoError::GroupComplete);
+        }
+
+        for holder in holders.iter() {
+            if utils::holds_seat(&env, &group, &holder) {
+                return Err(AjoError::AlreadyMember);
+            }
+        }
+
+        if group.members.len() >= group.max_members {
+            return Err(AjoError::MaxMembersExceeded);
+        }
+
+        // The seat is listed under its first holder
+        let primary = holders.get_unchecked(0);
+        let seat = group.members.len();
+        group.members.push_back(primary.clone());
+        group.seat_tiers.push_back(1);
+        utils::validate_tier_funding(&group)?;
+        storage::store_group(&env, group_id, &group);
+        storage::store_seat_holders(&env, group_id, &holders);
+
+        events::emit_member_joined(&env, group_id, &primary);
+        events::emit_seat_shared(&env, group_id, &holders);
+
+        Ok(seat)
+    }
+
+    /// Get the co-holders of a shared seat.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `group_id` - The unique group identifier
+    /// * `member` - Any co-holder of the seat
+    ///
+    /// # Returns
+    /// The co-holders, first holder first; empty if the address doesn't share a seat
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    pub fn get_seat_holders(env: Env, group_id: u64, member: Address) -> Result<Vec<Address>, AjoError> {
+        storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        Ok(storage::get_shared_seat(&env, group_id, &member)
+            .and_then(|primary| storage::get_seat_holders(&env, group_id, &primary))
+            .unwrap_or(Vec::new(&env)))
+    }
+
     /// Check if an address is a member of a group.
     ///
-    /// Returns whether the provided address is currently a member of the specified group.
+    /// Returns whether the provided address is currently a member of the specified group,
+    /// including as a co-holder of a shared seat.
     ///
     /// # Arguments
     /// * `env` - The Soroban contract environment
@@ -300,7 +451,7 @@ impl AjoContract {
     /// * `GroupNotFound` - If the group does not exist
     pub fn is_member(env: Env, group_id: u64, address: Address) -> Result<bool, AjoError> {
         let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
-        Ok(utils::is_member(&group.members, &address))
+        Ok(utils::holds_seat(&env, &group, &address))
     }
 
     /// Contribute to the current cycle.
@@ -309,6 +460,10 @@ impl AjoContract {
     /// once per cycle. Authentication is required. Contributions are recorded but actual
     /// fund transfers are handled by external payment systems.
     ///
+    /// If the member has already paid installments via
+    /// [`contribute_partial`](Self::contribute_partial), this pays the outstanding balance.
+    /// A member holding several seats pays for all of them at once.
+    ///
     /// Late contributions (after cycle ends but within grace period) incur penalties.
     /// Contributions after grace period are rejected.
     ///
@@ -333,411 +488,1848 @@ impl AjoContract {
         // Require authentication
         member.require_auth();
 
-        // Get group
-        let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        contribution::record_contribution(&env, &member, &member, group_id, None, None)
+    }
+
+    /// Pay the current cycle's contribution for one of the member's seats.
+    ///
+    /// Lets a member holding several seats pay them one at a time. Each seat costs
+    /// `contribution_amount`, and the member only counts as contributed once every
+    /// seat has been paid.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `member` - Address paying for the seat (must authenticate)
+    /// * `group_id` - The group to contribute to
+    /// * `seat` - The seat's position in the payout order (see
+    ///   [`get_member_seats`](Self::get_member_seats))
+    ///
+    /// # Returns
+    /// `Ok(())` on successful contribution recording
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    /// * `NotMember` - If the address doesn't hold `seat`
+    /// * `AlreadyContributed` - If the seat (or the full contribution) is already paid this cycle
+    /// * `GroupComplete` - If the group has completed all cycles
+    /// * `InstallmentExceedsBalance` - If earlier installments leave less than a seat outstanding
+    /// * `GracePeriodExpired` - If contribution is too late (after grace period)
+    pub fn contribute_seat(env: Env, member: Address, group_id: u64, seat: u32) -> Result<(), AjoError> {
+        // Check if paused
+        pausable::ensure_not_paused(&env)?;
+
+        // Require authentication
+        member.require_auth();
+
+        contribution::record_contribution(&env, &member, &member, group_id, None, Some(seat))
+    }
+
+    /// Pay an installment toward the current cycle's contribution.
+    ///
+    /// Installments accumulate toward the contribution of each seat the member
+    /// holds; the member only counts as contributed once the full amount has been paid. Installments paid after the cycle
+    /// ends are penalized on their own amount, so penalties only apply to the balance
+    /// still outstanding when the cycle ended.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `member` - Address paying the installment (must authenticate)
+    /// * `group_id` - The group to contribute to
+    /// * `amount` - Installment amount in stroops
+    ///
+    /// # Returns
+    /// `Ok(())` on successful installment recording
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    /// * `NotMember` - If the address is not a member
+    /// * `AlreadyContributed` - If the full contribution is already paid this cycle
+    /// * `GroupComplete` - If the group has completed all cycles
+    /// * `ContributionAmountZero` - If `amount` is zero
+    /// * `ContributionAmountNegative` - If `amount` is negative
+    /// * `InstallmentExceedsBalance` - If `amount` is more than the outstanding balance
+    /// * `GracePeriodExpired` - If the installment is too late (after grace period)
+    pub fn contribute_partial(
+        env: Env,
+        member: Address,
+        group_id: u64,
+        amount: i128,
+    ) -> Result<(), AjoError> {
+        // Check if paused
+        pausable::ensure_not_paused(&env)?;
+
+        // Require authentication
+        member.require_auth();
+
+        contribution::record_contribution(&env, &member, &member, group_id, Some(amount), None)
+    }
+
+    /// Contribute to the current cycle on behalf of a member.
+    ///
+    /// Lets a relative, employer or other sponsor pay a member's outstanding balance.
+    /// The payer authorizes and funds the contribution, while the member gets the
+    /// contribution credit and reliability record. The payer is recorded in the
+    /// [`ContributionRecord`](crate::types::ContributionRecord), so any refund goes
+    /// back to them.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `payer` - Address funding the contribution (must authenticate)
+    /// * `member` - The member being credited
+    /// * `group_id` - The group to contribute to
+    ///
+    /// # Returns
+    /// `Ok(())` on successful contribution recording
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    /// * `NotMember` - If `member` is not a member
+    /// * `AlreadyContributed` - If the member has already contributed this cycle
+    /// * `GroupComplete` - If the group has completed all cycles
+    /// * `ContributionPayerMismatch` - If someone else already paid part of this cycle
+    /// * `GracePeriodExpired` - If contribution is too late (after grace period)
+    pub fn contribute_for(
+        env: Env,
+        payer: Address,
+        member: Address,
+        group_id: u64,
+    ) -> Result<(), AjoError> {
+        // Check if paused
+        pausable::ensure_not_paused(&env)?;
+
+        // Require authentication
+        payer.require_auth();
+
+        contribution::record_contribution(&env, &payer, &member, group_id, None, None)
+    }
+
+    /// Prepay contributions for upcoming cycles.
+    ///
+    /// Pays for the `cycles` cycles after the current one (or after the member's last
+    /// prepaid cycle). Prepaid cycles count as on-time contributions when they start,
+    /// and are refunded if the group is cancelled or refunded before then.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `member` - Address prepaying (must authenticate)
+    /// * `group_id` - The group to prepay
+    /// * `cycles` - Number of future cycles to pay for
+    ///
+    /// # Returns
+    /// The last cycle now prepaid
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    /// * `GroupCancelled` - If the group has been cancelled
+    /// * `GroupComplete` - If the group has completed all cycles
+    /// * `NotMember` - If the address is not a member
+    /// * `InvalidPrepayment` - If `cycles` is zero or runs past the group's final cycle
+    pub fn prepay(env: Env, member: Address, group_id: u64, cycles: u32) -> Result<u32, AjoError> {
+        // Check if paused
+        pausable::ensure_not_paused(&env)?;
+
+        // Require authentication
+        member.require_auth();
+
+        contribution::prepay(&env, &member, group_id, cycles)
+    }
+
+    /// Enable an auto-debit mandate for a member.
+    ///
+    /// The member must separately approve the contract as a spender on the group's
+    /// contribution token. Keepers (via
+    /// [`collect_auto_contributions`](Self::collect_auto_contributions)) then pull
+    /// each due contribution against that allowance, for up to
+    /// `max_cycles` cycles, without a fresh signature. The allowance must cover
+    /// everything the mandate will pull, since each pull is counted against it.
+    /// Enabling again replaces the existing mandate and starts a new tally.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `member` - The member granting the mandate (must authenticate)
+    /// * `group_id` - The group to contribute to
+    /// * `max_cycles` - Maximum number of cycles to pull contributions for
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    /// * `GroupCancelled` - If the group has been cancelled
+    /// * `GroupComplete` - If the group has completed all cycles
+    /// * `NotMember` - If the address is not a member
+    /// * `NoContributionToken` - If the group has no contribution token
+    /// * `InvalidMandate` - If `max_cycles` is zero
+    pub fn enable_auto_contribute(
+        env: Env,
+        member: Address,
+        group_id: u64,
+        max_cycles: u32,
+    ) -> Result<(), AjoError> {
+        pausable::ensure_not_paused(&env)?;
+        member.require_auth();
 
-        // Check if group is cancelled
+        let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
         if group.state == crate::types::GroupState::Cancelled {
             return Err(AjoError::GroupCancelled);
         }
-
-        // Check if group is complete
         if group.is_complete {
             return Err(AjoError::GroupComplete);
         }
-
-        // Check if member
-        if !utils::is_member(&group.members, &member) {
+        if !utils::holds_seat(&env, &group, &member) {
             return Err(AjoError::NotMember);
         }
-
-        // Check if already contributed
-        if storage::has_contributed(&env, group_id, group.current_cycle, &member) {
-            return Err(AjoError::AlreadyContributed);
+        if group.token.is_none() {
+            return Err(AjoError::NoContributionToken);
         }
-
-        // Get current time and check contribution timing
-        let current_time = utils::get_current_timestamp(&env);
-        let (is_late, penalty_amount) = utils::check_contribution_timing(&group, current_time);
-
-        // If too late (after grace period), reject
-        if is_late && penalty_amount == 0 {
-            // This means we're past grace period
-            if !utils::is_within_grace_period(&group, current_time) {
-                return Err(AjoError::GracePeriodExpired);
-            }
+        if max_cycles == 0 {
+            return Err(AjoError::InvalidMandate);
         }
 
-        // Transfer contribution to contract
-        // Note: In production, this would use token.transfer() or native transfer
-        // For now, we mark as contributed (assuming payment succeeded)
-
-        // Record contribution with details
-        let contribution_record = crate::types::ContributionRecord {
+        let mandate = AutoContributeMandate {
             member: member.clone(),
             group_id,
-            cycle: group.current_cycle,
-            has_paid: true,
-            timestamp: current_time,
-            is_late,
-            penalty_amount,
+            remaining_cycles: max_cycles,
+            pulled: 0,
+            enabled_at: utils::get_current_timestamp(&env),
         };
-        storage::store_contribution_detail(&env, group_id, group.current_cycle, &member, &contribution_record);
+        storage::store_mandate(&env, group_id, &mandate);
 
-        // Also store simple boolean flag for backward compatibility
-        storage::store_contribution(&env, group_id, group.current_cycle, &member, true);
+        events::emit_auto_contribute_enabled(&env, group_id, &member, max_cycles);
 
-        // If late, add penalty to pool and update member record
-        if is_late && penalty_amount > 0 {
-            storage::add_to_penalty_pool(&env, group_id, group.current_cycle, penalty_amount);
-            utils::update_member_penalty_record(&env, group_id, &member, true, penalty_amount);
+        Ok(())
+    }
 
-            // Emit late contribution event
-            events::emit_late_contribution(
-                &env,
-                group_id,
-                &member,
-                group.current_cycle,
-                group.contribution_amount,
-                penalty_amount,
-            );
-        } else {
-            // Update member record for on-time contribution
-            utils::update_member_penalty_record(&env, group_id, &member, false, 0);
+    /// Revoke a member's auto-debit mandate.
+    ///
+    /// Works even while the contract is paused, so members can always stop pulls.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `member` - The member revoking the mandate (must authenticate)
+    /// * `group_id` - The group the mandate is for
+    ///
+    /// # Errors
+    /// * `MandateNotFound` - If the member has no mandate for this group
+    pub fn revoke_auto_contribute(env: Env, member: Address, group_id: u64) -> Result<(), AjoError> {
+        member.require_auth();
 
-            // Emit regular contribution event
-            events::emit_contribution_made(
-                &env,
-                group_id,
-                &member,
-                group.current_cycle,
-                group.contribution_amount,
-            );
-        }
+        storage::get_mandate(&env, group_id, &member).ok_or(AjoError::MandateNotFound)?;
+        storage::remove_mandate(&env, group_id, &member);
+
+        events::emit_auto_contribute_revoked(&env, group_id, &member);
 
         Ok(())
     }
 
-    /// Get contribution status for all members in a specific cycle.
+    /// Get a member's auto-debit mandate.
     ///
-    /// Returns an ordered list of all members paired with their contribution status
-    /// for the specified cycle. Member order matches the group's member list order.
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `group_id` - The unique group identifier
+    /// * `member` - The member's address
+    ///
+    /// # Returns
+    /// The member's mandate
+    ///
+    /// # Errors
+    /// * `MandateNotFound` - If the member has no mandate for this group
+    pub fn get_auto_contribute_mandate(
+        env: Env,
+        group_id: u64,
+        member: Address,
+    ) -> Result<AutoContributeMandate, AjoError> {
+        storage::get_mandate(&env, group_id, &member).ok_or(AjoError::MandateNotFound)
+    }
+
+    /// Pull due contributions from members' auto-debit mandates.
+    ///
+    /// Any address can call this during a cycle or its grace period; payouts don't
+    /// pull on their own, so keepers collect before the grace period ends. Pulls made
+    /// during the grace period are penalized like any late contribution; failed pulls
+    /// are skipped and the member falls back to contributing manually.
     ///
     /// # Arguments
     /// * `env` - The Soroban contract environment
-    /// * `group_id` - The group to check
-    /// * `cycle_number` - The cycle to check (typically use current_cycle from group)
+    /// * `keeper` - Address collecting the contributions (must authenticate)
+    /// * `group_id` - The group to collect for
     ///
     /// # Returns
-    /// Vector of (Address, bool) tuples where `true` indicates the member has contributed
+    /// The number of contributions pulled
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    pub fn collect_auto_contributions(
+        env: Env,
+        keeper: Address,
+        group_id: u64,
+    ) -> Result<u32, AjoError> {
+        pausable::ensure_not_paused(&env)?;
+        keeper.require_auth();
+
+        let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        Ok(contribution::pull_auto_contributions(&env, &group))
+    }
+
+    /// Get the last cycle a member has prepaid.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `group_id` - The unique group identifier
+    /// * `member` - The member's address
+    ///
+    /// # Returns
+    /// The last prepaid cycle (0 if the member never prepaid)
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    pub fn get_prepaid_through(env: Env, group_id: u64, member: Address) -> Result<u32, AjoError> {
+        storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        Ok(storage::get_prepaid_through(&env, group_id, &member))
+    }
+
+    /// Get contribution status for all members in a specific cycle.
+    ///
+    /// Returns an ordered list of all members paired with their contribution status
+    /// for the specified cycle. Member order matches the group's member list order.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `group_id` - The group to check
+    /// * `cycle_number` - The cycle to check (typically use current_cycle from group)
+    ///
+    /// # Returns
+    /// Vector of (Address, bool) tuples where `true` indicates the member has contributed
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    pub fn get_contribution_status(
+        env: Env,
+        group_id: u64,
+        cycle_number: u32,
+    ) -> Result<Vec<(Address, bool)>, AjoError> {
+        let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        Ok(storage::get_cycle_contributions(
+            &env,
+            group_id,
+            cycle_number,
+            &group.members,
+        ))
+    }
+
+    /// Execute payout for the current cycle.
+    ///
+    /// This is the core function that rotates payouts through group members.
+    /// It verifies that all members have contributed, calculates the total payout
+    /// (including any penalties collected), distributes funds to the next recipient,
+    /// and advances the cycle. When all members have received their payout, the group
+    /// is marked complete.
+    ///
+    /// Payout can only be executed after the grace period expires to ensure all
+    /// late contributions are collected.
+    ///
+    /// Process:
+    /// 1. Verifies all members have contributed in the current cycle
+    /// 2. Ensures grace period has expired
+    /// 3. Calculates total payout (contribution_amount × tier × member_count + penalties,
+    ///    when the penalty policy sends penalties to the recipient)
+    /// 4. Deducts the organizer commission and pays it to the creator
+    /// 5. Records the net payout to the current recipient
+    /// 6. Emits payout event with penalty bonus
+    /// 7. Advances to next cycle (or marks complete if done)
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `group_id` - The group to execute payout for
+    ///
+    /// # Returns
+    /// `Ok(())` on successful payout execution
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    /// * `IncompleteContributions` - If not all members have contributed
+    /// * `GroupComplete` - If the group has already completed all payouts
+    /// * `NoMembers` - If the group has no members (should never happen)
+    /// * `OutsideCycleWindow` - If grace period has not expired yet
+    pub fn execute_payout(env: Env, group_id: u64) -> Result<(), AjoError> {
+        // Check if paused
+        pausable::ensure_not_paused(&env)?;
+
+        payout::execute_payout(&env, group_id, None)?;
+        Ok(())
+    }
+
+    /// Execute a due payout as a keeper and collect the keeper bounty.
+    ///
+    /// Any address can crank a group once its payout is due. The keeper receives
+    /// the group's configured bounty, funded first from the cycle's penalty pool and
+    /// then from the organizer commission. If neither has funds the payout still
+    /// runs and the bounty is zero.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `keeper` - Address executing the payout (must authenticate)
+    /// * `group_id` - The group to pay out
+    ///
+    /// # Returns
+    /// The bounty paid to the keeper in stroops
+    ///
+    /// # Errors
+    /// Same as [`execute_payout`](Self::execute_payout)
+    pub fn crank(env: Env, keeper: Address, group_id: u64) -> Result<i128, AjoError> {
+        pausable::ensure_not_paused(&env)?;
+        keeper.require_auth();
+
+        payout::execute_payout(&env, group_id, Some(&keeper))
+    }
+
+    /// Crank several groups in one call, skipping any that can't be paid out.
+    ///
+    /// Groups whose payout fails (because it isn't due, the group doesn't exist, or
+    /// any other [`crank`](Self::crank) error) are skipped with a `skipped` event
+    /// carrying the reason, rather than failing the whole batch.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `keeper` - Address executing the payouts (must authenticate)
+    /// * `group_ids` - The groups to crank
+    ///
+    /// # Returns
+    /// The IDs of the groups that were paid out
+    ///
+    /// # Errors
+    /// * `ContractPaused` - If the contract is paused
+    pub fn crank_many(env: Env, keeper: Address, group_ids: Vec<u64>) -> Result<Vec<u64>, AjoError> {
+        pausable::ensure_not_paused(&env)?;
+        keeper.require_auth();
+
+        let mut cranked = Vec::new(&env);
+        for group_id in group_ids.iter() {
+            match payout::execute_payout(&env, group_id, Some(&keeper)) {
+                Ok(_) => cranked.push_back(group_id),
+                Err(reason) => events::emit_crank_skipped(&env, group_id, &keeper, reason as u32),
+            }
+        }
+
+        Ok(cranked)
+    }
+
+    /// Set the token that auto-debit mandates pull contributions in.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `group_id` - The unique group identifier
+    /// * `token` - The token contract address
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    /// * `GroupTermsLocked` - If another member has already joined
+    pub fn set_contribution_token(env: Env, group_id: u64, token: Address) -> Result<(), AjoError> {
+        utils::update_terms(&env, group_id, |terms| {
+            terms.token = Some(token.clone());
+            Ok(())
+        })?;
+
+        events::emit_token_set(&env, group_id, &token);
+
+        Ok(())
+    }
+
+    /// Require the creator's approval for seat transfers.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `group_id` - The unique group identifier
+    /// * `required` - Whether transfers need the creator's authorization
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    /// * `GroupTermsLocked` - If another member has already joined
+    pub fn set_seat_transfer_approval(env: Env, group_id: u64, required: bool) -> Result<(), AjoError> {
+        utils::update_terms(&env, group_id, |terms| {
+            terms.seat_transfer_needs_approval = required;
+            Ok(())
+        })?;
+
+        events::emit_seat_transfer_approval_set(&env, group_id, required);
+
+        Ok(())
+    }
+
+    /// Set how many seats one address may hold in a group.
+    ///
+    /// Each seat has its own payout turn and owes `contribution_amount` times its
+    /// tier every cycle.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `group_id` - The unique group identifier
+    /// * `max_seats` - Seats allowed per address, from 1 up to `max_members`
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    /// * `InvalidGroupTerms` - If `max_seats` is zero or more than `max_members`
+    /// * `GroupTermsLocked` - If another member has already joined
+    pub fn set_max_seats_per_member(env: Env, group_id: u64, max_seats: u32) -> Result<(), AjoError> {
+        utils::update_terms(&env, group_id, |terms| {
+            terms.max_seats_per_member = max_seats;
+            Ok(())
+        })?;
+
+        events::emit_max_seats_set(&env, group_id, max_seats);
+
+        Ok(())
+    }
+
+    /// Get the seats an address holds in a group.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `group_id` - The unique group identifier
+    /// * `member` - The member's address
+    ///
+    /// # Returns
+    /// Each seat's position in the payout order (cycle `position + 1`); empty for non-members
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    pub fn get_member_seats(env: Env, group_id: u64, member: Address) -> Result<Vec<u32>, AjoError> {
+        let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        Ok(utils::member_seats(&env, &group.members, &member))
+    }
+
+    /// Collect contributions more often than the group pays out.
+    ///
+    /// Each cycle lasts `payout_interval` and is split into contribution periods of
+    /// `contribution_interval`, each with its own deadline and grace window. Members
+    /// owe `contribution_amount` per period, so the payout grows with the number of
+    /// periods, and the payout still needs every period of the cycle paid. A payment
+    /// made after a period's deadline is penalized on the part covering overdue
+    /// periods.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `group_id` - The unique group identifier
+    /// * `contribution_interval` - Length of each contribution period in seconds
+    /// * `payout_interval` - Length of each cycle in seconds, a multiple of
+    ///   `contribution_interval`
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    /// * `GroupTermsLocked` - If another member has already joined
+    /// * `CycleDurationZero` - If either interval is zero
+    /// * `InvalidCycleSchedule` - If `contribution_interval` doesn't evenly divide
+    ///   `payout_interval`, or there would be more than
+    ///   [`MAX_CONTRIBUTION_PERIODS`](crate::types::MAX_CONTRIBUTION_PERIODS) periods
+    pub fn set_intervals(
+        env: Env,
+        group_id: u64,
+        contribution_interval: u64,
+        payout_interval: u64,
+    ) -> Result<(), AjoError> {
+        utils::update_terms(&env, group_id, |terms| {
+            // A zero contribution interval would otherwise turn periods off
+            utils::validate_intervals(contribution_interval, payout_interval)?;
+            terms.contribution_interval = contribution_interval;
+            terms.cycle_duration = payout_interval;
+            Ok(())
+        })?;
+
+        events::emit_intervals_set(&env, group_id, contribution_interval, payout_interval);
+
+        Ok(())
+    }
+
+    /// Set the highest contribution tier seats in a group may choose.
+    ///
+    /// A tier-n seat owes n × `contribution_amount` each cycle and is paid n shares
+    /// of the rotation, so its payout is n × `contribution_amount` × the number of
+    /// seats.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `group_id` - The unique group identifier
+    /// * `max_tier` - Highest tier allowed, from 1 up to
+    ///   [`MAX_CONTRIBUTION_TIER`](crate::types::MAX_CONTRIBUTION_TIER)
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    /// * `InvalidGroupTerms` - If `max_tier` is zero or above `MAX_CONTRIBUTION_TIER`
+    /// * `GroupTermsLocked` - If another member has already joined
+    pub fn set_max_contribution_tier(env: Env, group_id: u64, max_tier: u32) -> Result<(), AjoError> {
+        utils::update_terms(&env, group_id, |terms| {
+            terms.max_contribution_tier = max_tier;
+            Ok(())
+        })?;
+
+        events::emit_max_tier_set(&env, group_id, max_tier);
+
+        Ok(())
+    }
+
+    /// Choose the contribution tier of a seat the member holds.
+    ///
+    /// Seats start at tier 1. Tiers can be chosen until the first contribution of
+    /// the group is made, and the payout order must stay fully funded: each cycle's
+    /// payout has to be covered by that cycle's pot plus any surplus carried from
+    /// earlier, smaller payouts, so larger tiers belong later in the rotation.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `member` - The seat's holder (must authenticate)
+    /// * `group_id` - The unique group identifier
+    /// * `seat` - The seat's position in the payout order
+    /// * `tier` - The tier, from 1 up to the group's `max_contribution_tier`
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    /// * `GroupTermsLocked` - If contributions have already started
+    /// * `NotMember` - If `member` doesn't hold `seat`
+    /// * `ContributionAmountZero` - If `tier` is zero
+    /// * `InvalidGroupTerms` - If `tier` is above the group's maximum
+    /// * `InsufficientBalance` - If the tier would leave a payout unfunded
+    pub fn set_seat_tier(
+        env: Env,
+        member: Address,
+        group_id: u64,
+        seat: u32,
+        tier: u32,
+    ) -> Result<(), AjoError> {
+        pausable::ensure_not_paused(&env)?;
+        member.require_auth();
+
+        let mut group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+
+        if utils::contributions_started(&env, &group) {
+            return Err(AjoError::GroupTermsLocked);
+        }
+        if group.members.get(seat).as_ref() != Some(&member) {
+            return Err(AjoError::NotMember);
+        }
+        if tier == 0 {
+            return Err(AjoError::ContributionAmountZero);
+        }
+        if tier > group.max_contribution_tier {
+            return Err(AjoError::InvalidGroupTerms);
+        }
+
+        group.seat_tiers.set(seat, tier);
+        utils::validate_tier_funding(&group)?;
+        storage::store_group(&env, group_id, &group);
+
+        events::emit_seat_tier_set(&env, group_id, &member, seat, tier);
+
+        Ok(())
+    }
+
+    /// Set how many seats each payout pays.
+    ///
+    /// Each of the cycle's recipients receives an equal share of the pot, so with K
+    /// recipients a seat is paid `contribution_amount` × tier × seats / K and the
+    /// rotation completes in `ceil(seats / K)` cycles. When the seats don't divide
+    /// evenly, the short final cycle still pays each recipient the same share and
+    /// the rest of its pot is split among all members.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `group_id` - The unique group identifier
+    /// * `recipients` - Seats paid per cycle, from 1 up to `max_members`
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    /// * `InvalidGroupTerms` - If `recipients` is zero or more than `max_members`
+    /// * `InvalidCycleSchedule` - If a calendar schedule wouldn't cover every cycle
+    /// * `GroupTermsLocked` - If another member has already joined
+    pub fn set_recipients_per_cycle(env: Env, group_id: u64, recipients: u32) -> Result<(), AjoError> {
+        utils::update_terms(&env, group_id, |terms| {
+            terms.recipients_per_cycle = recipients;
+            Ok(())
+        })?;
+
+        events::emit_recipients_per_cycle_set(&env, group_id, recipients);
+
+        Ok(())
+    }
+
+    /// Choose whether the group's next round reshuffles the payout order.
+    ///
+    /// By default a renewed group keeps its members in the same payout order.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `group_id` - The unique group identifier
+    /// * `shuffle` - Whether the next round's order is reshuffled
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    /// * `GroupTermsLocked` - If another member has already joined
+    pub fn set_renewal_shuffle(env: Env, group_id: u64, shuffle: bool) -> Result<(), AjoError> {
+        utils::update_terms(&env, group_id, |terms| {
+            terms.shuffle_on_renewal = shuffle;
+            Ok(())
+        })?;
+
+        events::emit_renewal_shuffle_set(&env, group_id, shuffle);
+
+        Ok(())
+    }
+
+    /// Opt a group into the default insurance fund.
+    ///
+    /// Each contribution then pays a premium of `premium_bps` basis points on top,
+    /// into the insurance fund for the group's token. In return, the fund covers
+    /// contributions missed by members who have already received their payout once
+    /// the grace period expires, so the payout can go ahead. Pass 0 to opt out.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `group_id` - The unique group identifier
+    /// * `premium_bps` - Premium in basis points of each contribution
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    /// * `NoContributionToken` - If the group has no contribution token
+    /// * `InvalidGroupTerms` - If the premium is above
+    ///   [`MAX_INSURANCE_PREMIUM_BPS`](crate::types::MAX_INSURANCE_PREMIUM_BPS)
+    /// * `GroupTermsLocked` - If another member has already joined
+    pub fn set_insurance_premium(env: Env, group_id: u64, premium_bps: u32) -> Result<(), AjoError> {
+        let group = utils::update_terms(&env, group_id, |terms| {
+            // Even opting out needs a token, since the fund is per token
+            terms.token.as_ref().ok_or(AjoError::NoContributionToken)?;
+            terms.insurance_premium_bps = premium_bps;
+            Ok(())
+        })?;
+
+        if let Some(token) = &group.token {
+            events::emit_insurance_premium_set(&env, group_id, token, premium_bps);
+        }
+
+        Ok(())
+    }
+
+    /// Get the default insurance fund balance for a token.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `token` - The token the fund holds
+    ///
+    /// # Returns
+    /// Fund balance in stroops (0 if no premiums have been paid in this token)
+    pub fn get_insurance_fund_balance(env: Env, token: Address) -> i128 {
+        storage::get_insurance_fund(&env, &token)
+    }
+
+    /// Get the insurance claim paid for a member's default.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `group_id` - The unique group identifier
+    /// * `cycle` - The cycle the member defaulted on
+    /// * `member` - The member's address
+    ///
+    /// # Returns
+    /// `Some(InsuranceClaim)` if the fund covered the default, `None` otherwise
+    pub fn get_insurance_claim(
+        env: Env,
+        group_id: u64,
+        cycle: u32,
+        member: Address,
+    ) -> Option<InsuranceClaim> {
+        storage::get_insurance_claim(&env, group_id, cycle, &member)
+    }
+
+    /// Deposit a group's idle funds into a yield vault.
+    ///
+    /// Each contribution is deposited into `vault` as it comes in, and everything
+    /// is withdrawn just before the payout. Yield is distributed according to
+    /// `policy`, while a loss always reduces the payout. The vault must implement
+    /// the [`Vault`](crate::vault::Vault) interface.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `group_id` - The unique group identifier
+    /// * `vault` - The vault contract
+    /// * `policy` - Where the yield goes
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    /// * `GroupTermsLocked` - If another member has already joined
+    pub fn set_vault(
+        env: Env,
+        group_id: u64,
+        vault: Address,
+        policy: YieldPolicy,
+    ) -> Result<(), AjoError> {
+        utils::update_terms(&env, group_id, |terms| {
+            terms.vault = Some(vault.clone());
+            terms.yield_policy = policy;
+            Ok(())
+        })?;
+
+        events::emit_vault_set(&env, group_id, &vault, policy);
+
+        Ok(())
+    }
+
+    /// Get a group's holding in its vault.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `group_id` - The unique group identifier
+    ///
+    /// # Returns
+    /// `Some(VaultPosition)` if the group has funds deposited, `None` otherwise
+    pub fn get_vault_position(env: Env, group_id: u64) -> Option<VaultPosition> {
+        storage::get_vault_position(&env, group_id)
+    }
+
+    /// Turn a group into a non-rotating target savings group.
+    ///
+    /// Every member contributes each cycle for `cycles` cycles, but nobody is paid
+    /// along the way: [`execute_payout`](Self::execute_payout) just closes each cycle
+    /// once its grace period has expired. At maturity each member withdraws their own
+    /// savings plus their share of the group reserve with
+    /// [`withdraw_savings`](Self::withdraw_savings). Withdrawing earlier forfeits
+    /// `early_withdrawal_bps` basis points of the savings to the group reserve.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `group_id` - The unique group identifier
+    /// * `cycles` - Number of cycles until maturity, from 1 up to
+    ///   [`MAX_SAVINGS_CYCLES`](crate::types::MAX_SAVINGS_CYCLES)
+    /// * `early_withdrawal_bps` - Basis points (0-10,000) forfeited by withdrawing early
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    /// * `GroupTermsLocked` - If another member has already joined
+    /// * `InvalidCycleSchedule` - If `cycles` is out of range, or a calendar schedule
+    ///   doesn't cover every cycle
+    /// * `InvalidPenaltyRate` - If `early_withdrawal_bps` is above
+    ///   [`BPS_DENOMINATOR`](crate::types::BPS_DENOMINATOR)
+    pub fn set_target_savings(
+        env: Env,
+        group_id: u64,
+        cycles: u32,
+        early_withdrawal_bps: u32,
+    ) -> Result<(), AjoError> {
+        utils::update_terms(&env, group_id, |terms| {
+            terms.kind = GroupKind::TargetSavings;
+            terms.savings_cycles = cycles;
+            terms.early_withdrawal_bps = early_withdrawal_bps;
+            Ok(())
+        })?;
+
+        events::emit_target_savings_set(&env, group_id, cycles, early_withdrawal_bps);
+
+        Ok(())
+    }
+
+    /// Withdraw a member's savings from a target savings group.
+    ///
+    /// Once the group has matured, pays the member's savings plus their share of the
+    /// group reserve (penalties, early-withdrawal forfeits and vault yield). Before
+    /// maturity the member leaves the group instead: they forfeit the group's
+    /// early-withdrawal rate of their savings to the group reserve and can't
+    /// contribute again. Either way the withdrawal is stored as the member's payout
+    /// record.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `member` - The saver (must authenticate)
+    /// * `group_id` - The unique group identifier
+    ///
+    /// # Returns
+    /// The amount withdrawn
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    /// * `GroupCancelled` - If the group has been cancelled
+    /// * `NotMember` - If the address holds no seat
+    /// * `InvalidGroupKind` - If the group isn't a target savings group
+    /// * `AlreadyReceivedPayout` - If the member has already withdrawn
+    /// * `InsufficientBalance` - If the member has nothing to withdraw
+    pub fn withdraw_savings(env: Env, member: Address, group_id: u64) -> Result<i128, AjoError> {
+        pausable::ensure_not_paused(&env)?;
+        member.require_auth();
+
+        let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        if group.state == crate::types::GroupState::Cancelled {
+            return Err(AjoError::GroupCancelled);
+        }
+        if !utils::holds_seat(&env, &group, &member) {
+            return Err(AjoError::NotMember);
+        }
+        if group.kind != GroupKind::TargetSavings {
+            return Err(AjoError::InvalidGroupKind);
+        }
+        if storage::has_received_payout(&env, group_id, &member) {
+            return Err(AjoError::AlreadyReceivedPayout);
+        }
+
+        let savings = storage::get_savings_balance(&env, group_id, &member);
+        let (penalty, earnings) = if group.is_complete {
+            (0, storage::get_penalty_earnings(&env, group_id, &member))
+        } else {
+            (utils::apply_bps(savings, group.early_withdrawal_bps), 0)
+        };
+        let amount = savings - penalty + earnings;
+        if amount <= 0 {
+            return Err(AjoError::InsufficientBalance);
+        }
+
+        if penalty > 0 {
+            utils::add_to_group_reserve(&env, group_id, group.current_cycle, penalty);
+        }
+        // An early leaver's mandate would otherwise keep pulling contributions
+        storage::remove_mandate(&env, group_id, &member);
+
+        // Transfer savings to the member
+        // Note: In production, this would use token.transfer() or native transfer
+        // For now, we just record it
+        storage::mark_payout_received(&env, group_id, &member);
+        storage::store_payout_record(
+            &env,
+            group_id,
+            &member,
+            &PayoutRecord {
+                member: member.clone(),
+                group_id,
+                cycle: group.current_cycle,
+                amount,
+                commission: 0,
+                timestamp: utils::get_current_timestamp(&env),
+                shares: Vec::from_array(
+                    &env,
+                    [PayoutShare { recipient: member.clone(), amount }],
+                ),
+            },
+        );
+
+        events::emit_savings_withdrawn(&env, group_id, &member, group.current_cycle, amount, penalty);
+
+        Ok(amount)
+    }
+
+    /// Get a member's savings in a target savings group.
+    ///
+    /// Counts every contribution payment the member has made, including prepaid
+    /// cycles, but not their share of the group reserve.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `group_id` - The unique group identifier
+    /// * `member` - The member's address
+    ///
+    /// # Returns
+    /// The member's savings in stroops
+    pub fn get_savings_balance(env: Env, group_id: u64, member: Address) -> i128 {
+        storage::get_savings_balance(&env, group_id, &member)
+    }
+
+    /// Check whether a group's payout can be executed right now.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `group_id` - The group to check
+    ///
+    /// # Returns
+    /// `true` if `execute_payout` or `crank` would succeed
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    pub fn is_payout_due(env: Env, group_id: u64) -> Result<bool, AjoError> {
+        let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        let now = utils::get_current_timestamp(&env);
+        let claims = insurance::plan_claims(&env, &group);
+        Ok(utils::check_payout_ready(&env, &group, &claims, now).is_ok())
+    }
+
+    /// Set the bounty paid to keepers who crank a group's payouts.
+    ///
+    /// The bounty is funded from the cycle's penalty pool and the organizer
+    /// commission.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `group_id` - The unique group identifier
+    /// * `bounty` - Maximum bounty per crank in stroops
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    /// * `GroupTermsLocked` - If another member has already joined
+    /// * `InvalidGroupTerms` - If the bounty is negative
+    pub fn set_keeper_bounty(env: Env, group_id: u64, bounty: i128) -> Result<(), AjoError> {
+        utils::update_terms(&env, group_id, |terms| {
+            terms.keeper_bounty = bounty;
+            Ok(())
+        })?;
+
+        events::emit_keeper_bounty_set(&env, group_id, bounty);
+
+        Ok(())
+    }
+
+    /// Get the total bounties a keeper has earned across all groups.
+    pub fn get_keeper_earnings(env: Env, keeper: Address) -> i128 {
+        storage::get_keeper_earnings(&env, &keeper)
+    }
+
+    /// Check if a group has completed all cycles.
+    ///
+    /// Returns whether the group has completed its full rotation,
+    /// meaning all members have received at least one payout.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `group_id` - The group to check
+    ///
+    /// # Returns
+    /// `true` if the group has completed all payouts, `false` otherwise
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    pub fn is_complete(env: Env, group_id: u64) -> Result<bool, AjoError> {
+        let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        Ok(group.is_complete)
+    }
+
+    /// Get comprehensive group status.
+    ///
+    /// Returns a detailed snapshot of the group's current state, including cycle
+    /// information, contribution status, payout progress, and timing data.
+    /// This is the primary function for checking a group's overall progress.
+    ///
+    /// Returns information about:
+    /// - Current cycle number and progress
+    /// - Next recipient for payout
+    /// - Members who have contributed and those who are pending
+    /// - Cycle timing (start time, end time, whether cycle is active)
+    /// - The remaining payout order
+    /// - Whether the group is complete
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `group_id` - The unique group identifier
+    ///
+    /// # Returns
+    /// A `GroupStatus` struct containing comprehensive group state information
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    pub fn get_group_status(env: Env, group_id: u64) -> Result<GroupStatus, AjoError> {
+        // Get the group data
+        let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+
+        // Get current timestamp
+        let current_time = utils::get_current_timestamp(&env);
+
+        // Calculate cycle timing
+        let cycle_end_time = utils::get_cycle_end(&group);
+        let grace_period_end_time = utils::get_grace_period_end(&group);
+        let is_cycle_active = current_time < cycle_end_time;
+        let is_in_grace_period = utils::is_within_grace_period(&group, current_time);
+        let current_period = utils::current_period(&group, current_time);
+        let periods_per_cycle = utils::contribution_periods(&group);
+        let period_end_time = utils::get_period_end(&group, current_period);
+
+        // Get penalty pool for current cycle
+        let cycle_penalty_pool = storage::get_cycle_penalty_pool(&env, group_id, group.current_cycle);
+
+        // Whether a keeper could crank the payout right now
+        let claims = insurance::plan_claims(&env, &group);
+        let is_payout_due = utils::check_payout_ready(&env, &group, &claims, current_time).is_ok();
+
+        // Commission the organizer takes from the next payout
+        let commission_per_payout =
+            utils::calculate_commission(
+            &group.commission,
+            utils::calculate_payout_amount(&group, group.payout_index),
+        );
+
+        // Get contribution status for all members in current cycle
+        let contributions =
+            storage::get_cycle_contributions(&env, group_id, group.current_cycle, &group.members);
+
+        // Count contributions and build pending list
+        let mut contributions_received: u32 = 0;
+        let mut pending_contributors = Vec::new(&env);
+
+        for (member, has_contributed) in contributions.iter() {
+            if has_contributed {
+                contributions_received += 1;
+            } else {
+                pending_contributors.push_back(member);
+            }
+        }
+
+        // Members still waiting for their payout, in order (nobody in a savings group)
+        let rotating = group.kind == GroupKind::Rotating;
+        let payout_order = if group.is_complete || !rotating {
+            Vec::new(&env)
+        } else {
+            group.members.slice(group.payout_index..)
+        };
+
+        // Determine next recipient
+        let (has_next_recipient, next_recipient) = if group.is_complete || !rotating {
+            // Use placeholder (creator) when complete or not rotating
+            (false, group.creator.clone())
+        } else {
+            // Get the member at payout_index
+            let recipient = group
+                .members
+                .get(group.payout_index)
+                .unwrap_or_else(|| group.creator.clone());
+            (true, recipient)
+        };
+
+        // Build and return status
+        Ok(GroupStatus {
+            group_id: group.id,
+            current_cycle: group.current_cycle,
+            has_next_recipient,
+            next_recipient,
+            contributions_received,
+            total_members: group.members.len(),
+            pending_contributors,
+            is_complete: group.is_complete,
+            is_cycle_active,
+            cycle_start_time: group.cycle_start_time,
+            cycle_end_time,
+            current_time,
+            cycle_penalty_pool,
+            is_in_grace_period,
+            grace_period_end_time,
+            organizer_commission: group.commission,
+            commission_per_payout,
+            penalty_policy: group.penalty_policy,
+            penalty_reserve: storage::get_group_reserve(&env, group_id),
+            is_catching_up: group.catch_up_end > 0,
+            is_payout_due,
+            keeper_bounty: group.keeper_bounty,
+            cycle_schedule: group.cycle_schedule,
+            payout_order,
+            current_period,
+            periods_per_cycle,
+            period_end_time,
+        })
+    }
+
+    /// Set or update metadata for an Ajo group.
+    ///
+    /// Only the group creator can set or update metadata.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `group_id` - The unique group identifier
+    /// * `name` - The name of the group
+    /// * `description` - The description of the group
+    /// * `rules` - The rules of the group
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    /// * `Unauthorized` - If the caller is not the group creator
+    /// * `MetadataTooLong` - If any field exceeds its length limit
+    pub fn set_group_metadata(
+        env: Env,
+        group_id: u64,
+        name: soroban_sdk::String,
+        description: soroban_sdk::String,
+        rules: soroban_sdk::String,
+    ) -> Result<(), AjoError> {
+        // Validate lengths
+        let metadata = GroupMetadata {
+            name,
+            description,
+            rules,
+        };
+        utils::validate_metadata(&metadata)?;
+
+        // Get group to verify existence and check creator
+        let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+
+        // Require creator authentication
+        group.creator.require_auth();
+
+        // Store metadata
+        storage::store_group_metadata(&env, group_id, &metadata);
+        events::emit_metadata_set(&env, group_id, &metadata);
+
+        Ok(())
+    }
+
+    /// Get metadata for an Ajo group.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `group_id` - The unique group identifier
+    ///
+    /// # Returns
+    /// The group metadata
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If metadata for the group doesn't exist
+    pub fn get_group_metadata(env: Env, group_id: u64) -> Result<GroupMetadata, AjoError> {
+        storage::get_group_metadata(&env, group_id).ok_or(AjoError::GroupNotFound)
+    }
+
+    /// Save a group template to create identical groups from later.
+    ///
+    /// The template holds every term of a group, plus optional metadata, validated
+    /// as [`create_group_with_terms`](Self::create_group_with_terms) would. Templates
+    /// are listed per creator by [`get_creator_templates`](Self::get_creator_templates),
+    /// and anyone may create a group from one.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `template` - The template; its `creator` must authenticate
+    /// * `metadata` - Metadata given to each group created from the template
+    ///
+    /// # Returns
+    /// The unique template ID assigned to the template
+    ///
+    /// # Errors
+    /// * Any error from `create_group_with_terms`'s validation of the terms
+    /// * `MetadataTooLong` - If any metadata field exceeds its length limit
+    pub fn save_template(
+        env: Env,
+        template: GroupTemplate,
+        metadata: Option<GroupMetadata>,
+    ) -> Result<u64, AjoError> {
+        pausable::ensure_not_paused(&env)?;
+        template.creator.require_auth();
+
+        utils::validate_group_terms(&template.terms)?;
+        if let Some(metadata) = &metadata {
+            utils::validate_metadata(metadata)?;
+        }
+
+        let template_id = storage::store_template(&env, &template);
+        if let Some(metadata) = &metadata {
+            storage::store_template_metadata(&env, template_id, metadata);
+        }
+
+        events::emit_template_saved(&env, template_id, &template.creator);
+
+        Ok(template_id)
+    }
+
+    /// Get a saved group template.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `template_id` - The unique template identifier
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the template does not exist (templates have no error
+    ///   code of their own)
+    pub fn get_template(env: Env, template_id: u64) -> Result<GroupTemplate, AjoError> {
+        storage::get_template(&env, template_id).ok_or(AjoError::GroupNotFound)
+    }
+
+    /// Get the metadata given to groups created from a template.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `template_id` - The unique template identifier
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If metadata for the template doesn't exist
+    pub fn get_template_metadata(env: Env, template_id: u64) -> Result<GroupMetadata, AjoError> {
+        storage::get_template_metadata(&env, template_id).ok_or(AjoError::GroupNotFound)
+    }
+
+    /// Get the IDs of the templates an address has saved, oldest first.
+    pub fn get_creator_templates(env: Env, creator: Address) -> Vec<u64> {
+        storage::get_creator_templates(&env, &creator)
+    }
+
+    /// Create a new group from a saved template.
+    ///
+    /// Works like [`create_group_with_terms`](Self::create_group_with_terms) with the
+    /// template's terms, then gives the group the template's metadata. The caller
+    /// becomes the group's creator and first member.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `creator` - Address of the group creator (must authenticate)
+    /// * `template_id` - The template to create the group from
+    ///
+    /// # Returns
+    /// The unique group ID assigned to the new group
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the template does not exist (templates have no error
+    ///   code of their own)
+    /// * `ContractPaused` - If the contract is paused
+    pub fn create_group_from_template(
+        env: Env,
+        creator: Address,
+        template_id: u64,
+    ) -> Result<u64, AjoError> {
+        let template = storage::get_template(&env, template_id).ok_or(AjoError::GroupNotFound)?;
+
+        let group_id = Self::create_group_with_terms(env.clone(), creator, template.terms)?;
+        if let Some(metadata) = storage::get_template_metadata(&env, template_id) {
+            storage::store_group_metadata(&env, group_id, &metadata);
+        }
+
+        events::emit_group_from_template(&env, group_id, template_id);
+
+        Ok(group_id)
+    }
+
+    /// Create a new group with the same settings and metadata as an existing one.
+    ///
+    /// Every setting of the source group is copied, whatever state it's in; the
+    /// caller becomes the new group's creator and only member, and its first cycle
+    /// starts now. A calendar schedule belongs to the source group's dates, so the
+    /// copy uses a rolling schedule instead.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `creator` - Address of the new group's creator (must authenticate)
+    /// * `group_id` - The group to copy
+    ///
+    /// # Returns
+    /// The unique group ID assigned to the new group
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the source group does not exist
+    /// * `ContractPaused` - If the contract is paused
+    pub fn clone_group(env: Env, creator: Address, group_id: u64) -> Result<u64, AjoError> {
+        pausable::ensure_not_paused(&env)?;
+        creator.require_auth();
+
+        let source = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+
+        let new_group_id = storage::get_next_group_id(&env);
+        let now = utils::get_current_timestamp(&env);
+        let group = utils::copy_group_terms(
+            &source,
+            new_group_id,
+            creator.clone(),
+            Vec::from_array(&env, [creator.clone()]),
+            Vec::from_array(&env, [1]),
+            now,
+        );
+        storage::store_group(&env, new_group_id, &group);
+        if let Some(metadata) = storage::get_group_metadata(&env, group_id) {
+            storage::store_group_metadata(&env, new_group_id, &metadata);
+        }
+
+        events::emit_group_created(&env, &group);
+        events::emit_group_terms(&env, &group);
+        events::emit_group_cloned(&env, new_group_id, group_id);
+
+        Ok(new_group_id)
+    }
+
+    /// Set the organizer commission for a group.
+    ///
+    /// The creator is paid this commission out of every payout for organizing the
+    /// group. Terms are disclosed through `get_group` and `get_group_status`.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `group_id` - The unique group identifier
+    /// * `commission` - Basis points of each payout, or a fixed amount per payout
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    /// * `GroupTermsLocked` - If another member has already joined
+    /// * `InvalidCommission` - If the commission is negative or above the maximum
+    pub fn set_organizer_commission(
+        env: Env,
+        group_id: u64,
+        commission: OrganizerCommission,
+    ) -> Result<(), AjoError> {
+        let group = utils::update_terms(&env, group_id, |terms| {
+            terms.commission = commission;
+            Ok(())
+        })?;
+
+        events::emit_commission_set(&env, group_id, &group.commission);
+
+        Ok(())
+    }
+
+    /// Set the penalty distribution policy for a group.
+    ///
+    /// Controls where late penalties collected in each cycle end up: the cycle's
+    /// payout recipient (default), the members who paid on time, a group reserve
+    /// released at completion, the insurance fund for the group's contribution
+    /// token, or the protocol treasury.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `group_id` - The unique group identifier
+    /// * `policy` - The penalty distribution policy
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    /// * `GroupTermsLocked` - If another member has already joined
+    /// * `NoContributionToken` - If the policy is `InsurancePool` and the group has
+    ///   no contribution token
+    pub fn set_penalty_policy(
+        env: Env,
+        group_id: u64,
+        policy: PenaltyPolicy,
+    ) -> Result<(), AjoError> {
+        utils::update_terms(&env, group_id, |terms| {
+            terms.penalty_policy = policy;
+            Ok(())
+        })?;
+
+        events::emit_penalty_policy_set(&env, group_id, policy);
+
+        Ok(())
+    }
+
+    /// Set a time-scaled penalty schedule for a group.
+    ///
+    /// Replaces the flat `penalty_rate` with a linear per-hour rate or stepped
+    /// tiers, both in basis points and capped at a maximum.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `group_id` - The unique group identifier
+    /// * `schedule` - The penalty schedule
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    /// * `GroupTermsLocked` - If another member has already joined
+    /// * `InvalidGroupTerms` - If the schedule is malformed
+    pub fn set_penalty_schedule(
+        env: Env,
+        group_id: u64,
+        schedule: PenaltySchedule,
+    ) -> Result<(), AjoError> {
+        let group = utils::update_terms(&env, group_id, |terms| {
+            terms.penalty_schedule = schedule;
+            Ok(())
+        })?;
+
+        events::emit_penalty_schedule_set(&env, group_id, &group.penalty_schedule);
+
+        Ok(())
+    }
+
+    /// Set how cycle start times are scheduled for a group.
+    ///
+    /// `Rolling` (the default) starts each cycle when the previous payout runs.
+    /// `Anchored` fixes cycle N to `created_at + (N - 1) × cycle_duration`, and
+    /// `Calendar` uses an explicit list of start times, one per cycle. Anchored and
+    /// calendar cycles that begin after their scheduled end enter a short catch-up
+    /// window so the group can return to schedule.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `group_id` - The unique group identifier
+    /// * `schedule` - The cycle schedule
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    /// * `GroupTermsLocked` - If another member has already joined
+    /// * `InvalidCycleSchedule` - If calendar start times are unordered or too few
+    pub fn set_cycle_schedule(
+        env: Env,
+        group_id: u64,
+        schedule: CycleSchedule,
+    ) -> Result<(), AjoError> {
+        let group = utils::update_terms(&env, group_id, |terms| {
+            terms.cycle_schedule = schedule;
+            Ok(())
+        })?;
+
+        events::emit_cycle_schedule_set(&env, group_id, &group.cycle_schedule);
+
+        Ok(())
+    }
+
+    /// Preview the penalty a contribution would incur at a given time.
+    ///
+    /// Evaluates the group's penalty schedule against the current cycle as if a
+    /// contribution were made at `at_time`.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `group_id` - The unique group identifier
+    /// * `at_time` - Unix timestamp of the hypothetical contribution
+    ///
+    /// # Returns
+    /// Penalty amount in stroops (0 if on time)
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    /// * `GracePeriodExpired` - If `at_time` is after the grace period
+    pub fn preview_penalty(env: Env, group_id: u64, at_time: u64) -> Result<i128, AjoError> {
+        let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+
+        if at_time >= utils::get_grace_period_end(&group) {
+            return Err(AjoError::GracePeriodExpired);
+        }
+
+        let (_, penalty_amount) =
+            utils::check_contribution_timing(&group, at_time, group.contribution_amount);
+        Ok(penalty_amount)
+    }
+
+    /// Get the penalty reserve held for a group.
+    ///
+    /// Only groups using [`PenaltyPolicy::GroupReserve`] (or on-time splits where
+    /// nobody paid on time) accumulate a reserve. It is split among all members
+    /// when the group completes.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `group_id` - The unique group identifier
+    ///
+    /// # Returns
+    /// Reserve balance in stroops
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group doesn't exist
+    pub fn get_group_reserve(env: Env, group_id: u64) -> Result<i128, AjoError> {
+        storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        Ok(storage::get_group_reserve(&env, group_id))
+    }
+
+    /// Get the total penalty shares a member has received from a group.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `group_id` - The unique group identifier
+    /// * `member` - The member's address
+    ///
+    /// # Returns
+    /// Total penalty shares in stroops
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group doesn't exist
+    pub fn get_penalty_earnings(env: Env, group_id: u64, member: Address) -> Result<i128, AjoError> {
+        storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        Ok(storage::get_penalty_earnings(&env, group_id, &member))
+    }
+
+    /// Get the protocol treasury balance funded by penalties.
+    pub fn get_treasury_balance(env: Env) -> i128 {
+        storage::get_treasury(&env)
+    }
+
+    /// Choose where a member's payout is sent.
+    ///
+    /// Redirects the payout to another address, or splits it by basis points across
+    /// up to [`MAX_PAYOUT_SPLITS`](crate::types::MAX_PAYOUT_SPLITS) addresses (for
+    /// example 30% to a savings vault). Pass an empty list to send the payout to the
+    /// member again. Splits are locked once the member's payout cycle has started.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `member` - The member whose payout is redirected (must authenticate)
+    /// * `group_id` - The unique group identifier
+    /// * `splits` - Receiving addresses and their shares, totalling 10,000 bps
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    /// * `GroupCancelled` - If the group has been cancelled
+    /// * `NotMember` - If the address is not a member
+    /// * `PayoutSplitLocked` - If the member's payout cycle has started
+    /// * `InvalidPayoutSplit` - If a share is zero, there are too many splits, the
+    ///   shares don't total 100%, or the member's seat is shared (its payout is split
+    ///   among the co-holders)
+    pub fn set_payout_splits(
+        env: Env,
+        member: Address,
+        group_id: u64,
+        splits: Vec<PayoutSplit>,
+    ) -> Result<(), AjoError> {
+        pausable::ensure_not_paused(&env)?;
+        member.require_auth();
+
+        let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        if group.state == crate::types::GroupState::Cancelled {
+            return Err(AjoError::GroupCancelled);
+        }
+        if !utils::is_member(&group.members, &member) {
+            return Err(AjoError::NotMember);
+        }
+        if utils::has_payout_cycle_started(&group, &member) {
+            return Err(AjoError::PayoutSplitLocked);
+        }
+        if storage::get_seat_holders(&env, group_id, &member).is_some() {
+            return Err(AjoError::InvalidPayoutSplit);
+        }
+        utils::validate_payout_splits(&splits)?;
+
+        storage::store_payout_splits(&env, group_id, &member, &splits);
+
+        events::emit_payout_splits_set(&env, group_id, &member, splits.len());
+
+        Ok(())
+    }
+
+    /// Get where a member's payout will be sent.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `group_id` - The unique group identifier
+    /// * `member` - The member's address
+    ///
+    /// # Returns
+    /// The member's payout splits, empty if the payout goes to the member
     ///
     /// # Errors
     /// * `GroupNotFound` - If the group does not exist
-    pub fn get_contribution_status(
+    pub fn get_payout_splits(
         env: Env,
         group_id: u64,
-        cycle_number: u32,
-    ) -> Result<Vec<(Address, bool)>, AjoError> {
-        let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
-        Ok(storage::get_cycle_contributions(
-            &env,
-            group_id,
-            cycle_number,
-            &group.members,
-        ))
+        member: Address,
+    ) -> Result<Vec<PayoutSplit>, AjoError> {
+        storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        Ok(storage::get_payout_splits(&env, group_id, &member))
     }
 
-    /// Execute payout for the current cycle.
-    ///
-    /// This is the core function that rotates payouts through group members.
-    /// It verifies that all members have contributed, calculates the total payout
-    /// (including any penalties collected), distributes funds to the next recipient,
-    /// and advances the cycle. When all members have received their payout, the group
-    /// is marked complete.
-    ///
-    /// Payout can only be executed after the grace period expires to ensure all
-    /// late contributions are collected.
+    /// Opt into the group's next round.
     ///
-    /// Process:
-    /// 1. Verifies all members have contributed in the current cycle
-    /// 2. Ensures grace period has expired
-    /// 3. Calculates total payout (contribution_amount × member_count + penalties)
-    /// 4. Records payout to the current recipient
-    /// 5. Emits payout event with penalty bonus
-    /// 6. Advances to next cycle (or marks complete if done)
+    /// When the group completes, members who opted in are moved into a new group with
+    /// the same settings, their seats and tiers, linked to this one through
+    /// `previous_group_id` so their penalty records carry over. Opting in again has no
+    /// effect.
     ///
     /// # Arguments
     /// * `env` - The Soroban contract environment
-    /// * `group_id` - The group to execute payout for
-    ///
-    /// # Returns
-    /// `Ok(())` on successful payout execution
+    /// * `member` - The member continuing into the next round (must authenticate)
+    /// * `group_id` - The unique group identifier
     ///
     /// # Errors
     /// * `GroupNotFound` - If the group does not exist
-    /// * `IncompleteContributions` - If not all members have contributed
-    /// * `GroupComplete` - If the group has already completed all payouts
-    /// * `NoMembers` - If the group has no members (should never happen)
-    /// * `OutsideCycleWindow` - If grace period has not expired yet
-    pub fn execute_payout(env: Env, group_id: u64) -> Result<(), AjoError> {
-        // Check if paused
+    /// * `GroupCancelled` - If the group has been cancelled
+    /// * `GroupComplete` - If the group has already completed
+    /// * `NotMember` - If the address is not a member
+    pub fn opt_in_next_round(env: Env, member: Address, group_id: u64) -> Result<(), AjoError> {
         pausable::ensure_not_paused(&env)?;
+        member.require_auth();
 
-        // Get group
-        let mut group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
-
-        // Check if group is cancelled
+        let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
         if group.state == crate::types::GroupState::Cancelled {
             return Err(AjoError::GroupCancelled);
         }
-
-        // Check if group is complete
         if group.is_complete {
             return Err(AjoError::GroupComplete);
         }
-
-        // Check if all members have contributed
-        if !utils::all_members_contributed(&env, &group) {
-            return Err(AjoError::IncompleteContributions);
-        }
-
-        // Ensure grace period has expired before executing payout
-        let current_time = utils::get_current_timestamp(&env);
-        let grace_end = utils::get_grace_period_end(&group);
-        if current_time < grace_end {
-            // Still within grace period - delay payout
-            return Err(AjoError::OutsideCycleWindow);
-        }
-
-        // Get payout recipient
-        let payout_recipient = group
-            .members
-            .get(group.payout_index)
-            .ok_or(AjoError::NoMembers)?;
-
-        // Calculate base payout amount
-        let base_payout = utils::calculate_payout_amount(&group);
-
-        // Get penalty pool for this cycle
-        let penalty_bonus = storage::get_cycle_penalty_pool(&env, group_id, group.current_cycle);
-
-        // Total payout includes penalties
-        let total_payout = base_payout + penalty_bonus;
-
-        // Transfer payout to recipient
-        // Note: In production, this would use token.transfer() or native transfer
-        // For now, we just record it
-
-        // Mark payout as received
-        storage::mark_payout_received(&env, group_id, &payout_recipient);
-
-        // Emit payout event with penalty information
-        if penalty_bonus > 0 {
-            events::emit_penalty_distributed(
-                &env,
-                group_id,
-                &payout_recipient,
-                group.current_cycle,
-                base_payout,
-                penalty_bonus,
-            );
+        if !utils::is_member(&group.members, &member) {
+            return Err(AjoError::NotMember);
         }
 
-        events::emit_payout_executed(
-            &env,
-            group_id,
-            &payout_recipient,
-            group.current_cycle,
-            total_payout,
-        );
-
-        // Advance payout index
-        group.payout_index += 1;
-
-        // Check if all members have received payout
-        if group.payout_index >= group.members.len() {
-            // All members have received payout - mark complete
-            group.is_complete = true;
-            group.state = crate::types::GroupState::Complete;
-            events::emit_group_completed(&env, group_id);
-        } else {
-            // Advance to next cycle
-            group.current_cycle += 1;
-            group.cycle_start_time = utils::get_current_timestamp(&env);
+        let mut opt_ins = storage::get_renewal_opt_ins(&env, group_id);
+        if opt_ins.contains(&member) {
+            return Ok(());
         }
+        opt_ins.push_back(member.clone());
+        storage::store_renewal_opt_ins(&env, group_id, &opt_ins);
 
-        // Update storage
-        storage::store_group(&env, group_id, &group);
+        events::emit_renewal_opt_in(&env, group_id, &member);
 
         Ok(())
     }
 
-    /// Check if a group has completed all cycles.
-    ///
-    /// Returns whether the group has completed its full rotation,
-    /// meaning all members have received at least one payout.
+    /// Get the members who opted into the group's next round.
     ///
     /// # Arguments
     /// * `env` - The Soroban contract environment
-    /// * `group_id` - The group to check
+    /// * `group_id` - The unique group identifier
     ///
     /// # Returns
-    /// `true` if the group has completed all payouts, `false` otherwise
+    /// Opted-in members in opt-in order
     ///
     /// # Errors
     /// * `GroupNotFound` - If the group does not exist
-    pub fn is_complete(env: Env, group_id: u64) -> Result<bool, AjoError> {
-        let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
-        Ok(group.is_complete)
+    pub fn get_renewal_opt_ins(env: Env, group_id: u64) -> Result<Vec<Address>, AjoError> {
+        storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        Ok(storage::get_renewal_opt_ins(&env, group_id))
     }
 
-    /// Get comprehensive group status.
-    ///
-    /// Returns a detailed snapshot of the group's current state, including cycle
-    /// information, contribution status, payout progress, and timing data.
-    /// This is the primary function for checking a group's overall progress.
+    /// Hand a member's seat over to a new address.
     ///
-    /// Returns information about:
-    /// - Current cycle number and progress
-    /// - Next recipient for payout
-    /// - Members who have contributed and those who are pending
-    /// - Cycle timing (start time, end time, whether cycle is active)
-    /// - Whether the group is complete
+    /// Both addresses must authorize, plus the creator when the group requires
+    /// approval for transfers. The new address takes over the seat's position in the
+    /// payout order, contribution history, prepaid cycles, penalty record, payout and
+    /// refund entitlement, along with any contribution still owed this cycle, so
+    /// `payout_index` is unaffected. A member holding several seats hands all of them
+    /// over. Mandates, payout splits and swap proposals made by the old address are
+    /// cancelled.
     ///
     /// # Arguments
     /// * `env` - The Soroban contract environment
     /// * `group_id` - The unique group identifier
-    ///
-    /// # Returns
-    /// A `GroupStatus` struct containing comprehensive group state information
+    /// * `from` - The member leaving (must authenticate)
+    /// * `to` - The address taking over the seat (must authenticate)
     ///
     /// # Errors
     /// * `GroupNotFound` - If the group does not exist
-    pub fn get_group_status(env: Env, group_id: u64) -> Result<GroupStatus, AjoError> {
-        // Get the group data
-        let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+    /// * `GroupCancelled` - If the group has been cancelled
+    /// * `GroupComplete` - If the group has completed all cycles
+    /// * `NotMember` - If `from` is not a member (co-holders of a shared seat included)
+    /// * `GroupTermsLocked` - If the seat is shared, since its co-holders are fixed
+    ///   when they join
+    /// * `AlreadyMember` - If `to` is already a member
+    pub fn transfer_seat(env: Env, group_id: u64, from: Address, to: Address) -> Result<(), AjoError> {
+        pausable::ensure_not_paused(&env)?;
+        from.require_auth();
+        to.require_auth();
 
-        // Get current timestamp
-        let current_time = utils::get_current_timestamp(&env);
+        let mut group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        if group.seat_transfer_needs_approval {
+            group.creator.require_auth();
+        }
 
-        // Calculate cycle timing
-        let cycle_end_time = group.cycle_start_time + group.cycle_duration;
-        let grace_period_end_time = utils::get_grace_period_end(&group);
-        let is_cycle_active = current_time < cycle_end_time;
-        let is_in_grace_period = utils::is_within_grace_period(&group, current_time);
+        if group.state == crate::types::GroupState::Cancelled {
+            return Err(AjoError::GroupCancelled);
+        }
+        if group.is_complete {
+            return Err(AjoError::GroupComplete);
+        }
+        let seats = utils::member_seats(&env, &group.members, &from);
+        if seats.is_empty() {
+            return Err(AjoError::NotMember);
+        }
+        if storage::get_seat_holders(&env, group_id, &from).is_some() {
+            return Err(AjoError::GroupTermsLocked);
+        }
+        if utils::holds_seat(&env, &group, &to) {
+            return Err(AjoError::AlreadyMember);
+        }
 
-        // Get penalty pool for current cycle
-        let cycle_penalty_pool = storage::get_cycle_penalty_pool(&env, group_id, group.current_cycle);
+        for index in seats.iter() {
+            group.members.set(index, to.clone());
+        }
+        storage::store_group(&env, group_id, &group);
 
-        // Get contribution status for all members in current cycle
-        let contributions =
-            storage::get_cycle_contributions(&env, group_id, group.current_cycle, &group.members);
+        let last_cycle = group
+            .current_cycle
+            .max(storage::get_prepaid_through(&env, group_id, &from));
+        storage::transfer_member_records(&env, group_id, last_cycle, &from, &to);
 
-        // Count contributions and build pending list
-        let mut contributions_received: u32 = 0;
-        let mut pending_contributors = Vec::new(&env);
+        events::emit_seat_transferred(&env, group_id, &from, &to);
 
-        for (member, has_contributed) in contributions.iter() {
-            if has_contributed {
-                contributions_received += 1;
-            } else {
-                pending_contributors.push_back(member);
-            }
-        }
+        Ok(())
+    }
 
-        // Determine next recipient
-        let (has_next_recipient, next_recipient) = if group.is_complete {
-            // Use placeholder (creator) when complete
-            (false, group.creator.clone())
-        } else {
-            // Get the member at payout_index
-            let recipient = group
-                .members
-                .get(group.payout_index)
-                .unwrap_or_else(|| group.creator.clone());
-            (true, recipient)
+    /// Propose swapping payout positions with another member.
+    ///
+    /// Both members must still be waiting for a later cycle's payout: neither can
+    /// have been paid or be the current cycle's recipient. The swap happens once
+    /// `to` calls [`accept_swap`](Self::accept_swap). A new proposal replaces any
+    /// earlier one from the same member.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `group_id` - The unique group identifier
+    /// * `from` - The member proposing the swap (must authenticate)
+    /// * `to` - The member to swap positions with
+    ///
+    /// # Errors
+    /// * `GroupNotFound` - If the group does not exist
+    /// * `GroupCancelled` - If the group has been cancelled
+    /// * `GroupComplete` - If every member has been paid
+    /// * `NotMember` - If either address isn't a member, or both are the same member
+    /// * `AlreadyReceivedPayout` - If either member has been paid or is the current recipient
+    pub fn propose_swap(env: Env, group_id: u64, from: Address, to: Address) -> Result<(), AjoError> {
+        pausable::ensure_not_paused(&env)?;
+        from.require_auth();
+
+        let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        utils::get_swap_positions(&group, &from, &to)?;
+
+        let proposal = SwapProposal {
+            from: from.clone(),
+            to: to.clone(),
+            proposed_at: utils::get_current_timestamp(&env),
         };
+        storage::store_swap_proposal(&env, group_id, &proposal);
 
-        // Build and return status
-        Ok(GroupStatus {
-            group_id: group.id,
-            current_cycle: group.current_cycle,
-            has_next_recipient,
-            next_recipient,
-            contributions_received,
-            total_members: group.members.len() as u32,
-            pending_contributors,
-            is_complete: group.is_complete,
-            is_cycle_active,
-            cycle_start_time: group.cycle_start_time,
-            cycle_end_time,
-            current_time,
-            cycle_penalty_pool,
-            is_in_grace_period,
-            grace_period_end_time,
-        })
+        events::emit_swap_proposed(&env, group_id, &from, &to);
+
+        Ok(())
     }
 
-    /// Set or update metadata for an Ajo group.
+    /// Accept a proposed swap, exchanging both members' payout positions.
     ///
-    /// Only the group creator can set or update metadata.
+    /// Eligibility is checked again, since the proposer or the acceptor may have
+    /// become the current recipient since the swap was proposed.
     ///
     /// # Arguments
     /// * `env` - The Soroban contract environment
     /// * `group_id` - The unique group identifier
-    /// * `name` - The name of the group
-    /// * `description` - The description of the group
-    /// * `rules` - The rules of the group
+    /// * `from` - The member who proposed the swap
+    /// * `to` - The member accepting the swap (must authenticate)
     ///
     /// # Errors
     /// * `GroupNotFound` - If the group does not exist
-    /// * `Unauthorized` - If the caller is not the group creator
-    /// * `MetadataTooLong` - If any field exceeds its length limit
-    pub fn set_group_metadata(
-        env: Env,
-        group_id: u64,
-        name: soroban_sdk::String,
-        description: soroban_sdk::String,
-        rules: soroban_sdk::String,
-    ) -> Result<(), AjoError> {
-        // Validate lengths
-        if name.len() > crate::types::MAX_NAME_LENGTH
-            || description.len() > crate::types::MAX_DESCRIPTION_LENGTH
-            || rules.len() > crate::types::MAX_RULES_LENGTH
-        {
-            return Err(AjoError::MetadataTooLong);
-        }
+    /// * `Unauthorized` - If `from` has no pending proposal to `to`
+    /// * `AlreadyReceivedPayout` - If either member has since become the current recipient
+    /// * `InsufficientBalance` - If the new order would leave a tiered payout unfunded
+    pub fn accept_swap(env: Env, group_id: u64, from: Address, to: Address) -> Result<(), AjoError> {
+        pausable::ensure_not_paused(&env)?;
+        to.require_auth();
 
-        // Get group to verify existence and check creator
-        let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
+        let mut group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
 
-        // Require creator authentication
-        group.creator.require_auth();
+        let proposal = storage::get_swap_proposal(&env, group_id, &from)
+            .filter(|proposal| proposal.to == to)
+            .ok_or(AjoError::Unauthorized)?;
+        let (from_index, to_index) = utils::get_swap_positions(&group, &proposal.from, &to)?;
+
+        // Each member keeps their tier in the new position
+        let from_tier = utils::seat_tier(&group, from_index);
+        let to_tier = utils::seat_tier(&group, to_index);
+        group.members.set(from_index, to.clone());
+        group.members.set(to_index, from.clone());
+        group.seat_tiers.set(from_index, to_tier);
+        group.seat_tiers.set(to_index, from_tier);
+        utils::validate_tier_funding(&group)?;
+        storage::store_group(&env, group_id, &group);
+        storage::remove_swap_proposal(&env, group_id, &from);
 
-        // Create and store metadata
-        let metadata = GroupMetadata {
-            name,
-            description,
-            rules,
-        };
-        storage::store_group_metadata(&env, group_id, &metadata);
+        // Cycle n pays the member at position n - 1
+        events::emit_positions_swapped(&env, group_id, &from, &to, to_index + 1, from_index + 1);
 
         Ok(())
     }
 
-    /// Get metadata for an Ajo group.
+    /// Get a member's pending swap proposal.
     ///
     /// # Arguments
     /// * `env` - The Soroban contract environment
     /// * `group_id` - The unique group identifier
+    /// * `from` - The member who proposed the swap
     ///
     /// # Returns
-    /// The group metadata
+    /// The pending swap proposal, or `None` if there isn't one
+    pub fn get_swap_proposal(env: Env, group_id: u64, from: Address) -> Option<SwapProposal> {
+        storage::get_swap_proposal(&env, group_id, &from)
+    }
+
+    /// Get the payout record for a member.
+    ///
+    /// Returns the net amount paid to the member, the organizer commission
+    /// deducted from their payout, and how the net amount was split. For a member
+    /// holding several seats, this is their most recent payout.
+    ///
+    /// # Arguments
+    /// * `env` - The Soroban contract environment
+    /// * `group_id` - The unique group identifier
+    /// * `member` - The member's address
+    ///
+    /// # Returns
+    /// The payout record
     ///
     /// # Errors
-    /// * `GroupNotFound` - If metadata for the group doesn't exist
-    pub fn get_group_metadata(env: Env, group_id: u64) -> Result<GroupMetadata, AjoError> {
-        storage::get_group_metadata(&env, group_id).ok_or(AjoError::GroupNotFound)
+    /// * `GroupNotFound` - If no payout record exists
+    pub fn get_payout_record(
+        env: Env,
+        group_id: u64,
+        member: Address,
+    ) -> Result<crate::types::PayoutRecord, AjoError> {
+        storage::get_payout_record(&env, group_id, &member).ok_or(AjoError::GroupNotFound)
     }
 
     /// Get member penalty statistics.
@@ -765,7 +2357,7 @@ impl AjoContract {
         let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
 
         // Verify member
-        if !utils::is_member(&group.members, &member) {
+        if !utils::holds_seat(&env, &group, &member) {
             return Err(AjoError::NotMember);
         }
 
@@ -810,8 +2402,8 @@ impl AjoContract {
 
     /// Get the penalty pool for a specific cycle.
     ///
-    /// Returns the total penalties collected during a cycle, which will be
-    /// distributed to the payout recipient along with regular contributions.
+    /// Returns the total penalties collected during a cycle, which are distributed
+    /// at payout according to the group's penalty policy.
     ///
     /// # Arguments
     /// * `env` - The Soroban contract environment
@@ -846,7 +2438,8 @@ impl AjoContract {
     /// # Errors
     /// * `GroupNotFound` - If the group doesn't exist
     /// * `OnlyCreatorCanCancel` - If the caller is not the group creator
-    /// * `CannotCancelAfterPayout` - If any payout has been executed
+    /// * `CannotCancelAfterPayout` - If any payout has been executed, or a target savings
+    ///   group has closed a cycle or had a withdrawal
     /// * `GroupCancelled` - If the group is already cancelled
     /// * `GroupComplete` - If the group is already complete
     pub fn cancel_group(env: Env, creator: Address, group_id: u64) -> Result<(), AjoError> {
@@ -868,29 +2461,38 @@ impl AjoContract {
             return Err(AjoError::GroupComplete);
         }
 
-        // Cannot cancel after first payout
-        if group.payout_index > 0 {
+        // Cannot cancel after first payout, or once savings have closed a cycle or
+        // been withdrawn
+        if group.payout_index > 0
+            || group.current_cycle > 1
+            || utils::active_savers(&env, &group).len() < utils::contributors(&env, &group).len()
+        {
             return Err(AjoError::CannotCancelAfterPayout);
         }
 
-        // Calculate refunds for each member who contributed
-        for member in group.members.iter() {
-            if storage::has_contributed(&env, group_id, group.current_cycle, &member) {
-                let refund_amount = group.contribution_amount;
+        // Bring back anything deposited in the vault before refunding
+        vault::withdraw(&env, &group)?;
 
-                // Store refund record
-                let refund_record = crate::types::RefundRecord {
-                    group_id,
-                    member: member.clone(),
-                    amount: refund_amount,
-                    timestamp: utils::get_current_timestamp(&env),
-                    reason: crate::types::RefundReason::CreatorCancellation,
-                };
-                storage::store_refund_record(&env, group_id, &member, &refund_record);
-
-                // Emit refund event
-                events::emit_refund_processed(&env, group_id, &member, refund_amount, 0);
-            }
+        // Refund whoever paid in, including installments, prepaid cycles and sponsors
+        for (payer, refund_amount) in utils::collect_refunds(&env, &group).iter() {
+            // Store refund record
+            let refund_record = crate::types::RefundRecord {
+                group_id,
+                member: payer.clone(),
+                amount: refund_amount,
+                timestamp: utils::get_current_timestamp(&env),
+                reason: crate::types::RefundReason::CreatorCancellation,
+            };
+            storage::store_refund_record(&env, group_id, &payer, &refund_record);
+
+            // Emit refund event
+            events::emit_refund_processed(
+                &env,
+                group_id,
+                &payer,
+                refund_amount,
+                crate::types::RefundReason::CreatorCancellation,
+            );
         }
 
         // Update group state
@@ -937,7 +2539,7 @@ impl AjoContract {
         let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
 
         // Verify member
-        if !utils::is_member(&group.members, &requester) {
+        if !utils::holds_seat(&env, &group, &requester) {
             return Err(AjoError::NotMember);
         }
 
@@ -1014,7 +2616,7 @@ impl AjoContract {
         let group = storage::get_group(&env, group_id).ok_or(AjoError::GroupNotFound)?;
 
         // Verify member
-        if !utils::is_member(&group.members, &voter) {
+        if !utils::holds_seat(&env, &group, &voter) {
             return Err(AjoError::NotMember);
         }
 
@@ -1060,7 +2662,13 @@ impl AjoContract {
     ///
     /// Can be called by any member after the voting period ends. If the refund
     /// is approved (>51% votes in favor), all members receive proportional refunds
-    /// based on their contributions.
+    /// based on their contributions. Otherwise the request is closed as rejected
+    /// and the group carries on.
+    ///
+    /// A rejection is a successful call: the request is marked executed and not
+    /// approved, and a `refreject` event is emitted. Earlier versions returned
+    /// `RefundNotApproved` instead, which rolled the rejection back and left the
+    /// request open.
     ///
     /// # Arguments
     /// * `env` - The Soroban contract environment
@@ -1068,14 +2676,15 @@ impl AjoContract {
     /// * `group_id` - The unique group identifier
     ///
     /// # Returns
-    /// `Ok(())` on successful execution
+    /// `Ok(())` once the request is executed, whether it was approved or rejected
     ///
     /// # Errors
     /// * `GroupNotFound` - If the group doesn't exist
     /// * `NoRefundRequest` - If no refund request exists
     /// * `VotingPeriodActive` - If the voting period hasn't ended
-    /// * `RefundNotApproved` - If the refund wasn't approved
-    /// * `RefundAlreadyExecuted` - If the refund has already been executed
+    /// * `RefundAlreadyExecuted` - If the request has already been executed or rejected
+    ///
+    /// A rejected vote is not an error, so `RefundNotApproved` is never returned.
     pub fn execute_refund(env: Env, executor: Address, group_id: u64) -> Result<(), AjoError> {
         pausable::ensure_not_paused(&env)?;
         executor.require_auth();
@@ -1097,38 +2706,42 @@ impl AjoContract {
 
         // Calculate approval percentage
         let total_votes = request.votes_for + request.votes_against;
-        let approval_percentage = if total_votes > 0 {
-            (request.votes_for * 100) / total_votes
-        } else {
-            0
-        };
+        let approval_percentage = (request.votes_for * 100)
+            .checked_div(total_votes)
+            .unwrap_or(0);
 
         // Check if approved
         if approval_percentage < crate::types::REFUND_APPROVAL_THRESHOLD {
             request.executed = true;
             request.approved = false;
             storage::store_refund_request(&env, group_id, &request);
-            return Err(AjoError::RefundNotApproved);
-        }
-
-        // Process refunds for all members who contributed
-        for member in group.members.iter() {
-            if storage::has_contributed(&env, group_id, group.current_cycle, &member) {
-                let refund_amount = group.contribution_amount;
-
-                // Store refund record
-                let refund_record = crate::types::RefundRecord {
-                    group_id,
-                    member: member.clone(),
-                    amount: refund_amount,
-                    timestamp: now,
-                    reason: crate::types::RefundReason::MemberVote,
-                };
-                storage::store_refund_record(&env, group_id, &member, &refund_record);
-
-                // Emit refund event
-                events::emit_refund_processed(&env, group_id, &member, refund_amount, 1);
-            }
+            events::emit_refund_rejected(&env, group_id, request.votes_for, request.votes_against);
+            return Ok(());
+        }
+
+        // Bring back anything deposited in the vault before refunding
+        vault::withdraw(&env, &group)?;
+
+        // Refund whoever paid in, including installments, prepaid cycles and sponsors
+        for (payer, refund_amount) in utils::collect_refunds(&env, &group).iter() {
+            // Store refund record
+            let refund_record = crate::types::RefundRecord {
+                group_id,
+                member: payer.clone(),
+                amount: refund_amount,
+                timestamp: now,
+                reason: crate::types::RefundReason::MemberVote,
+            };
+            storage::store_refund_record(&env, group_id, &payer, &refund_record);
+
+            // Emit refund event
+            events::emit_refund_processed(
+                &env,
+                group_id,
+                &payer,
+                refund_amount,
+                crate::types::RefundReason::MemberVote,
+            );
         }
 
         // Update request and group state
@@ -1139,6 +2752,8 @@ impl AjoContract {
         group.state = crate::types::GroupState::Cancelled;
         storage::store_group(&env, group_id, &group);
 
+        events::emit_refund_approved(&env, group_id, request.votes_for, request.votes_against);
+
         Ok(())
     }
 
@@ -1178,25 +2793,31 @@ impl AjoContract {
         let now = utils::get_current_timestamp(&env);
         let mut total_refunded = 0i128;
 
-        // Process refunds for all members who contributed
-        for member in group.members.iter() {
-            if storage::has_contributed(&env, group_id, group.current_cycle, &member) {
-                let refund_amount = group.contribution_amount;
-                total_refunded += refund_amount;
-
-                // Store refund record
-                let refund_record = crate::types::RefundRecord {
-                    group_id,
-                    member: member.clone(),
-                    amount: refund_amount,
-                    timestamp: now,
-                    reason: crate::types::RefundReason::EmergencyRefund,
-                };
-                storage::store_refund_record(&env, group_id, &member, &refund_record);
-
-                // Emit refund event
-                events::emit_refund_processed(&env, group_id, &member, refund_amount, 2);
-            }
+        // Bring back anything deposited in the vault before refunding
+        vault::withdraw(&env, &group)?;
+
+        // Refund whoever paid in, including installments, prepaid cycles and sponsors
+        for (payer, refund_amount) in utils::collect_refunds(&env, &group).iter() {
+            total_refunded += refund_amount;
+
+            // Store refund record
+            let refund_record = crate::types::RefundRecord {
+                group_id,
+                member: payer.clone(),
+                amount: refund_amount,
+                timestamp: now,
+                reason: crate::types::RefundReason::EmergencyRefund,
+            };
+            storage::store_refund_record(&env, group_id, &payer, &refund_record);
+
+            // Emit refund event
+            events::emit_refund_processed(
+                &env,
+                group_id,
+                &payer,
+                refund_amount,
+                crate::types::RefundReason::EmergencyRefund,
+            );
         }
 
         // Update group state
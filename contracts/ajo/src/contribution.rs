@@ -0,0 +1,434 @@
+use soroban_sdk::{token, Address, Env, Vec};
+
+use crate::errors::AjoError;
+use crate::events;
+use crate::insurance;
+use crate::storage;
+use crate::types::{ContributionRecord, Group, GroupKind, GroupState};
+use crate::utils;
+use crate::vault;
+
+/// Record a payment toward a member's contribution for the current cycle.
+///
+/// Shared by [`contribute`](crate::contract::AjoContract::contribute),
+/// [`contribute_partial`](crate::contract::AjoContract::contribute_partial),
+/// [`contribute_seat`](crate::contract::AjoContract::contribute_seat) and
+/// [`contribute_for`](crate::contract::AjoContract::contribute_for). The caller is
+/// responsible for the pause check and for authenticating the payer.
+///
+/// Payments accumulate in the member's [`ContributionRecord`] until they reach
+/// the contribution of every seat the member holds (or their share of a shared
+/// seat); only then is the member counted as contributed. Each payment made after
+/// the cycle ends is penalized on its own amount, so a late penalty only ever
+/// applies to the balance that was still outstanding. In a target savings group
+/// every payment is also added to the member's savings balance.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `payer` - The address funding the payment (the member, or a sponsor)
+/// * `member` - The member being credited
+/// * `group_id` - The group to contribute to
+/// * `amount` - The installment to pay, or `None` to pay the outstanding balance
+/// * `seat` - A seat to pay the contribution of, by payout position; takes
+///   precedence over `amount`
+///
+/// # Errors
+/// * `GroupNotFound` - If the group does not exist
+/// * `GroupCancelled` - If the group has been cancelled
+/// * `GroupComplete` - If the group has completed all cycles
+/// * `NotMember` - If the address holds no seat, or doesn't hold `seat`
+/// * `AlreadyReceivedPayout` - If the member has withdrawn their savings
+/// * `AlreadyContributed` - If the contribution (or `seat`) is already paid this cycle
+/// * `ContributionAmountZero` - If the installment is zero
+/// * `ContributionAmountNegative` - If the installment is negative
+/// * `InstallmentExceedsBalance` - If the installment is larger than the outstanding balance
+/// * `ContributionPayerMismatch` - If someone else already paid part of this cycle
+/// * `GracePeriodExpired` - If the payment is too late (after grace period)
+pub fn record_contribution(
+    env: &Env,
+    payer: &Address,
+    member: &Address,
+    group_id: u64,
+    amount: Option<i128>,
+    seat: Option<u32>,
+) -> Result<(), AjoError> {
+    let group = storage::get_group(env, group_id).ok_or(AjoError::GroupNotFound)?;
+
+    // Check if group is cancelled
+    if group.state == GroupState::Cancelled {
+        return Err(AjoError::GroupCancelled);
+    }
+
+    // Check if group is complete
+    if group.is_complete {
+        return Err(AjoError::GroupComplete);
+    }
+
+    // Check if member
+    if !utils::holds_seat(env, &group, member) {
+        return Err(AjoError::NotMember);
+    }
+
+    // Savers who withdrew early have left the group
+    if group.kind == GroupKind::TargetSavings && storage::has_received_payout(env, group_id, member)
+    {
+        return Err(AjoError::AlreadyReceivedPayout);
+    }
+
+    // Check if already contributed
+    let cycle = group.current_cycle;
+    if storage::has_contributed(env, group_id, cycle, member) {
+        return Err(AjoError::AlreadyContributed);
+    }
+
+    // Pick up any installments already paid this cycle
+    let mut record = storage::get_contribution_detail(env, group_id, cycle, member).unwrap_or(
+        ContributionRecord {
+            member: member.clone(),
+            group_id,
+            cycle,
+            has_paid: false,
+            amount_paid: 0,
+            timestamp: 0,
+            is_late: false,
+            penalty_amount: 0,
+            is_prepaid: false,
+            payer: payer.clone(),
+            seats_paid: Vec::new(env),
+        },
+    );
+
+    // Refunds go back to a single payer, so one payer funds each cycle
+    if record.payer != *payer {
+        return Err(AjoError::ContributionPayerMismatch);
+    }
+    let obligation = utils::member_obligation(env, &group, member);
+    let outstanding = obligation - record.amount_paid;
+    let current_time = utils::get_current_timestamp(env);
+
+    let installment = match (seat, amount) {
+        (Some(seat), _) => {
+            if group.members.get(seat).as_ref() != Some(member) {
+                return Err(AjoError::NotMember);
+            }
+            if record.seats_paid.contains(seat) {
+                return Err(AjoError::AlreadyContributed);
+            }
+            let seat_amount = utils::seat_contribution(&group, seat);
+            if seat_amount > outstanding {
+                return Err(AjoError::InstallmentExceedsBalance);
+            }
+            record.seats_paid.push_back(seat);
+            seat_amount
+        }
+        (None, amount) => match amount {
+            Some(0) => return Err(AjoError::ContributionAmountZero),
+            Some(amount) if amount < 0 => return Err(AjoError::ContributionAmountNegative),
+            Some(amount) if amount > outstanding => {
+                return Err(AjoError::InstallmentExceedsBalance)
+            }
+            Some(amount) => amount,
+            None => {
+                // Pay everything due through the current contribution period
+                let period = utils::current_period(&group, current_time);
+                let due = utils::due_by_period(&group, obligation, period) - record.amount_paid;
+                if due <= 0 {
+                    return Err(AjoError::AlreadyContributed);
+                }
+                due
+            }
+        },
+    };
+
+    // Check the payment against the contribution period deadlines
+    let (is_late, penalty_amount, too_late) = utils::check_period_timing(
+        &group,
+        current_time,
+        record.amount_paid,
+        obligation,
+        installment,
+    );
+
+    // If too late (after grace period), reject
+    if too_late {
+        return Err(AjoError::GracePeriodExpired);
+    }
+
+    // Transfer installment to contract
+    // Note: In production, this would use token.transfer() or native transfer
+    // For now, we record it (assuming payment succeeded)
+    insurance::collect_premium(env, &group, member, cycle, installment);
+    vault::deposit(env, &group, cycle, installment);
+    if group.kind == GroupKind::TargetSavings {
+        storage::add_savings_balance(env, group_id, member, installment);
+    }
+
+    record.amount_paid += installment;
+    record.has_paid = record.amount_paid == obligation;
+    record.timestamp = current_time;
+    record.is_late = record.is_late || is_late;
+    record.penalty_amount += penalty_amount;
+    storage::store_contribution_detail(env, group_id, cycle, member, &record);
+
+    // Penalties are pooled as each late installment comes in
+    if penalty_amount > 0 {
+        storage::add_to_penalty_pool(env, group_id, cycle, penalty_amount);
+    }
+
+    if payer != member {
+        events::emit_contribution_sponsored(env, group_id, payer, member, cycle, installment);
+    }
+
+    if amount.is_some() || seat.is_some() || installment < outstanding {
+        events::emit_installment_paid(
+            env,
+            group_id,
+            member,
+            cycle,
+            installment,
+            obligation - record.amount_paid,
+        );
+    }
+
+    if !record.has_paid {
+        return Ok(());
+    }
+
+    // Also store simple boolean flag for backward compatibility
+    storage::store_contribution(env, group_id, cycle, member, true);
+
+    if record.is_late && record.penalty_amount > 0 {
+        utils::update_member_penalty_record(env, group_id, member, true, record.penalty_amount);
+
+        // Emit late contribution event
+        events::emit_late_contribution(
+            env,
+            group_id,
+            member,
+            cycle,
+            obligation,
+            record.penalty_amount,
+        );
+    } else {
+        // Update member record for on-time contribution
+        utils::update_member_penalty_record(env, group_id, member, false, 0);
+
+        // Emit regular contribution event
+        events::emit_contribution_made(env, group_id, member, cycle, obligation);
+    }
+
+    Ok(())
+}
+
+/// Prepay a member's contributions for upcoming cycles.
+///
+/// Credits the `cycles` cycles following the later of the current cycle and the
+/// member's last prepaid cycle, covering every seat the member holds (or their share
+/// of a shared seat). Each credited
+/// cycle is stored as a completed, on-time contribution, so [`storage::has_contributed`] reports it as paid. The caller is
+/// responsible for the pause check and for authenticating the member.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `member` - The member prepaying
+/// * `group_id` - The group to prepay
+/// * `cycles` - Number of future cycles to pay for
+///
+/// # Returns
+/// The last cycle now prepaid
+///
+/// # Errors
+/// * `GroupNotFound` - If the group does not exist
+/// * `GroupCancelled` - If the group has been cancelled
+/// * `GroupComplete` - If the group has completed all cycles
+/// * `NotMember` - If the address is not a member
+/// * `AlreadyReceivedPayout` - If the member has withdrawn their savings
+/// * `InvalidPrepayment` - If `cycles` is zero or runs past the group's final cycle
+pub fn prepay(env: &Env, member: &Address, group_id: u64, cycles: u32) -> Result<u32, AjoError> {
+    let group = storage::get_group(env, group_id).ok_or(AjoError::GroupNotFound)?;
+
+    if group.state == GroupState::Cancelled {
+        return Err(AjoError::GroupCancelled);
+    }
+
+    if group.is_complete {
+        return Err(AjoError::GroupComplete);
+    }
+
+    if !utils::holds_seat(env, &group, member) {
+        return Err(AjoError::NotMember);
+    }
+
+    if group.kind == GroupKind::TargetSavings && storage::has_received_payout(env, group_id, member)
+    {
+        return Err(AjoError::AlreadyReceivedPayout);
+    }
+
+    if cycles == 0 {
+        return Err(AjoError::InvalidPrepayment);
+    }
+
+    let first_cycle = group
+        .current_cycle
+        .max(storage::get_prepaid_through(env, group_id, member))
+        + 1;
+    let last_cycle = first_cycle.saturating_add(cycles - 1);
+    if last_cycle > utils::total_cycles(&group) {
+        return Err(AjoError::InvalidPrepayment);
+    }
+
+    // Transfer prepayment to contract
+    // Note: In production, this would use token.transfer() or native transfer
+    // For now, we record it (assuming payment succeeded)
+    let obligation = utils::member_obligation(env, &group, member);
+    insurance::collect_premium(env, &group, member, first_cycle, obligation * cycles as i128);
+    vault::deposit(env, &group, first_cycle, obligation * cycles as i128);
+    if group.kind == GroupKind::TargetSavings {
+        storage::add_savings_balance(env, group_id, member, obligation * cycles as i128);
+    }
+
+    let current_time = utils::get_current_timestamp(env);
+    for cycle in first_cycle..=last_cycle {
+        let record = ContributionRecord {
+            member: member.clone(),
+            group_id,
+            cycle,
+            has_paid: true,
+            amount_paid: obligation,
+            timestamp: current_time,
+            is_late: false,
+            penalty_amount: 0,
+            is_prepaid: true,
+            payer: member.clone(),
+            seats_paid: Vec::new(env),
+        };
+        storage::store_contribution_detail(env, group_id, cycle, member, &record);
+        storage::store_contribution(env, group_id, cycle, member, true);
+    }
+    storage::store_prepaid_through(env, group_id, member, last_cycle);
+
+    events::emit_cycles_prepaid(
+        env,
+        group_id,
+        member,
+        first_cycle,
+        last_cycle,
+        obligation * cycles as i128,
+    );
+
+    Ok(last_cycle)
+}
+
+/// Count prepaid contributions for a cycle that has just started.
+///
+/// Prepaid cycles are credited up front, but a member's reliability record and the
+/// contribution event are only updated once the cycle actually starts.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `group` - The group, already advanced to the new cycle
+pub fn recognize_prepaid_contributions(env: &Env, group: &Group) {
+    let group_id = group.id;
+    let cycle = group.current_cycle;
+    for member in utils::contributors(env, group).iter() {
+        let Some(record) = storage::get_contribution_detail(env, group_id, cycle, &member) else {
+            continue;
+        };
+        if record.is_prepaid {
+            utils::update_member_penalty_record(env, group_id, &member, false, 0);
+            events::emit_contribution_made(env, group_id, &member, cycle, record.amount_paid);
+        }
+    }
+}
+
+/// Pull due contributions for every member with an auto-debit mandate.
+///
+/// For each mandated member who still owes the current cycle, records the balance
+/// due through the current contribution period as paid, provided their balance on
+/// the group's token and what is left of their allowance cover it (plus any
+/// insurance premium). Like every other flow in the contract the pull is
+/// bookkeeping only; no tokens move. The balance is only checked, but the allowance
+/// is used up: each pull is added to the mandate's `pulled` tally, and the
+/// allowance must cover the tally plus the amount due. A pull made during the grace period is penalized like any late contribution.
+/// A pull that fails (for example an expired allowance, an empty balance or a
+/// contribution the contract rejects) is skipped with an `autofail` event, leaving
+/// the member to contribute manually under the normal late-penalty rules. Nothing
+/// is pulled once the grace period has expired.
+///
+/// A cycle of the mandate is used up by the pull that completes the member's
+/// contribution for the cycle, so a group collecting over several periods pulls
+/// once per period but uses up one cycle; the mandate is removed when none remain.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `group` - The group to pull contributions for
+///
+/// # Returns
+/// The number of contributions pulled
+pub fn pull_auto_contributions(env: &Env, group: &Group) -> u32 {
+    let Some(token) = group.token.clone() else {
+        return 0;
+    };
+    if group.state == GroupState::Cancelled || group.is_complete {
+        return 0;
+    }
+
+    let current_time = utils::get_current_timestamp(env);
+    if !utils::is_within_cycle_window(group, current_time)
+        && !utils::is_within_grace_period(group, current_time)
+    {
+        return 0;
+    }
+
+    let group_id = group.id;
+    let cycle = group.current_cycle;
+    let contract = env.current_contract_address();
+    let token_client = token::Client::new(env, &token);
+    let mut pulled = 0;
+
+    // One pull covers every seat the member holds
+    for member in utils::contributors(env, group).iter() {
+        let Some(mut mandate) = storage::get_mandate(env, group_id, &member) else {
+            continue;
+        };
+        if storage::has_contributed(env, group_id, cycle, &member) {
+            continue;
+        }
+
+        // Installments paid by a sponsor can't be topped up from the member's allowance
+        let record = storage::get_contribution_detail(env, group_id, cycle, &member);
+        if record.as_ref().is_some_and(|record| record.payer != member) {
+            continue;
+        }
+        let amount_paid = record.map(|record| record.amount_paid).unwrap_or(0);
+        let obligation = utils::member_obligation(env, group, &member);
+        let period = utils::current_period(group, current_time);
+        let outstanding = utils::due_by_period(group, obligation, period) - amount_paid;
+        if outstanding <= 0 {
+            continue;
+        }
+
+        let due = outstanding + insurance::premium_for(group, outstanding);
+        let funded = token_client.allowance(&member, &contract) - mandate.pulled >= due
+            && token_client.balance(&member) >= due;
+        if !funded || record_contribution(env, &member, &member, group_id, None, None).is_err() {
+            events::emit_auto_contribution_failed(env, group_id, &member, cycle, outstanding);
+            continue;
+        }
+        events::emit_auto_contribution_pulled(env, group_id, &member, cycle, outstanding);
+        pulled += 1;
+        mandate.pulled += due;
+
+        // A cycle is only used up once the member's contribution is complete
+        if storage::has_contributed(env, group_id, cycle, &member) {
+            mandate.remaining_cycles -= 1;
+        }
+        if mandate.remaining_cycles == 0 {
+            storage::remove_mandate(env, group_id, &member);
+        } else {
+            storage::store_mandate(env, group_id, &mandate);
+        }
+    }
+
+    pulled
+}
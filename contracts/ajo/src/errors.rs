@@ -5,7 +5,8 @@ use soroban_sdk::contracterror;
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 #[repr(u32)]
 pub enum AjoError {
-    /// The specified group wasn't found in storage.
+    /// The specified group wasn't found in storage. Also returned for a missing
+    /// group template, which has no code of its own since the enum is full.
     GroupNotFound = 1,
 
     /// Can't join because the group is already at its member limit.
@@ -17,31 +18,32 @@ pub enum AjoError {
     /// Address isn't a member of the group.
     NotMember = 4,
 
-    /// You've already made your contribution for this cycle.
+    /// You've already made your contribution for this cycle (or contribution period).
     AlreadyContributed = 5,
 
     /// We can't move forward until everyone has contributed.
     IncompleteContributions = 6,
 
-    /// Member has already been paid out.
+    /// Member has already been paid out, or their payout cycle is underway.
     AlreadyReceivedPayout = 7,
 
     /// All cycles for this group are finished.
     GroupComplete = 8,
 
-    /// Contribution amount can't be zero.
+    /// Contribution amount (or a seat's tier) can't be zero.
     ContributionAmountZero = 9,
 
     /// Cycle duration must be greater than zero.
     CycleDurationZero = 10,
 
-    /// Groups need at least 2 members to work.
+    /// Groups need at least 2 members to work, and shared seats at least 2 co-holders.
     MaxMembersBelowMinimum = 11,
 
-    /// Max members exceeds reasonable limit.
+    /// Max members exceeds reasonable limit, or a shared seat has too many co-holders.
     MaxMembersAboveLimit = 18,
 
-    /// Member doesn't have enough balance.
+    /// Member doesn't have enough balance, has no savings to withdraw, or the pot can't
+    /// fund a tiered payout.
     InsufficientBalance = 12,
 
     /// The token transfer didn't go through.
@@ -80,7 +82,8 @@ pub enum AjoError {
     /// Invalid grace period duration.
     InvalidGracePeriod = 25,
 
-    /// Invalid penalty rate (must be 0-100).
+    /// Invalid penalty rate (must be 0-100), or an early withdrawal rate above
+    /// 10,000 basis points.
     InvalidPenaltyRate = 26,
 
     /// Metadata field exceeds maximum length.
@@ -107,7 +110,9 @@ pub enum AjoError {
     /// Voting period has ended.
     VotingPeriodEnded = 34,
 
-    /// Refund request was not approved.
+    /// The refund vote didn't pass. No longer returned: `execute_refund` now closes
+    /// a rejected request successfully so the rejection is kept. The code stays
+    /// reserved so clients decoding it aren't broken.
     RefundNotApproved = 35,
 
     /// Refund has already been executed.
@@ -115,4 +120,49 @@ pub enum AjoError {
 
     /// Cannot request refund before cycle deadline.
     CycleNotExpired = 37,
+
+    /// Organizer commission is negative or above the allowed maximum.
+    InvalidCommission = 38,
+
+    /// Group terms can't be changed once another member has joined, and a shared
+    /// seat's co-holders can't be changed by transferring it.
+    GroupTermsLocked = 39,
+
+    /// The operation doesn't apply to this kind of group.
+    InvalidGroupKind = 40,
+
+    /// Calendar start times are out of order or don't cover every cycle, a savings
+    /// plan has no cycles or too many, or the contribution interval doesn't evenly
+    /// divide the payout interval.
+    InvalidCycleSchedule = 41,
+
+    /// A group term is outside its allowed range: a negative keeper bounty, a seat,
+    /// tier or recipient limit of zero or above its maximum, an insurance premium
+    /// above the maximum, a seat tier above the group's limit, or a penalty schedule
+    /// whose tiers are out of order or whose rates exceed 100%.
+    InvalidGroupTerms = 42,
+
+    /// Installment is larger than the member's outstanding balance for the cycle.
+    InstallmentExceedsBalance = 43,
+
+    /// Prepaid cycle count is zero or runs past the group's final cycle.
+    InvalidPrepayment = 44,
+
+    /// Someone else has already paid part of this member's contribution this cycle.
+    ContributionPayerMismatch = 45,
+
+    /// Auto-debit mandates must cover at least one cycle.
+    InvalidMandate = 46,
+
+    /// The group has no contribution token, so auto-debit is unavailable.
+    NoContributionToken = 47,
+
+    /// The member has no auto-debit mandate for this group.
+    MandateNotFound = 48,
+
+    /// Payout splits are empty shares, too many, or don't total 100%.
+    InvalidPayoutSplit = 49,
+
+    /// Payout splits can't change once the member's payout cycle has started.
+    PayoutSplitLocked = 50,
 }
@@ -1,22 +1,239 @@
-use soroban_sdk::{symbol_short, Address, Env};
+//! Contract events.
+//!
+//! Every event is published under the same topic shape, `(name, group_id)`, where
+//! `name` is a short symbol such as `contrib` and `group_id` is zero for events not
+//! tied to a group (`init`, `upgraded`, `paused`, `unpaused` and `template`). The
+//! data is a `#[contracttype]` payload struct from [`crate::types`] that starts with
+//! the schema version ([`EVENT_SCHEMA_VERSION`]) and the group id, followed by the
+//! state the event changed, so indexers can decode every event with one schema.
+//!
+//! | Name | Payload | Emitted when |
+//! |------|---------|--------------|
+//! | `init` | [`AdminEvent`] | The contract is initialized |
+//! | `upgraded` | [`UpgradeEvent`] | The contract's Wasm is upgraded |
+//! | `paused` / `unpaused` | [`AdminEvent`] | The admin pauses or unpauses the contract |
+//! | `created` | [`GroupCreatedEvent`] | A group is created, renewed or cloned |
+//! | `joined` | [`MemberEvent`] | A member takes a seat |
+//! | `seatshare` | [`SeatSharedEvent`] | Co-holders join sharing one seat |
+//! | `contrib` | [`MemberAmountEvent`] | A contribution (or prepaid cycle) is credited in full |
+//! | `install` | [`InstallmentEvent`] | A payment leaves part of the contribution owed |
+//! | `late` | [`PenaltyEvent`] | A contribution is paid late |
+//! | `sponsored` | [`SponsoredEvent`] | A third party pays a member's contribution |
+//! | `premium` | [`MemberAmountEvent`] | An insurance premium is paid |
+//! | `vaultin` / `vaultfail` | [`VaultAmountEvent`] | A payment is deposited into the vault, or the vault rejects it |
+//! | `prepaid` | [`PrepaidEvent`] | A member prepays future cycles |
+//! | `autoon` / `autooff` | [`MandateEvent`] / [`MemberEvent`] | An auto-debit mandate is enabled or revoked |
+//! | `autopull` / `autofail` | [`MemberAmountEvent`] | A mandate pull succeeds or fails |
+//! | `insclaim` | [`MemberAmountEvent`] | The insurance fund covers a default |
+//! | `vaultout` | [`VaultWithdrawalEvent`] | The group's funds come back from the vault |
+//! | `cranked` | [`KeeperCrankedEvent`] | A keeper cranks a payout |
+//! | `skipped` | [`CrankSkippedEvent`] | A batched crank skips a group |
+//! | `penshare` / `penpooled` | [`MemberAmountEvent`] / [`PenaltyPooledEvent`] | A cycle's penalties are shared or pooled |
+//! | `commpaid` | [`MemberAmountEvent`] | The organizer commission is paid |
+//! | `payshare` | [`PayoutShareEvent`] | Part of a split payout is sent to one address |
+//! | `pendistr` | [`PenaltyEvent`] | Penalties are added to a payout |
+//! | `payout` | [`MemberAmountEvent`] | A recipient is paid |
+//! | `saveclose` | [`SavingsCycleClosedEvent`] | A target savings cycle closes |
+//! | `savewdraw` | [`PenaltyEvent`] | A saver withdraws |
+//! | `cycle` / `catchup` | [`CycleEvent`] / [`CatchUpEvent`] | The next cycle starts, behind schedule for `catchup` |
+//! | `complete` | [`CycleEvent`] | The group completes |
+//! | `renewed` | [`GroupRenewedEvent`] | A completed group renews into its next round |
+//! | `tokenset`, `seatappr`, `seatmax`, `intervals`, `tiermax`, `payees`, `renewshuf`, `insure`, `vaultset`, `savings`, `bountyset`, `commset`, `penpolicy`, `pensched`, `cyclesch` | Setter payloads | The creator changes the setting |
+//! | `metadata` | [`MetadataSetEvent`] | The creator sets the group's metadata |
+//! | `seattier` | [`SeatTierSetEvent`] | A member chooses a seat's tier |
+//! | `splitset` | [`SplitsSetEvent`] | A member changes where their payout is sent |
+//! | `renewopt` | [`MemberEvent`] | A member opts into the next round |
+//! | `template` | [`TemplateSavedEvent`] | A group template is saved |
+//! | `fromtmpl` / `cloned` | [`GroupSourceEvent`] | A group is created from a template or another group |
+//! | `seatxfer` / `swapprop` | [`MemberPairEvent`] | A seat is handed over, or a swap proposed |
+//! | `swapped` | [`SwapEvent`] | Two members swap payout positions |
+//! | `cancel` | [`GroupCancelledEvent`] | The creator cancels the group |
+//! | `refreq` / `refvote` | [`RefundRequestedEvent`] / [`RefundVoteEvent`] | A refund vote is requested or cast |
+//! | `refund` | [`RefundEvent`] | A refund is paid |
+//! | `refreject` | [`RefundRejectedEvent`] | A refund request is rejected by vote |
+//! | `refaccept` | [`RefundApprovedEvent`] | A refund request is approved by vote and the group cancelled |
+//! | `emrefund` | [`EmergencyRefundEvent`] | The admin refunds the group |
+//!
+//! ## Events by entry point
+//!
+//! Events are emitted in the order listed; those marked `?` only when they apply.
+//! A call that fails emits nothing.
+//!
+//! | Entry point | Events |
+//! |-------------|--------|
+//! | `initialize`, `upgrade`, `pause`, `unpause` | `init`, `upgraded`, `paused`, `unpaused` |
+//! | `create_group` | `created` |
+//! | `create_group_with_terms` | `created`, then the setter event of each term that differs from the `create_group` defaults, in the order `tokenset`, `intervals`, `commset`, `penpolicy`, `pensched`, `bountyset`, `seatappr`, `seatmax`, `tiermax`, `payees`, `insure`, `vaultset`, `savings`, `cyclesch`, `renewshuf` |
+//! | `create_group_from_template` | the `create_group_with_terms` events, then `fromtmpl` |
+//! | `clone_group` | the `create_group_with_terms` events for the copied terms, then `cloned` |
+//! | `save_template` | `template` |
+//! | `join_group` | `joined` |
+//! | `join_shared_seat` | `joined`, `seatshare` |
+//! | `contribute`, `contribute_seat`, `contribute_partial`, `contribute_for` | `premium`?, `vaultin` or `vaultfail`?, `sponsored`?, `install`? for a partial or per-seat payment, then `late` or `contrib` once the cycle is paid in full |
+//! | `prepay` | `premium`?, `vaultin` or `vaultfail`?, `prepaid` |
+//! | `enable_auto_contribute`, `revoke_auto_contribute` | `autoon`, `autooff` |
+//! | `collect_auto_contributions` | per mandate: `autofail`, or the `contribute` events followed by `autopull` |
+//! | `execute_payout`, `crank` | the payout events below |
+//! | `crank_many` | per group: the `collect_auto_contributions` events, then `skipped`, or `insclaim`? and the payout events |
+//! | `withdraw_savings` | `penpooled`? for an early withdrawal penalty, `savewdraw` |
+//! | `set_*` | the setting's event (`tokenset`, `seatappr`, `metadata`, ...) |
+//! | `set_seat_tier`, `set_payout_splits`, `opt_in_next_round` | `seattier`, `splitset`, `renewopt` |
+//! | `transfer_seat`, `propose_swap`, `accept_swap` | `seatxfer`, `swapprop`, `swapped` |
+//! | `cancel_group` | `vaultout`?, `refund` per payer, `cancel` |
+//! | `request_refund`, `vote_refund` | `refreq`, `refvote` |
+//! | `execute_refund` | `refreject`, or `vaultout`?, `refund` per payer, `refaccept` |
+//! | `emergency_refund` | `vaultout`?, `refund` per payer, `emrefund` |
+//!
+//! A rotating payout emits `insclaim`? per covered default, `vaultout`?,
+//! `cranked`?, then `penshare` per member or `penpooled` when the cycle's
+//! penalties aren't added to the payout, `commpaid`?, and for each recipient
+//! `payshare`? per address, `pendistr`? `payout`. It ends with `cycle` and
+//! `catchup`?, or on the last cycle with `penshare`? per member for the group
+//! reserve, `complete`, then `created`? and `renewed`? for the next round. A target savings payout emits the same events up
+//! to `cranked`? and `penpooled`?, then `saveclose` followed by the same ending.
+
+use soroban_sdk::{symbol_short, Address, BytesN, Env, IntoVal, Symbol, Val, Vec};
+
+use crate::types::{
+    AdminEvent, BountySetEvent, CatchUpEvent, CommissionSetEvent, CrankSkippedEvent, CycleEvent,
+    CycleSchedule, CycleScheduleSetEvent, EmergencyRefundEvent, FlagSetEvent, Group, GroupKind,
+    GroupCancelledEvent, GroupCreatedEvent, GroupMetadata, GroupRenewedEvent, GroupSourceEvent,
+    InstallmentEvent, InsuranceSetEvent, IntervalsSetEvent, KeeperCrankedEvent, LimitSetEvent,
+    MandateEvent, MemberAmountEvent, MemberEvent, MemberPairEvent, MetadataSetEvent,
+    OrganizerCommission, PayoutShareEvent, PenaltyEvent, PenaltyPolicy, PenaltyPolicySetEvent,
+    PenaltyPooledEvent, PenaltySchedule, PenaltyScheduleSetEvent, PrepaidEvent, RefundEvent,
+    RefundApprovedEvent, RefundReason, RefundRejectedEvent, RefundRequestedEvent, RefundVoteEvent,
+    SavingsCycleClosedEvent, SeatSharedEvent, SeatTierSetEvent, SplitsSetEvent, SponsoredEvent,
+    SwapEvent, TargetSavingsSetEvent, TemplateSavedEvent, TokenSetEvent, UpgradeEvent,
+    VaultAmountEvent, VaultSetEvent, VaultWithdrawalEvent, YieldPolicy, EVENT_SCHEMA_VERSION,
+};
+
+/// Publishes an event under the `(name, group_id)` topic shared by every event.
+fn publish<T: IntoVal<Env, Val>>(env: &Env, name: Symbol, group_id: u64, payload: T) {
+    env.events().publish((name, group_id), payload);
+}
+
+/// Emit an event when the contract is initialized with its admin
+pub fn emit_initialized(env: &Env, admin: &Address) {
+    let payload = AdminEvent { version: EVENT_SCHEMA_VERSION, group_id: 0, admin: admin.clone() };
+    publish(env, symbol_short!("init"), 0, payload);
+}
+
+/// Emit an event when the admin upgrades the contract's Wasm
+pub fn emit_upgraded(env: &Env, admin: &Address, wasm_hash: &BytesN<32>) {
+    let payload = UpgradeEvent {
+        version: EVENT_SCHEMA_VERSION,
+        group_id: 0,
+        admin: admin.clone(),
+        wasm_hash: wasm_hash.clone(),
+    };
+    publish(env, symbol_short!("upgraded"), 0, payload);
+}
+
+/// Emit an event when the admin pauses the contract
+pub fn emit_paused(env: &Env, admin: &Address) {
+    let payload = AdminEvent { version: EVENT_SCHEMA_VERSION, group_id: 0, admin: admin.clone() };
+    publish(env, symbol_short!("paused"), 0, payload);
+}
+
+/// Emit an event when the admin unpauses the contract
+pub fn emit_unpaused(env: &Env, admin: &Address) {
+    let payload = AdminEvent { version: EVENT_SCHEMA_VERSION, group_id: 0, admin: admin.clone() };
+    publish(env, symbol_short!("unpaused"), 0, payload);
+}
 
 /// Emit an event when a group is created
-pub fn emit_group_created(
-    env: &Env,
-    group_id: u64,
-    creator: &Address,
-    contribution_amount: i128,
-    max_members: u32,
-) {
-    let topics = (symbol_short!("created"), group_id);
-    env.events()
-        .publish(topics, (creator, contribution_amount, max_members));
+pub fn emit_group_created(env: &Env, group: &Group) {
+    let payload = GroupCreatedEvent {
+        version: EVENT_SCHEMA_VERSION,
+        group_id: group.id,
+        creator: group.creator.clone(),
+        members: group.members.clone(),
+        created_at: group.created_at,
+        contribution_amount: group.contribution_amount,
+        cycle_duration: group.cycle_duration,
+        max_members: group.max_members,
+        grace_period: group.grace_period,
+        penalty_rate: group.penalty_rate,
+    };
+    publish(env, symbol_short!("created"), group.id, payload);
+}
+
+/// Emit the setter event of every term a new group was created with that differs
+/// from the defaults of `create_group`, so indexers see the terms the same way as
+/// if the creator had set them one by one
+pub fn emit_group_terms(env: &Env, group: &Group) {
+    let group_id = group.id;
+    if let Some(token) = &group.token {
+        emit_token_set(env, group_id, token);
+    }
+    if group.contribution_interval != 0 {
+        emit_intervals_set(env, group_id, group.contribution_interval, group.cycle_duration);
+    }
+    if group.commission != OrganizerCommission::None {
+        emit_commission_set(env, group_id, &group.commission);
+    }
+    if group.penalty_policy != PenaltyPolicy::NextRecipient {
+        emit_penalty_policy_set(env, group_id, group.penalty_policy);
+    }
+    if group.penalty_schedule != PenaltySchedule::Flat {
+        emit_penalty_schedule_set(env, group_id, &group.penalty_schedule);
+    }
+    if group.keeper_bounty != 0 {
+        emit_keeper_bounty_set(env, group_id, group.keeper_bounty);
+    }
+    if group.seat_transfer_needs_approval {
+        emit_seat_transfer_approval_set(env, group_id, true);
+    }
+    if group.max_seats_per_member != 1 {
+        emit_max_seats_set(env, group_id, group.max_seats_per_member);
+    }
+    if group.max_contribution_tier != 1 {
+        emit_max_tier_set(env, group_id, group.max_contribution_tier);
+    }
+    if group.recipients_per_cycle != 1 {
+        emit_recipients_per_cycle_set(env, group_id, group.recipients_per_cycle);
+    }
+    if let (Some(token), true) = (&group.token, group.insurance_premium_bps != 0) {
+        emit_insurance_premium_set(env, group_id, token, group.insurance_premium_bps);
+    }
+    if let Some(vault) = &group.vault {
+        emit_vault_set(env, group_id, vault, group.yield_policy);
+    }
+    if group.kind == GroupKind::TargetSavings {
+        emit_target_savings_set(env, group_id, group.savings_cycles, group.early_withdrawal_bps);
+    }
+    if group.cycle_schedule != CycleSchedule::Rolling {
+        emit_cycle_schedule_set(env, group_id, &group.cycle_schedule);
+    }
+    if group.shuffle_on_renewal {
+        emit_renewal_shuffle_set(env, group_id, true);
+    }
 }
 
 /// Emit an event when a member joins a group
 pub fn emit_member_joined(env: &Env, group_id: u64, member: &Address) {
-    let topics = (symbol_short!("joined"), group_id);
-    env.events().publish(topics, member);
+    let payload = MemberEvent { version: EVENT_SCHEMA_VERSION, group_id, member: member.clone() };
+    publish(env, symbol_short!("joined"), group_id, payload);
+}
+
+/// Publishes a [`MemberAmountEvent`] under `name`
+fn publish_member_amount(
+    env: &Env,
+    name: Symbol,
+    group_id: u64,
+    cycle: u32,
+    member: &Address,
+    amount: i128,
+) {
+    let payload = MemberAmountEvent {
+        version: EVENT_SCHEMA_VERSION,
+        group_id,
+        cycle,
+        member: member.clone(),
+        amount,
+    };
+    publish(env, name, group_id, payload);
 }
 
 /// Emit an event when a member contributes
@@ -27,8 +244,358 @@ pub fn emit_contribution_made(
     cycle: u32,
     amount: i128,
 ) {
-    let topics = (symbol_short!("contrib"), group_id, cycle);
-    env.events().publish(topics, (member, amount));
+    publish_member_amount(env, symbol_short!("contrib"), group_id, cycle, member, amount);
+}
+
+/// Emit an event when a member pays an installment toward their contribution
+pub fn emit_installment_paid(
+    env: &Env,
+    group_id: u64,
+    member: &Address,
+    cycle: u32,
+    amount: i128,
+    outstanding: i128,
+) {
+    let payload = InstallmentEvent {
+        version: EVENT_SCHEMA_VERSION,
+        group_id,
+        cycle,
+        member: member.clone(),
+        amount,
+        outstanding,
+    };
+    publish(env, symbol_short!("install"), group_id, payload);
+}
+
+/// Emit an event when a third party pays a member's contribution
+pub fn emit_contribution_sponsored(
+    env: &Env,
+    group_id: u64,
+    payer: &Address,
+    member: &Address,
+    cycle: u32,
+    amount: i128,
+) {
+    let payload = SponsoredEvent {
+        version: EVENT_SCHEMA_VERSION,
+        group_id,
+        cycle,
+        payer: payer.clone(),
+        member: member.clone(),
+        amount,
+    };
+    publish(env, symbol_short!("sponsored"), group_id, payload);
+}
+
+/// Emit an event when the creator sets the group's contribution token
+pub fn emit_token_set(env: &Env, group_id: u64, token: &Address) {
+    let payload = TokenSetEvent { version: EVENT_SCHEMA_VERSION, group_id, token: token.clone() };
+    publish(env, symbol_short!("tokenset"), group_id, payload);
+}
+
+/// Emit an event when a member enables an auto-debit mandate
+pub fn emit_auto_contribute_enabled(env: &Env, group_id: u64, member: &Address, max_cycles: u32) {
+    let payload = MandateEvent {
+        version: EVENT_SCHEMA_VERSION,
+        group_id,
+        member: member.clone(),
+        max_cycles,
+    };
+    publish(env, symbol_short!("autoon"), group_id, payload);
+}
+
+/// Emit an event when an auto-debit mandate is revoked
+pub fn emit_auto_contribute_revoked(env: &Env, group_id: u64, member: &Address) {
+    let payload = MemberEvent { version: EVENT_SCHEMA_VERSION, group_id, member: member.clone() };
+    publish(env, symbol_short!("autooff"), group_id, payload);
+}
+
+/// Emit an event when a contribution is pulled under an auto-debit mandate
+pub fn emit_auto_contribution_pulled(
+    env: &Env,
+    group_id: u64,
+    member: &Address,
+    cycle: u32,
+    amount: i128,
+) {
+    publish_member_amount(env, symbol_short!("autopull"), group_id, cycle, member, amount);
+}
+
+/// Emit an event when an auto-debit pull fails (e.g. insufficient allowance or balance)
+pub fn emit_auto_contribution_failed(
+    env: &Env,
+    group_id: u64,
+    member: &Address,
+    cycle: u32,
+    amount: i128,
+) {
+    publish_member_amount(env, symbol_short!("autofail"), group_id, cycle, member, amount);
+}
+
+/// Emit an event when a member prepays a range of future cycles
+pub fn emit_cycles_prepaid(
+    env: &Env,
+    group_id: u64,
+    member: &Address,
+    first_cycle: u32,
+    last_cycle: u32,
+    amount: i128,
+) {
+    let payload = PrepaidEvent {
+        version: EVENT_SCHEMA_VERSION,
+        group_id,
+        member: member.clone(),
+        first_cycle,
+        last_cycle,
+        amount,
+    };
+    publish(env, symbol_short!("prepaid"), group_id, payload);
+}
+
+/// Emit an event when a member changes where their payout is sent
+pub fn emit_payout_splits_set(env: &Env, group_id: u64, member: &Address, split_count: u32) {
+    let payload = SplitsSetEvent {
+        version: EVENT_SCHEMA_VERSION,
+        group_id,
+        member: member.clone(),
+        split_count,
+    };
+    publish(env, symbol_short!("splitset"), group_id, payload);
+}
+
+/// Emit an event for each address receiving part of a payout
+pub fn emit_payout_share(
+    env: &Env,
+    group_id: u64,
+    member: &Address,
+    cycle: u32,
+    recipient: &Address,
+    amount: i128,
+) {
+    let payload = PayoutShareEvent {
+        version: EVENT_SCHEMA_VERSION,
+        group_id,
+        cycle,
+        member: member.clone(),
+        recipient: recipient.clone(),
+        amount,
+    };
+    publish(env, symbol_short!("payshare"), group_id, payload);
+}
+
+/// Publishes a [`FlagSetEvent`] under `name`
+fn publish_flag(env: &Env, name: Symbol, group_id: u64, enabled: bool) {
+    let payload = FlagSetEvent { version: EVENT_SCHEMA_VERSION, group_id, enabled };
+    publish(env, name, group_id, payload);
+}
+
+/// Publishes a [`LimitSetEvent`] under `name`
+fn publish_limit(env: &Env, name: Symbol, group_id: u64, value: u32) {
+    let payload = LimitSetEvent { version: EVENT_SCHEMA_VERSION, group_id, value };
+    publish(env, name, group_id, payload);
+}
+
+/// Emit an event when the creator changes whether seat transfers need approval
+pub fn emit_seat_transfer_approval_set(env: &Env, group_id: u64, required: bool) {
+    publish_flag(env, symbol_short!("seatappr"), group_id, required);
+}
+
+/// Emit an event when the creator changes how many seats one address may hold
+pub fn emit_max_seats_set(env: &Env, group_id: u64, max_seats: u32) {
+    publish_limit(env, symbol_short!("seatmax"), group_id, max_seats);
+}
+
+/// Emit an event when the creator sets the group's default insurance premium
+pub fn emit_insurance_premium_set(env: &Env, group_id: u64, token: &Address, premium_bps: u32) {
+    let payload = InsuranceSetEvent {
+        version: EVENT_SCHEMA_VERSION,
+        group_id,
+        token: token.clone(),
+        premium_bps,
+    };
+    publish(env, symbol_short!("insure"), group_id, payload);
+}
+
+/// Emit an event when a contribution's insurance premium is paid into the fund
+pub fn emit_premium_paid(env: &Env, group_id: u64, member: &Address, cycle: u32, amount: i128) {
+    publish_member_amount(env, symbol_short!("premium"), group_id, cycle, member, amount);
+}
+
+/// Emit an event when the insurance fund covers a member's default
+pub fn emit_insurance_claim_paid(
+    env: &Env,
+    group_id: u64,
+    member: &Address,
+    cycle: u32,
+    amount: i128,
+) {
+    publish_member_amount(env, symbol_short!("insclaim"), group_id, cycle, member, amount);
+}
+
+/// Emit an event when the creator sets the group's vault and yield policy
+pub fn emit_vault_set(env: &Env, group_id: u64, vault: &Address, policy: YieldPolicy) {
+    let payload = VaultSetEvent {
+        version: EVENT_SCHEMA_VERSION,
+        group_id,
+        vault: vault.clone(),
+        yield_policy: policy,
+    };
+    publish(env, symbol_short!("vaultset"), group_id, payload);
+}
+
+/// Emit an event when the creator sets separate contribution and payout intervals
+pub fn emit_intervals_set(
+    env: &Env,
+    group_id: u64,
+    contribution_interval: u64,
+    payout_interval: u64,
+) {
+    let payload = IntervalsSetEvent {
+        version: EVENT_SCHEMA_VERSION,
+        group_id,
+        contribution_interval,
+        payout_interval,
+    };
+    publish(env, symbol_short!("intervals"), group_id, payload);
+}
+
+/// Emit an event when the creator sets the highest contribution tier seats may choose
+pub fn emit_max_tier_set(env: &Env, group_id: u64, max_tier: u32) {
+    publish_limit(env, symbol_short!("tiermax"), group_id, max_tier);
+}
+
+/// Emit an event when a member chooses the contribution tier of one of their seats
+pub fn emit_seat_tier_set(env: &Env, group_id: u64, member: &Address, seat: u32, tier: u32) {
+    let payload = SeatTierSetEvent {
+        version: EVENT_SCHEMA_VERSION,
+        group_id,
+        member: member.clone(),
+        seat,
+        tier,
+    };
+    publish(env, symbol_short!("seattier"), group_id, payload);
+}
+
+/// Emit an event when the creator sets how many seats each payout pays
+pub fn emit_recipients_per_cycle_set(env: &Env, group_id: u64, recipients: u32) {
+    publish_limit(env, symbol_short!("payees"), group_id, recipients);
+}
+
+/// Emit an event when the creator makes the group a target savings group
+pub fn emit_target_savings_set(env: &Env, group_id: u64, cycles: u32, early_withdrawal_bps: u32) {
+    let payload = TargetSavingsSetEvent {
+        version: EVENT_SCHEMA_VERSION,
+        group_id,
+        savings_cycles: cycles,
+        early_withdrawal_bps,
+    };
+    publish(env, symbol_short!("savings"), group_id, payload);
+}
+
+/// Emit an event when a target savings cycle closes, with the number of savers who missed it
+pub fn emit_savings_cycle_closed(env: &Env, group_id: u64, cycle: u32, missed: u32) {
+    let payload = SavingsCycleClosedEvent { version: EVENT_SCHEMA_VERSION, group_id, cycle, missed };
+    publish(env, symbol_short!("saveclose"), group_id, payload);
+}
+
+/// Emit an event when a saver withdraws, with any early-withdrawal penalty
+pub fn emit_savings_withdrawn(
+    env: &Env,
+    group_id: u64,
+    member: &Address,
+    cycle: u32,
+    amount: i128,
+    penalty: i128,
+) {
+    let payload = PenaltyEvent {
+        version: EVENT_SCHEMA_VERSION,
+        group_id,
+        cycle,
+        member: member.clone(),
+        amount,
+        penalty,
+    };
+    publish(env, symbol_short!("savewdraw"), group_id, payload);
+}
+
+/// Emit an event when a contribution is deposited into the group's vault
+pub fn emit_vault_deposit(env: &Env, group_id: u64, cycle: u32, amount: i128) {
+    let payload = VaultAmountEvent { version: EVENT_SCHEMA_VERSION, group_id, cycle, amount };
+    publish(env, symbol_short!("vaultin"), group_id, payload);
+}
+
+/// Emit an event when the vault rejects a deposit and the funds stay in the contract
+pub fn emit_vault_deposit_failed(env: &Env, group_id: u64, cycle: u32, amount: i128) {
+    let payload = VaultAmountEvent { version: EVENT_SCHEMA_VERSION, group_id, cycle, amount };
+    publish(env, symbol_short!("vaultfail"), group_id, payload);
+}
+
+/// Emit an event when a group's funds are withdrawn from its vault
+pub fn emit_vault_withdrawal(
+    env: &Env,
+    group_id: u64,
+    cycle: u32,
+    withdrawn: i128,
+    vault_yield: i128,
+) {
+    let payload = VaultWithdrawalEvent {
+        version: EVENT_SCHEMA_VERSION,
+        group_id,
+        cycle,
+        withdrawn,
+        vault_yield,
+    };
+    publish(env, symbol_short!("vaultout"), group_id, payload);
+}
+
+/// Emit an event when co-holders join a group sharing one seat
+pub fn emit_seat_shared(env: &Env, group_id: u64, holders: &Vec<Address>) {
+    let payload = SeatSharedEvent { version: EVENT_SCHEMA_VERSION, group_id, holders: holders.clone() };
+    publish(env, symbol_short!("seatshare"), group_id, payload);
+}
+
+/// Emit an event when a member hands their seat to a new address
+pub fn emit_seat_transferred(env: &Env, group_id: u64, from: &Address, to: &Address) {
+    let payload = MemberPairEvent {
+        version: EVENT_SCHEMA_VERSION,
+        group_id,
+        from: from.clone(),
+        to: to.clone(),
+    };
+    publish(env, symbol_short!("seatxfer"), group_id, payload);
+}
+
+/// Emit an event when a member proposes swapping payout positions
+pub fn emit_swap_proposed(env: &Env, group_id: u64, from: &Address, to: &Address) {
+    let payload = MemberPairEvent {
+        version: EVENT_SCHEMA_VERSION,
+        group_id,
+        from: from.clone(),
+        to: to.clone(),
+    };
+    publish(env, symbol_short!("swapprop"), group_id, payload);
+}
+
+/// Emit an event when two members swap payout positions.
+///
+/// `from_cycle` and `to_cycle` are the cycles each member is now paid in.
+pub fn emit_positions_swapped(
+    env: &Env,
+    group_id: u64,
+    from: &Address,
+    to: &Address,
+    from_cycle: u32,
+    to_cycle: u32,
+) {
+    let payload = SwapEvent {
+        version: EVENT_SCHEMA_VERSION,
+        group_id,
+        from: from.clone(),
+        to: to.clone(),
+        from_cycle,
+        to_cycle,
+    };
+    publish(env, symbol_short!("swapped"), group_id, payload);
 }
 
 /// Emit an event when a payout is executed
@@ -39,20 +606,158 @@ pub fn emit_payout_executed(
     cycle: u32,
     amount: i128,
 ) {
-    let topics = (symbol_short!("payout"), group_id, cycle);
-    env.events().publish(topics, (recipient, amount));
+    publish_member_amount(env, symbol_short!("payout"), group_id, cycle, recipient, amount);
+}
+
+/// Emit an event when the organizer commission is set for a group
+pub fn emit_commission_set(env: &Env, group_id: u64, commission: &OrganizerCommission) {
+    let payload = CommissionSetEvent {
+        version: EVENT_SCHEMA_VERSION,
+        group_id,
+        commission: commission.clone(),
+    };
+    publish(env, symbol_short!("commset"), group_id, payload);
+}
+
+/// Emit an event when the organizer commission is paid out of a payout
+pub fn emit_commission_paid(
+    env: &Env,
+    group_id: u64,
+    organizer: &Address,
+    cycle: u32,
+    amount: i128,
+) {
+    publish_member_amount(env, symbol_short!("commpaid"), group_id, cycle, organizer, amount);
+}
+
+/// Emit an event when the keeper bounty is set for a group
+pub fn emit_keeper_bounty_set(env: &Env, group_id: u64, bounty: i128) {
+    let payload = BountySetEvent { version: EVENT_SCHEMA_VERSION, group_id, bounty };
+    publish(env, symbol_short!("bountyset"), group_id, payload);
+}
+
+/// Emit an event when a keeper cranks a payout.
+///
+/// `overdue_by` is how many seconds the payout had been due, for monitoring
+/// groups that stall.
+pub fn emit_keeper_cranked(
+    env: &Env,
+    group_id: u64,
+    keeper: &Address,
+    cycle: u32,
+    bounty: i128,
+    overdue_by: u64,
+) {
+    let payload = KeeperCrankedEvent {
+        version: EVENT_SCHEMA_VERSION,
+        group_id,
+        cycle,
+        keeper: keeper.clone(),
+        bounty,
+        overdue_by,
+    };
+    publish(env, symbol_short!("cranked"), group_id, payload);
+}
+
+/// Emit an event when a batched crank skips a group that isn't ready
+pub fn emit_crank_skipped(env: &Env, group_id: u64, keeper: &Address, reason: u32) {
+    let payload = CrankSkippedEvent {
+        version: EVENT_SCHEMA_VERSION,
+        group_id,
+        keeper: keeper.clone(),
+        reason,
+    };
+    publish(env, symbol_short!("skipped"), group_id, payload);
+}
+
+/// Emit an event when the creator chooses whether the next round is reshuffled
+pub fn emit_renewal_shuffle_set(env: &Env, group_id: u64, shuffle: bool) {
+    publish_flag(env, symbol_short!("renewshuf"), group_id, shuffle);
+}
+
+/// Emit an event when a member opts into the group's next round
+pub fn emit_renewal_opt_in(env: &Env, group_id: u64, member: &Address) {
+    let payload = MemberEvent { version: EVENT_SCHEMA_VERSION, group_id, member: member.clone() };
+    publish(env, symbol_short!("renewopt"), group_id, payload);
+}
+
+/// Emit an event when a completed group renews into its next round
+pub fn emit_group_renewed(env: &Env, group_id: u64, next_group_id: u64, members: u32) {
+    let payload = GroupRenewedEvent {
+        version: EVENT_SCHEMA_VERSION,
+        group_id,
+        next_group_id,
+        members,
+    };
+    publish(env, symbol_short!("renewed"), group_id, payload);
+}
+
+/// Emit an event when a creator saves a group template
+pub fn emit_template_saved(env: &Env, template_id: u64, creator: &Address) {
+    let payload = TemplateSavedEvent {
+        version: EVENT_SCHEMA_VERSION,
+        group_id: 0,
+        template_id,
+        creator: creator.clone(),
+    };
+    publish(env, symbol_short!("template"), 0, payload);
+}
+
+/// Emit an event when a group is created from a template
+pub fn emit_group_from_template(env: &Env, group_id: u64, template_id: u64) {
+    let payload = GroupSourceEvent { version: EVENT_SCHEMA_VERSION, group_id, source_id: template_id };
+    publish(env, symbol_short!("fromtmpl"), group_id, payload);
+}
+
+/// Emit an event when a group is created as a copy of another group
+pub fn emit_group_cloned(env: &Env, group_id: u64, source_group_id: u64) {
+    let payload = GroupSourceEvent { version: EVENT_SCHEMA_VERSION, group_id, source_id: source_group_id };
+    publish(env, symbol_short!("cloned"), group_id, payload);
 }
 
 /// Emit an event when a group completes all cycles
-pub fn emit_group_completed(env: &Env, group_id: u64) {
-    let topics = (symbol_short!("complete"), group_id);
-    env.events().publish(topics, ());
+pub fn emit_group_completed(env: &Env, group_id: u64, cycle: u32, timestamp: u64) {
+    let payload = CycleEvent { version: EVENT_SCHEMA_VERSION, group_id, cycle, timestamp };
+    publish(env, symbol_short!("complete"), group_id, payload);
 }
 
 /// Emit an event when a cycle advances
 pub fn emit_cycle_advanced(env: &Env, group_id: u64, new_cycle: u32, cycle_start_time: u64) {
-    let topics = (symbol_short!("cycle"), group_id);
-    env.events().publish(topics, (new_cycle, cycle_start_time));
+    let payload = CycleEvent {
+        version: EVENT_SCHEMA_VERSION,
+        group_id,
+        cycle: new_cycle,
+        timestamp: cycle_start_time,
+    };
+    publish(env, symbol_short!("cycle"), group_id, payload);
+}
+
+/// Emit an event when the cycle schedule is set for a group
+pub fn emit_cycle_schedule_set(env: &Env, group_id: u64, schedule: &CycleSchedule) {
+    let payload = CycleScheduleSetEvent {
+        version: EVENT_SCHEMA_VERSION,
+        group_id,
+        schedule: schedule.clone(),
+    };
+    publish(env, symbol_short!("cyclesch"), group_id, payload);
+}
+
+/// Emit an event when a cycle starts after its scheduled end and must catch up
+pub fn emit_cycle_catch_up(
+    env: &Env,
+    group_id: u64,
+    cycle: u32,
+    scheduled_start: u64,
+    catch_up_end: u64,
+) {
+    let payload = CatchUpEvent {
+        version: EVENT_SCHEMA_VERSION,
+        group_id,
+        cycle,
+        scheduled_start,
+        catch_up_end,
+    };
+    publish(env, symbol_short!("catchup"), group_id, payload);
 }
 
 /// Emit an event when a group is cancelled by its creator
@@ -63,9 +768,14 @@ pub fn emit_group_cancelled(
     member_count: u32,
     refund_per_member: i128,
 ) {
-    let topics = (symbol_short!("cancel"), group_id);
-    env.events()
-        .publish(topics, (creator, member_count, refund_per_member));
+    let payload = GroupCancelledEvent {
+        version: EVENT_SCHEMA_VERSION,
+        group_id,
+        creator: creator.clone(),
+        member_count,
+        refund_per_member,
+    };
+    publish(env, symbol_short!("cancel"), group_id, payload);
 }
 
 /// Emit an event when a late contribution is made with penalty
@@ -77,8 +787,15 @@ pub fn emit_late_contribution(
     amount: i128,
     penalty: i128,
 ) {
-    let topics = (symbol_short!("late"), group_id, cycle);
-    env.events().publish(topics, (member, amount, penalty));
+    let payload = PenaltyEvent {
+        version: EVENT_SCHEMA_VERSION,
+        group_id,
+        cycle,
+        member: member.clone(),
+        amount,
+        penalty,
+    };
+    publish(env, symbol_short!("late"), group_id, payload);
 }
 
 /// Emit an event when penalties are distributed with payout
@@ -90,9 +807,58 @@ pub fn emit_penalty_distributed(
     base_amount: i128,
     penalty_bonus: i128,
 ) {
-    let topics = (symbol_short!("pendistr"), group_id, cycle);
-    env.events()
-        .publish(topics, (recipient, base_amount, penalty_bonus));
+    let payload = PenaltyEvent {
+        version: EVENT_SCHEMA_VERSION,
+        group_id,
+        cycle,
+        member: recipient.clone(),
+        amount: base_amount,
+        penalty: penalty_bonus,
+    };
+    publish(env, symbol_short!("pendistr"), group_id, payload);
+}
+
+/// Emit an event when the penalty policy is set for a group
+pub fn emit_penalty_policy_set(env: &Env, group_id: u64, policy: PenaltyPolicy) {
+    let payload = PenaltyPolicySetEvent { version: EVENT_SCHEMA_VERSION, group_id, policy };
+    publish(env, symbol_short!("penpolicy"), group_id, payload);
+}
+
+/// Emit an event when the penalty schedule is set for a group
+pub fn emit_penalty_schedule_set(env: &Env, group_id: u64, schedule: &PenaltySchedule) {
+    let payload = PenaltyScheduleSetEvent {
+        version: EVENT_SCHEMA_VERSION,
+        group_id,
+        schedule: schedule.clone(),
+    };
+    publish(env, symbol_short!("pensched"), group_id, payload);
+}
+
+/// Emit an event when a member receives a share of collected penalties
+pub fn emit_penalty_share(env: &Env, group_id: u64, member: &Address, cycle: u32, amount: i128) {
+    publish_member_amount(env, symbol_short!("penshare"), group_id, cycle, member, amount);
+}
+
+/// Emit an event when penalties are moved into a reserve, insurance or treasury pool
+pub fn emit_penalty_pooled(
+    env: &Env,
+    group_id: u64,
+    cycle: u32,
+    policy: PenaltyPolicy,
+    amount: i128,
+) {
+    let payload = PenaltyPooledEvent { version: EVENT_SCHEMA_VERSION, group_id, cycle, policy, amount };
+    publish(env, symbol_short!("penpooled"), group_id, payload);
+}
+
+/// Emit an event when the creator sets the group's metadata
+pub fn emit_metadata_set(env: &Env, group_id: u64, metadata: &GroupMetadata) {
+    let payload = MetadataSetEvent {
+        version: EVENT_SCHEMA_VERSION,
+        group_id,
+        metadata: metadata.clone(),
+    };
+    publish(env, symbol_short!("metadata"), group_id, payload);
 }
 
 /// Emit an event when a refund request is created
@@ -102,8 +868,13 @@ pub fn emit_refund_requested(
     requester: &Address,
     voting_deadline: u64,
 ) {
-    let topics = (symbol_short!("refreq"), group_id);
-    env.events().publish(topics, (requester, voting_deadline));
+    let payload = RefundRequestedEvent {
+        version: EVENT_SCHEMA_VERSION,
+        group_id,
+        requester: requester.clone(),
+        voting_deadline,
+    };
+    publish(env, symbol_short!("refreq"), group_id, payload);
 }
 
 /// Emit an event when a member votes on a refund request
@@ -113,8 +884,35 @@ pub fn emit_refund_vote(
     voter: &Address,
     in_favor: bool,
 ) {
-    let topics = (symbol_short!("refvote"), group_id);
-    env.events().publish(topics, (voter, in_favor));
+    let payload = RefundVoteEvent {
+        version: EVENT_SCHEMA_VERSION,
+        group_id,
+        voter: voter.clone(),
+        in_favor,
+    };
+    publish(env, symbol_short!("refvote"), group_id, payload);
+}
+
+/// Emit an event when a refund request fails its vote
+pub fn emit_refund_rejected(env: &Env, group_id: u64, votes_for: u32, votes_against: u32) {
+    let payload = RefundRejectedEvent {
+        version: EVENT_SCHEMA_VERSION,
+        group_id,
+        votes_for,
+        votes_against,
+    };
+    publish(env, symbol_short!("refreject"), group_id, payload);
+}
+
+/// Emit an event when a refund request passes its vote and the group is refunded
+pub fn emit_refund_approved(env: &Env, group_id: u64, votes_for: u32, votes_against: u32) {
+    let payload = RefundApprovedEvent {
+        version: EVENT_SCHEMA_VERSION,
+        group_id,
+        votes_for,
+        votes_against,
+    };
+    publish(env, symbol_short!("refaccept"), group_id, payload);
 }
 
 /// Emit an event when a refund is processed
@@ -123,10 +921,16 @@ pub fn emit_refund_processed(
     group_id: u64,
     member: &Address,
     amount: i128,
-    reason: u32,
+    reason: RefundReason,
 ) {
-    let topics = (symbol_short!("refund"), group_id);
-    env.events().publish(topics, (member, amount, reason));
+    let payload = RefundEvent {
+        version: EVENT_SCHEMA_VERSION,
+        group_id,
+        member: member.clone(),
+        amount,
+        reason,
+    };
+    publish(env, symbol_short!("refund"), group_id, payload);
 }
 
 /// Emit an event when an emergency refund is executed
@@ -136,6 +940,11 @@ pub fn emit_emergency_refund(
     admin: &Address,
     total_refunded: i128,
 ) {
-    let topics = (symbol_short!("emrefund"), group_id);
-    env.events().publish(topics, (admin, total_refunded));
+    let payload = EmergencyRefundEvent {
+        version: EVENT_SCHEMA_VERSION,
+        group_id,
+        admin: admin.clone(),
+        total_refunded,
+    };
+    publish(env, symbol_short!("emrefund"), group_id, payload);
 }
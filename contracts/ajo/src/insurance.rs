@@ -0,0 +1,163 @@
+use soroban_sdk::{Address, Env, Vec};
+
+use crate::events;
+use crate::storage;
+use crate::types::{ContributionRecord, Group, GroupKind, GroupState, InsuranceClaim};
+use crate::utils;
+
+/// Returns the insurance premium owed on a contribution payment.
+///
+/// # Arguments
+/// * `group` - The group
+/// * `amount` - The contribution payment
+///
+/// # Returns
+/// The premium in stroops; zero if the group isn't insured
+pub fn premium_for(group: &Group, amount: i128) -> i128 {
+    if group.token.is_none() {
+        return 0;
+    }
+    utils::apply_bps(amount, group.insurance_premium_bps)
+}
+
+/// Pays the premium on a contribution payment into the insurance fund.
+///
+/// Premiums are charged on top of the contribution and aren't refunded.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `group` - The group
+/// * `member` - The member being credited with the contribution
+/// * `cycle` - The cycle (or first prepaid cycle) the payment is for
+/// * `amount` - The contribution payment
+pub fn collect_premium(env: &Env, group: &Group, member: &Address, cycle: u32, amount: i128) {
+    let premium = premium_for(group, amount);
+    let Some(token) = &group.token else {
+        return;
+    };
+    if premium <= 0 {
+        return;
+    }
+
+    // Transfer premium to the fund
+    // Note: In production, this would use token.transfer() or native transfer
+    // For now, we record it (assuming payment succeeded)
+    storage::add_to_insurance_fund(env, token, premium);
+    events::emit_premium_paid(env, group.id, member, cycle, premium);
+}
+
+/// Plans the insurance claims covering defaults by members who have already been paid.
+///
+/// Once the grace period has expired, each member whose every seat has been paid
+/// out but who still owes the current cycle is due a claim for their outstanding
+/// balance from the insurance fund for the group's token, so the payout can go
+/// ahead. Defaults are only covered while the fund can pay them in full; members
+/// still waiting for their own payout are never covered, and target savings groups,
+/// where nobody is paid before maturity, aren't covered at all.
+///
+/// Nothing is written: the claims are only paid by [`pay_claims`] once the payout
+/// they make possible is certain to go ahead.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `group` - The group to cover
+///
+/// # Returns
+/// The claims to pay, in member order
+pub fn plan_claims(env: &Env, group: &Group) -> Vec<InsuranceClaim> {
+    let mut claims = Vec::new(env);
+    let Some(token) = group.token.clone() else {
+        return claims;
+    };
+    if group.insurance_premium_bps == 0
+        || group.kind == GroupKind::TargetSavings
+        || group.state == GroupState::Cancelled
+        || group.is_complete
+    {
+        return claims;
+    }
+
+    let current_time = utils::get_current_timestamp(env);
+    if current_time <= utils::get_grace_period_end(group) {
+        return claims;
+    }
+
+    let group_id = group.id;
+    let cycle = group.current_cycle;
+    let mut fund = storage::get_insurance_fund(env, &token);
+
+    for member in utils::contributors(env, group).iter() {
+        if storage::has_contributed(env, group_id, cycle, &member) {
+            continue;
+        }
+
+        // A member holding several seats isn't covered until the last one is paid
+        let seat_holder = storage::get_shared_seat(env, group_id, &member).unwrap_or(member.clone());
+        let seats = utils::member_seats(env, &group.members, &seat_holder);
+        if seats.iter().any(|seat| seat >= group.payout_index) {
+            continue;
+        }
+
+        let amount_paid = storage::get_contribution_detail(env, group_id, cycle, &member)
+            .map_or(0, |record| record.amount_paid);
+        let amount = utils::member_obligation(env, group, &member) - amount_paid;
+        if fund < amount {
+            continue;
+        }
+
+        fund -= amount;
+        claims.push_back(InsuranceClaim {
+            member,
+            group_id,
+            cycle,
+            amount,
+            token: token.clone(),
+            timestamp: current_time,
+        });
+    }
+
+    claims
+}
+
+/// Pays claims planned by [`plan_claims`] from the insurance fund.
+///
+/// Each covered contribution is recorded as late and paid by the contract, and the
+/// claim is stored.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `group` - The group being covered
+/// * `claims` - The claims to pay
+pub fn pay_claims(env: &Env, group: &Group, claims: &Vec<InsuranceClaim>) {
+    let contract = env.current_contract_address();
+
+    for claim in claims.iter() {
+        let (group_id, cycle, member) = (group.id, claim.cycle, &claim.member);
+        let mut record = storage::get_contribution_detail(env, group_id, cycle, member)
+            .unwrap_or(ContributionRecord {
+                member: member.clone(),
+                group_id,
+                cycle,
+                has_paid: false,
+                amount_paid: 0,
+                timestamp: 0,
+                is_late: false,
+                penalty_amount: 0,
+                is_prepaid: false,
+                payer: contract.clone(),
+                seats_paid: Vec::new(env),
+            });
+
+        storage::add_to_insurance_fund(env, &claim.token, -claim.amount);
+        record.amount_paid += claim.amount;
+        record.has_paid = true;
+        record.timestamp = claim.timestamp;
+        record.is_late = true;
+        storage::store_contribution_detail(env, group_id, cycle, member, &record);
+        storage::store_contribution(env, group_id, cycle, member, true);
+        utils::update_member_penalty_record(env, group_id, member, true, 0);
+
+        storage::store_insurance_claim(env, &claim);
+        events::emit_insurance_claim_paid(env, group_id, member, cycle, claim.amount);
+    }
+}
@@ -13,14 +13,37 @@
 //! - Native XLM support
 
 mod contract;
+mod contribution;
 mod errors;
 mod events;
+mod insurance;
 mod pausable;
+mod payout;
+mod renewal;
 mod storage;
 mod types;
 mod utils;
+mod vault;
 
 pub use contract::AjoContract;
 pub use contract::AjoContractClient;
 pub use errors::AjoError;
-pub use types::{GroupState, RefundReason, RefundRequest, RefundRecord, RefundVote};
+pub use types::{
+    AutoContributeMandate, ContributionRecord, CycleSchedule, Group, GroupKind, GroupMetadata,
+    GroupState, GroupStatus, GroupTemplate, GroupTerms, MemberPenaltyRecord, InsuranceClaim, LinearPenalty, OrganizerCommission, PayoutRecord, PayoutShare, PayoutSplit, PenaltyPolicy, PenaltySchedule,
+    PenaltyTier, RefundReason, RefundRequest, RefundRecord, RefundVote, SteppedPenalty, SwapProposal,
+    VaultPosition, YieldPolicy,
+};
+pub use types::{
+    AdminEvent, UpgradeEvent, GroupCreatedEvent, MemberEvent, MemberAmountEvent, InstallmentEvent,
+    SponsoredEvent, PenaltyEvent, TokenSetEvent, MandateEvent, PrepaidEvent, SplitsSetEvent,
+    PayoutShareEvent, FlagSetEvent, LimitSetEvent, InsuranceSetEvent, VaultSetEvent,
+    IntervalsSetEvent, SeatTierSetEvent, TargetSavingsSetEvent, SavingsCycleClosedEvent,
+    VaultAmountEvent, VaultWithdrawalEvent, SeatSharedEvent, MemberPairEvent, SwapEvent,
+    CommissionSetEvent, BountySetEvent, KeeperCrankedEvent, CrankSkippedEvent, GroupRenewedEvent,
+    TemplateSavedEvent, GroupSourceEvent, CycleEvent, CycleScheduleSetEvent, CatchUpEvent,
+    GroupCancelledEvent, PenaltyPolicySetEvent, PenaltyScheduleSetEvent, PenaltyPooledEvent,
+    RefundRequestedEvent, RefundVoteEvent, RefundEvent, RefundRejectedEvent, RefundApprovedEvent,
+    EmergencyRefundEvent, MetadataSetEvent, EVENT_SCHEMA_VERSION,
+};
+pub use vault::{Vault, VaultClient};
@@ -1,6 +1,7 @@
 use soroban_sdk::{symbol_short, Env};
 
 use crate::errors::AjoError;
+use crate::events;
 use crate::storage;
 
 /// Get the current pause state from instance storage.
@@ -101,6 +102,7 @@ pub fn pause(env: &Env) -> Result<(), AjoError> {
     
     // Set paused state
     set_paused(env, true);
+    events::emit_paused(env, &admin);
     
     Ok(())
 }
@@ -141,6 +143,7 @@ pub fn unpause(env: &Env) -> Result<(), AjoError> {
     
     // Clear paused state
     set_paused(env, false);
+    events::emit_unpaused(env, &admin);
     
     Ok(())
 }
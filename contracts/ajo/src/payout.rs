@@ -0,0 +1,326 @@
+use soroban_sdk::{Address, Env, Vec};
+
+use crate::contribution;
+use crate::errors::AjoError;
+use crate::events;
+use crate::insurance;
+use crate::renewal;
+use crate::storage;
+use crate::types::{Group, GroupKind, GroupState, PayoutRecord};
+use crate::utils;
+use crate::vault;
+
+/// Execute the payout for a group's current cycle.
+///
+/// Shared by [`execute_payout`](crate::contract::AjoContract::execute_payout) and the
+/// keeper entry points. The caller is responsible for the pause check and for
+/// authenticating the keeper.
+///
+/// Process:
+/// 1. Plans the insurance claims covering defaults by already-paid members (see
+///    [`insurance::plan_claims`])
+/// 2. Verifies the payout is due and funded, counting those members as paid (see
+///    [`utils::check_payout_ready`])
+/// 3. Withdraws the pot from the group's vault, applying the yield policy
+/// 4. Pays the planned claims from the insurance fund
+/// 5. Pays the keeper bounty, if a keeper is cranking, from the cycle's penalty
+///    pool first and then from the organizer commission
+/// 6. Distributes the remaining penalties according to the group's penalty policy
+/// 7. Pays the remaining commission to the creator and the net payout to each of the
+///    cycle's `recipients_per_cycle` recipients, split across any addresses they
+///    nominated, or across a shared seat's co-holders
+/// 8. Advances to the next cycle, counting any prepaid contributions for it, or marks
+///    the group complete, sharing any pot a short final cycle left unpaid among all
+///    members and renewing the group into its next round (see
+///    [`renewal::renew_group`])
+///
+/// A target savings group pays nobody; its cycle is closed instead (see
+/// [`close_savings_cycle`]).
+///
+/// Nothing is written until the payout is certain to go ahead, so a failed payout
+/// leaves no trace even when the caller, like
+/// [`crank_many`](crate::contract::AjoContract::crank_many), carries on.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `group_id` - The group to pay out
+/// * `keeper` - The keeper cranking the payout, if any
+///
+/// # Returns
+/// The bounty paid to the keeper (always 0 without a keeper)
+///
+/// # Errors
+/// * `GroupNotFound` - If the group does not exist
+/// * `GroupCancelled` - If the group has been cancelled
+/// * `GroupComplete` - If the group has already completed all payouts
+/// * `IncompleteContributions` - If not all members have contributed
+/// * `InsufficientBalance` - If the pot and surplus can't fund a tiered payout
+/// * `OutsideCycleWindow` - If grace period has not expired yet
+/// * `TransferFailed` - If the vault rejects the withdrawal
+/// * `NoMembers` - If the group has no members (should never happen)
+pub fn execute_payout(env: &Env, group_id: u64, keeper: Option<&Address>) -> Result<i128, AjoError> {
+    let mut group = storage::get_group(env, group_id).ok_or(AjoError::GroupNotFound)?;
+
+    // Let the insurance fund cover defaults by members who have already been paid
+    let claims = insurance::plan_claims(env, &group);
+
+    let current_time = utils::get_current_timestamp(env);
+    utils::check_payout_ready(env, &group, &claims, current_time)?;
+
+    // Bring the pot back from the vault, with any yield or loss
+    let vault_yield = vault::withdraw(env, &group)?;
+    insurance::pay_claims(env, &group, &claims);
+    if group.kind == GroupKind::TargetSavings {
+        return close_savings_cycle(env, &mut group, keeper, vault_yield, current_time);
+    }
+    let yield_to_payout = vault::distribute_yield(env, &group, vault_yield);
+
+    // This cycle's recipients: the next `recipients_per_cycle` seats, or fewer in
+    // a short final cycle
+    let recipients = utils::cycle_recipients(&group);
+    let count = recipients.len() as u32;
+    if count == 0 {
+        return Err(AjoError::NoMembers);
+    }
+
+    // Calculate base payouts, carrying any surplus to later cycles
+    let mut base_payouts = Vec::new(env);
+    let mut commissions = Vec::new(env);
+    for seat in recipients.clone() {
+        let base_payout = utils::calculate_payout_amount(&group, seat);
+        base_payouts.push_back(base_payout);
+        // Organizer commission comes out of each base payout
+        commissions.push_back(utils::calculate_commission(&group.commission, base_payout));
+    }
+    let total_base: i128 = base_payouts.iter().sum();
+    group.pot_surplus += utils::cycle_pot(&group) - total_base;
+
+    let mut organizer_commission: i128 = commissions.iter().sum();
+    let mut penalty_pool = storage::get_cycle_penalty_pool(env, group_id, group.current_cycle);
+
+    // Keeper bounty is funded from penalties first, then from the commission
+    let mut bounty = 0;
+    if let Some(keeper) = keeper {
+        let from_penalties = group.keeper_bounty.min(penalty_pool);
+        let from_commission = (group.keeper_bounty - from_penalties).min(organizer_commission);
+        penalty_pool -= from_penalties;
+        organizer_commission -= from_commission;
+        bounty = from_penalties + from_commission;
+
+        let overdue_by = current_time.saturating_sub(utils::get_grace_period_end(&group));
+        storage::add_keeper_earnings(env, keeper, bounty);
+        events::emit_keeper_cranked(env, group_id, keeper, group.current_cycle, bounty, overdue_by);
+    }
+
+    // Distribute this cycle's penalties according to the group's policy
+    let penalty_bonus =
+        utils::distribute_cycle_penalties(env, &group, group.current_cycle, penalty_pool);
+
+    if organizer_commission > 0 {
+        events::emit_commission_paid(
+            env,
+            group_id,
+            &group.creator,
+            group.current_cycle,
+            organizer_commission,
+        );
+    }
+
+    for (index, seat) in recipients.enumerate() {
+        let index = index as u32;
+        let payout_recipient = group.members.get(seat).ok_or(AjoError::NoMembers)?;
+        let base_payout = base_payouts.get_unchecked(index);
+        let commission = commissions.get_unchecked(index);
+
+        // Penalties and vault yield are shared evenly among the recipients
+        let recipient_bonus = utils::even_share(penalty_bonus, count, index);
+        let recipient_yield = utils::even_share(yield_to_payout, count, index);
+
+        // Total payout includes penalties and vault yield, net of commission
+        let total_payout = base_payout + recipient_bonus + recipient_yield - commission;
+
+        // Transfer payout to the recipient, or across the addresses they nominated
+        // Note: In production, this would use token.transfer() or native transfer
+        // For now, we just record it
+        let holders = utils::seat_holders(env, group_id, &payout_recipient);
+        let (shares, is_split) = if holders.len() > 1 {
+            (utils::split_shared_payout(env, &group, &holders, total_payout), true)
+        } else {
+            let splits = storage::get_payout_splits(env, group_id, &payout_recipient);
+            let shares = utils::split_payout(env, &payout_recipient, &splits, total_payout);
+            (shares, !splits.is_empty())
+        };
+
+        // Mark payout as received by everyone holding the seat
+        let record = PayoutRecord {
+            member: payout_recipient.clone(),
+            group_id,
+            cycle: group.current_cycle,
+            amount: total_payout,
+            commission,
+            timestamp: current_time,
+            shares: shares.clone(),
+        };
+        for holder in holders.iter() {
+            storage::mark_payout_received(env, group_id, &holder);
+            storage::store_payout_record(
+                env,
+                group_id,
+                &holder,
+                &PayoutRecord { member: holder.clone(), ..record.clone() },
+            );
+        }
+
+        if is_split {
+            for share in shares.iter() {
+                events::emit_payout_share(
+                    env,
+                    group_id,
+                    &payout_recipient,
+                    group.current_cycle,
+                    &share.recipient,
+                    share.amount,
+                );
+            }
+        }
+
+        // Emit payout event with penalty information
+        if recipient_bonus > 0 {
+            events::emit_penalty_distributed(
+                env,
+                group_id,
+                &payout_recipient,
+                group.current_cycle,
+                base_payout,
+                recipient_bonus,
+            );
+        }
+
+        events::emit_payout_executed(env, group_id, &payout_recipient, group.current_cycle, total_payout);
+    }
+
+    // Advance payout index past this cycle's recipients
+    group.payout_index += count;
+
+    // Check if all members have received payout
+    if group.payout_index >= group.members.len() {
+        // All members have received payout - mark complete
+        group.is_complete = true;
+        group.state = GroupState::Complete;
+        // A short final cycle leaves part of its pot unpaid; it's shared by everyone
+        if group.pot_surplus > 0 {
+            let reserve = storage::get_group_reserve(env, group_id);
+            storage::store_group_reserve(env, group_id, reserve + group.pot_surplus);
+            group.pot_surplus = 0;
+        }
+        utils::release_group_reserve(env, &group);
+        events::emit_group_completed(env, group_id, group.current_cycle, current_time);
+        group.next_group_id = renewal::renew_group(env, &group);
+    } else {
+        // Advance to next cycle
+        let catch_up = utils::start_next_cycle(&mut group, current_time);
+        events::emit_cycle_advanced(env, group_id, group.current_cycle, group.cycle_start_time);
+        if let Some(scheduled_start) = catch_up {
+            events::emit_cycle_catch_up(
+                env,
+                group_id,
+                group.current_cycle,
+                scheduled_start,
+                group.catch_up_end,
+            );
+        }
+        contribution::recognize_prepaid_contributions(env, &group);
+    }
+
+    // Update storage
+    storage::store_group(env, group_id, &group);
+
+    Ok(bounty)
+}
+
+/// Close the current cycle of a target savings group.
+///
+/// Nobody is paid. Savers who haven't contributed in full are recorded as late,
+/// with no penalty, in their [`MemberPenaltyRecord`](crate::types::MemberPenaltyRecord).
+/// The keeper bounty comes from the cycle's penalty pool, and the remaining penalties
+/// are distributed according to the group's penalty policy, with any share meant for
+/// a recipient held in the group reserve along with vault yield or losses. After the
+/// final cycle the group matures, the reserve is released to the remaining savers and
+/// the group is renewed into its next round.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `group` - The group, already checked to be due
+/// * `keeper` - The keeper cranking the cycle, if any
+/// * `vault_yield` - Yield withdrawn from the group's vault, negative for a loss
+/// * `current_time` - Current ledger timestamp
+///
+/// # Returns
+/// The bounty paid to the keeper (always 0 without a keeper)
+fn close_savings_cycle(
+    env: &Env,
+    group: &mut Group,
+    keeper: Option<&Address>,
+    vault_yield: i128,
+    current_time: u64,
+) -> Result<i128, AjoError> {
+    let group_id = group.id;
+    let cycle = group.current_cycle;
+
+    let mut missed = 0;
+    for saver in utils::active_savers(env, group).iter() {
+        if !storage::has_contributed(env, group_id, cycle, &saver) {
+            utils::update_member_penalty_record(env, group_id, &saver, true, 0);
+            missed += 1;
+        }
+    }
+
+    let mut penalty_pool = storage::get_cycle_penalty_pool(env, group_id, cycle);
+    let mut bounty = 0;
+    if let Some(keeper) = keeper {
+        bounty = group.keeper_bounty.min(penalty_pool);
+        penalty_pool -= bounty;
+
+        let overdue_by = current_time.saturating_sub(utils::get_grace_period_end(group));
+        storage::add_keeper_earnings(env, keeper, bounty);
+        events::emit_keeper_cranked(env, group_id, keeper, cycle, bounty, overdue_by);
+    }
+
+    // There's no recipient, so penalties meant for one stay in the group reserve
+    let unallocated = utils::distribute_cycle_penalties(env, group, cycle, penalty_pool);
+    if unallocated > 0 {
+        utils::add_to_group_reserve(env, group_id, cycle, unallocated);
+    }
+    if vault_yield != 0 {
+        let reserve = storage::get_group_reserve(env, group_id);
+        storage::store_group_reserve(env, group_id, reserve + vault_yield);
+    }
+
+    events::emit_savings_cycle_closed(env, group_id, cycle, missed);
+
+    if cycle >= group.savings_cycles {
+        // Matured - savings can now be withdrawn
+        group.is_complete = true;
+        group.state = GroupState::Complete;
+        utils::release_group_reserve(env, group);
+        events::emit_group_completed(env, group_id, group.current_cycle, current_time);
+        group.next_group_id = renewal::renew_group(env, group);
+    } else {
+        let catch_up = utils::start_next_cycle(group, current_time);
+        events::emit_cycle_advanced(env, group_id, group.current_cycle, group.cycle_start_time);
+        if let Some(scheduled_start) = catch_up {
+            events::emit_cycle_catch_up(
+                env,
+                group_id,
+                group.current_cycle,
+                scheduled_start,
+                group.catch_up_end,
+            );
+        }
+        contribution::recognize_prepaid_contributions(env, group);
+    }
+
+    storage::store_group(env, group_id, group);
+
+    Ok(bounty)
+}
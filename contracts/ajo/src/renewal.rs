@@ -0,0 +1,98 @@
+use soroban_sdk::{Address, Env, Vec};
+
+use crate::events;
+use crate::storage;
+use crate::types::{Group, MemberPenaltyRecord};
+use crate::utils;
+
+/// Renews a group that has just completed into its next round.
+///
+/// Does nothing unless at least one member opted in with
+/// [`opt_in_next_round`](crate::contract::AjoContract::opt_in_next_round). The
+/// successor keeps every setting of the completed group and starts its first cycle
+/// now with the opted-in members' seats, tiers included, in the same payout order
+/// or reshuffled if the group asked for it. An order whose tiered payouts can't be
+/// funded is rearranged from the smallest tier to the largest. A calendar schedule
+/// has already run out, so the successor uses a rolling one instead.
+///
+/// The creator stays in charge if they opted in; otherwise the first opted-in
+/// member takes over. Any metadata is copied, and each member's penalty record is
+/// carried over so their reliability builds across rounds. Co-holders of a shared
+/// seat, auto-debit mandates and payout splits aren't carried over.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `group` - The group that has just completed
+///
+/// # Returns
+/// The successor group's ID, or `None` if nobody opted in
+pub fn renew_group(env: &Env, group: &Group) -> Option<u64> {
+    let opt_ins = storage::get_renewal_opt_ins(env, group.id);
+    if opt_ins.is_empty() {
+        return None;
+    }
+
+    // Opted-in members keep their seats and tiers
+    let mut seats: Vec<(Address, u32)> = Vec::new(env);
+    for (seat, member) in group.members.iter().enumerate() {
+        if opt_ins.contains(&member) {
+            seats.push_back((member, utils::seat_tier(group, seat as u32)));
+        }
+    }
+    if group.shuffle_on_renewal {
+        env.prng().shuffle(&mut seats);
+    }
+    let mut members = Vec::new(env);
+    let mut seat_tiers = Vec::new(env);
+    for (member, tier) in seats.iter() {
+        members.push_back(member);
+        seat_tiers.push_back(tier);
+    }
+
+    let creator = if opt_ins.contains(&group.creator) {
+        group.creator.clone()
+    } else {
+        seats.get_unchecked(0).0
+    };
+
+    let group_id = storage::get_next_group_id(env);
+    let now = utils::get_current_timestamp(env);
+    let mut successor = utils::copy_group_terms(group, group_id, creator, members, seat_tiers, now);
+    successor.previous_group_id = Some(group.id);
+
+    // Smallest tiers first keeps every payout funded
+    if utils::validate_tier_funding(&successor).is_err() {
+        let mut members = Vec::new(env);
+        let mut seat_tiers = Vec::new(env);
+        for tier in 1..=successor.max_contribution_tier {
+            for (member, seat_tier) in seats.iter() {
+                if seat_tier == tier {
+                    members.push_back(member);
+                    seat_tiers.push_back(seat_tier);
+                }
+            }
+        }
+        successor.members = members;
+        successor.seat_tiers = seat_tiers;
+    }
+
+    storage::store_group(env, group_id, &successor);
+    if let Some(metadata) = storage::get_group_metadata(env, group.id) {
+        storage::store_group_metadata(env, group_id, &metadata);
+    }
+    for member in opt_ins.iter() {
+        if let Some(record) = storage::get_member_penalty(env, group.id, &member) {
+            storage::store_member_penalty(
+                env,
+                group_id,
+                &member,
+                &MemberPenaltyRecord { group_id, ..record },
+            );
+        }
+    }
+
+    events::emit_group_created(env, &successor);
+    events::emit_group_renewed(env, group.id, group_id, opt_ins.len());
+
+    Some(group_id)
+}
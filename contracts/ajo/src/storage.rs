@@ -44,6 +44,90 @@ pub enum StorageKey {
     /// Penalty pool for current cycle.
     /// Stored in persistent storage under `("PENPOOL", group_id, cycle)`.
     CyclePenaltyPool(u64, u32),
+
+    /// Payout record (net amount and commission) for a member.
+    /// Stored in persistent storage under `("PAYREC", group_id, member)`.
+    PayoutDetail(u64, Address),
+
+    /// Penalties held in reserve until the group completes.
+    /// Stored in persistent storage under `("RESERVE", group_id)`.
+    GroupReserve(u64),
+
+    /// Total penalty shares a member has received from a group.
+    /// Stored in persistent storage under `("PENSHARE", group_id, member)`.
+    PenaltyEarnings(u64, Address),
+
+    /// Protocol treasury balance funded by penalties.
+    /// Stored in instance storage under `"TREASURY"`.
+    Treasury,
+
+    /// Total bounties earned by a keeper across all groups.
+    /// Stored in persistent storage under `("KEEPER", keeper)`.
+    KeeperEarnings(Address),
+
+    /// Last cycle a member has prepaid in a group.
+    /// Stored in persistent storage under `("PREPAID", group_id, member)`.
+    PrepaidThrough(u64, Address),
+
+    /// Auto-debit mandate for a member of a group.
+    /// Stored in persistent storage under `("MANDATE", group_id, member)`.
+    AutoContributeMandate(u64, Address),
+
+    /// Addresses a member's payout is split across.
+    /// Stored in persistent storage under `("PAYSPLIT", group_id, member)`.
+    PayoutSplits(u64, Address),
+
+    /// Pending payout position swap proposed by a member.
+    /// Stored in persistent storage under `("SWAP", group_id, from)`.
+    SwapProposal(u64, Address),
+
+    /// Co-holders of a shared seat, keyed by the holder listed in the payout order.
+    /// Stored in persistent storage under `("HOLDERS", group_id, primary)`.
+    SeatHolders(u64, Address),
+
+    /// The shared seat an address co-holds, as the holder listed in the payout order.
+    /// Stored in persistent storage under `("SHSEAT", group_id, holder)`.
+    SharedSeat(u64, Address),
+
+    /// Default insurance fund balance for a token, funded by premiums.
+    /// Stored in persistent storage under `("INSFUND", token)`.
+    InsuranceFund(Address),
+
+    /// Default covered by the insurance fund.
+    /// Stored in persistent storage under `("INSCLAIM", group_id, cycle, member)`.
+    InsuranceClaim(u64, u32, Address),
+
+    /// A group's holding in its vault.
+    /// Stored in persistent storage under `("VAULTPOS", group_id)`.
+    VaultPosition(u64),
+
+    /// Total vault shares held by the contract across all groups using a vault.
+    /// Stored in persistent storage under `("VAULTSHR", vault)`.
+    VaultShares(Address),
+
+    /// Savings a member has built up in a target savings group.
+    /// Stored in persistent storage under `("SAVINGS", group_id, member)`.
+    SavingsBalance(u64, Address),
+
+    /// Members who want to continue into the group's next round, in opt-in order.
+    /// Stored in persistent storage under `("RENEWAL", group_id)`.
+    RenewalOptIns(u64),
+
+    /// Monotonically increasing counter used to assign unique template IDs.
+    /// Stored in instance storage under `"TCOUNTER"`.
+    TemplateCounter,
+
+    /// A saved group template keyed by its numeric ID.
+    /// Stored in persistent storage under `("TEMPLATE", template_id)`.
+    Template(u64),
+
+    /// IDs of the templates an address has saved, oldest first.
+    /// Stored in persistent storage under `("TEMPLATES", creator)`.
+    CreatorTemplates(Address),
+
+    /// Optional metadata for a group template.
+    /// Stored in persistent storage under `("TMPLMETA", template_id)`.
+    TemplateMetadata(u64),
 }
 
 impl StorageKey {
@@ -69,6 +153,27 @@ impl StorageKey {
             StorageKey::ContributionDetail(_, _, _) => symbol_short!("CONTREC"),
             StorageKey::MemberPenalty(_, _) => symbol_short!("PENALTY"),
             StorageKey::CyclePenaltyPool(_, _) => symbol_short!("PENPOOL"),
+            StorageKey::PayoutDetail(_, _) => symbol_short!("PAYREC"),
+            StorageKey::GroupReserve(_) => symbol_short!("RESERVE"),
+            StorageKey::PenaltyEarnings(_, _) => symbol_short!("PENSHARE"),
+            StorageKey::Treasury => symbol_short!("TREASURY"),
+            StorageKey::KeeperEarnings(_) => symbol_short!("KEEPER"),
+            StorageKey::PrepaidThrough(_, _) => symbol_short!("PREPAID"),
+            StorageKey::AutoContributeMandate(_, _) => symbol_short!("MANDATE"),
+            StorageKey::PayoutSplits(_, _) => symbol_short!("PAYSPLIT"),
+            StorageKey::SwapProposal(_, _) => symbol_short!("SWAP"),
+            StorageKey::SeatHolders(_, _) => symbol_short!("HOLDERS"),
+            StorageKey::SharedSeat(_, _) => symbol_short!("SHSEAT"),
+            StorageKey::InsuranceFund(_) => symbol_short!("INSFUND"),
+            StorageKey::InsuranceClaim(_, _, _) => symbol_short!("INSCLAIM"),
+            StorageKey::VaultPosition(_) => symbol_short!("VAULTPOS"),
+            StorageKey::VaultShares(_) => symbol_short!("VAULTSHR"),
+            StorageKey::SavingsBalance(_, _) => symbol_short!("SAVINGS"),
+            StorageKey::RenewalOptIns(_) => symbol_short!("RENEWAL"),
+            StorageKey::TemplateCounter => symbol_short!("TCOUNTER"),
+            StorageKey::Template(_) => symbol_short!("TEMPLATE"),
+            StorageKey::CreatorTemplates(_) => symbol_short!("TEMPLATES"),
+            StorageKey::TemplateMetadata(_) => symbol_short!("TMPLMETA"),
         }
     }
 }
@@ -157,6 +262,7 @@ pub fn store_contribution(env: &Env, group_id: u64, cycle: u32, member: &Address
 /// Returns `true` if the given member has contributed during the specified cycle.
 ///
 /// Defaults to `false` if no record exists, meaning the member has not yet contributed.
+/// Cycles paid in advance with `prepay` are flagged when prepaid, so they count here too.
 ///
 /// # Arguments
 /// * `env` - The contract environment used to access persistent storage
@@ -171,6 +277,28 @@ pub fn has_contributed(env: &Env, group_id: u64, cycle: u32, member: &Address) -
     env.storage().persistent().get(&key).unwrap_or(false)
 }
 
+/// Returns `true` once a seat's contribution for the cycle is complete.
+///
+/// A shared seat is complete only when every co-holder has paid their share;
+/// any other seat is complete when its holder has contributed.
+///
+/// # Arguments
+/// * `env` - The contract environment used to access persistent storage
+/// * `group_id` - The group to check
+/// * `cycle` - The cycle number to check
+/// * `member` - The seat's holder, as listed in the group's member list
+///
+/// # Returns
+/// `true` if the seat has been paid for, `false` otherwise
+pub fn has_seat_contributed(env: &Env, group_id: u64, cycle: u32, member: &Address) -> bool {
+    match get_seat_holders(env, group_id, member) {
+        Some(holders) => holders
+            .iter()
+            .all(|holder| has_contributed(env, group_id, cycle, &holder)),
+        None => has_contributed(env, group_id, cycle, member),
+    }
+}
+
 /// Records that the given member has received their payout for a group.
 ///
 /// This flag is set after `execute_payout` successfully distributes funds.
@@ -201,11 +329,47 @@ pub fn has_received_payout(env: &Env, group_id: u64, member: &Address) -> bool {
     env.storage().persistent().get(&key).unwrap_or(false)
 }
 
+/// Stores the detailed payout record for a member.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `group_id` - The group the payout belongs to
+/// * `member` - The address that received the payout
+/// * `record` - The payout record with net amount and commission
+pub fn store_payout_record(
+    env: &Env,
+    group_id: u64,
+    member: &Address,
+    record: &crate::types::PayoutRecord,
+) {
+    let key = (symbol_short!("PAYREC"), group_id, member);
+    env.storage().persistent().set(&key, record);
+}
+
+/// Retrieves the detailed payout record for a member.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `group_id` - The group to check
+/// * `member` - The member address
+///
+/// # Returns
+/// `Some(PayoutRecord)` if the member has been paid, `None` otherwise
+pub fn get_payout_record(
+    env: &Env,
+    group_id: u64,
+    member: &Address,
+) -> Option<crate::types::PayoutRecord> {
+    let key = (symbol_short!("PAYREC"), group_id, member);
+    env.storage().persistent().get(&key)
+}
+
 /// Returns contribution status for every member in a cycle as an ordered vector.
 ///
 /// Iterates through `members` in order and looks up each one's contribution
 /// flag for the given cycle. The returned vector preserves member order and
 /// pairs each address with a `bool` indicating whether they have contributed.
+/// A shared seat only counts as contributed once every co-holder has paid.
 ///
 /// # Arguments
 /// * `env` - The contract environment used to access persistent storage
@@ -223,7 +387,7 @@ pub fn get_cycle_contributions(
 ) -> Vec<(Address, bool)> {
     let mut results = Vec::new(env);
     for member in members.iter() {
-        let paid = has_contributed(env, group_id, cycle, &member);
+        let paid = has_seat_contributed(env, group_id, cycle, &member);
         results.push_back((member, paid));
     }
     results
@@ -407,6 +571,622 @@ pub fn add_to_penalty_pool(env: &Env, group_id: u64, cycle: u32, penalty: i128)
     store_cycle_penalty_pool(env, group_id, cycle, current + penalty);
 }
 
+/// Retrieves the penalty reserve held for a group.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `group_id` - The group
+///
+/// # Returns
+/// Reserve balance in stroops, defaults to 0 if not set
+pub fn get_group_reserve(env: &Env, group_id: u64) -> i128 {
+    let key = (symbol_short!("RESERVE"), group_id);
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
+/// Stores the penalty reserve held for a group.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `group_id` - The group
+/// * `amount` - New reserve balance
+pub fn store_group_reserve(env: &Env, group_id: u64, amount: i128) {
+    let key = (symbol_short!("RESERVE"), group_id);
+    env.storage().persistent().set(&key, &amount);
+}
+
+/// Retrieves the total penalty shares a member has received from a group.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `group_id` - The group
+/// * `member` - The member's address
+///
+/// # Returns
+/// Total penalty shares in stroops, defaults to 0 if not set
+pub fn get_penalty_earnings(env: &Env, group_id: u64, member: &Address) -> i128 {
+    let key = (symbol_short!("PENSHARE"), group_id, member);
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
+/// Adds a penalty share to a member's running total for a group.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `group_id` - The group
+/// * `member` - The member receiving the share
+/// * `amount` - Share amount to add
+pub fn add_penalty_earnings(env: &Env, group_id: u64, member: &Address, amount: i128) {
+    let key = (symbol_short!("PENSHARE"), group_id, member);
+    let current = get_penalty_earnings(env, group_id, member);
+    env.storage().persistent().set(&key, &(current + amount));
+}
+
+/// Retrieves the default insurance fund balance for a token.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `token` - The token the fund holds
+///
+/// # Returns
+/// Fund balance in stroops, defaults to 0 if not set
+pub fn get_insurance_fund(env: &Env, token: &Address) -> i128 {
+    let key = (symbol_short!("INSFUND"), token);
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
+/// Adds an amount to (or, when negative, takes it from) a token's insurance fund.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `token` - The token the fund holds
+/// * `amount` - Amount to add
+pub fn add_to_insurance_fund(env: &Env, token: &Address, amount: i128) {
+    let key = (symbol_short!("INSFUND"), token);
+    let current = get_insurance_fund(env, token);
+    env.storage().persistent().set(&key, &(current + amount));
+}
+
+/// Stores a claim paid by the insurance fund.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `claim` - The claim
+pub fn store_insurance_claim(env: &Env, claim: &crate::types::InsuranceClaim) {
+    let key = (symbol_short!("INSCLAIM"), claim.group_id, claim.cycle, &claim.member);
+    env.storage().persistent().set(&key, claim);
+}
+
+/// Retrieves a claim paid by the insurance fund.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `group_id` - The group
+/// * `cycle` - The cycle the member defaulted on
+/// * `member` - The member whose contribution was covered
+///
+/// # Returns
+/// `Some(InsuranceClaim)` if the default was covered, `None` otherwise
+pub fn get_insurance_claim(
+    env: &Env,
+    group_id: u64,
+    cycle: u32,
+    member: &Address,
+) -> Option<crate::types::InsuranceClaim> {
+    let key = (symbol_short!("INSCLAIM"), group_id, cycle, member);
+    env.storage().persistent().get(&key)
+}
+
+/// Stores a group's holding in its vault.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `group_id` - The group
+/// * `position` - The vault position
+pub fn store_vault_position(env: &Env, group_id: u64, position: &crate::types::VaultPosition) {
+    let key = (symbol_short!("VAULTPOS"), group_id);
+    env.storage().persistent().set(&key, position);
+}
+
+/// Retrieves a group's holding in its vault.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `group_id` - The group
+///
+/// # Returns
+/// `Some(VaultPosition)` if the group has funds deposited, `None` otherwise
+pub fn get_vault_position(env: &Env, group_id: u64) -> Option<crate::types::VaultPosition> {
+    let key = (symbol_short!("VAULTPOS"), group_id);
+    env.storage().persistent().get(&key)
+}
+
+/// Removes a group's vault position once everything has been withdrawn.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `group_id` - The group
+pub fn remove_vault_position(env: &Env, group_id: u64) {
+    let key = (symbol_short!("VAULTPOS"), group_id);
+    env.storage().persistent().remove(&key);
+}
+
+/// Retrieves the total shares the contract holds in a vault.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `vault` - The vault contract
+///
+/// # Returns
+/// Total shares across all groups, defaults to 0
+pub fn get_vault_shares(env: &Env, vault: &Address) -> i128 {
+    let key = (symbol_short!("VAULTSHR"), vault);
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
+/// Stores the total shares the contract holds in a vault.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `vault` - The vault contract
+/// * `shares` - Total shares across all groups
+pub fn store_vault_shares(env: &Env, vault: &Address, shares: i128) {
+    let key = (symbol_short!("VAULTSHR"), vault);
+    env.storage().persistent().set(&key, &shares);
+}
+
+/// Retrieves a member's savings in a target savings group.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `group_id` - The group
+/// * `member` - The member's address
+///
+/// # Returns
+/// Contributions saved in stroops, defaults to 0 if not set
+pub fn get_savings_balance(env: &Env, group_id: u64, member: &Address) -> i128 {
+    let key = (symbol_short!("SAVINGS"), group_id, member);
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
+/// Adds a contribution payment to a member's savings in a target savings group.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `group_id` - The group
+/// * `member` - The member being credited
+/// * `amount` - Amount to add
+pub fn add_savings_balance(env: &Env, group_id: u64, member: &Address, amount: i128) {
+    let key = (symbol_short!("SAVINGS"), group_id, member);
+    let current = get_savings_balance(env, group_id, member);
+    env.storage().persistent().set(&key, &(current + amount));
+}
+
+/// Retrieves the members who opted into a group's next round.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `group_id` - The group
+///
+/// # Returns
+/// Opted-in members in opt-in order, empty if nobody has opted in
+pub fn get_renewal_opt_ins(env: &Env, group_id: u64) -> Vec<Address> {
+    let key = (symbol_short!("RENEWAL"), group_id);
+    env.storage().persistent().get(&key).unwrap_or(Vec::new(env))
+}
+
+/// Stores the members who opted into a group's next round.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `group_id` - The group
+/// * `members` - Opted-in members in opt-in order
+pub fn store_renewal_opt_ins(env: &Env, group_id: u64, members: &Vec<Address>) {
+    let key = (symbol_short!("RENEWAL"), group_id);
+    env.storage().persistent().set(&key, members);
+}
+
+/// Stores a new group template and adds it to its creator's list.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `template` - The template to save
+///
+/// # Returns
+/// The new template's ID (starting from 1)
+pub fn store_template(env: &Env, template: &crate::types::GroupTemplate) -> u64 {
+    let counter_key = symbol_short!("TCOUNTER");
+    let template_id: u64 = env.storage().instance().get(&counter_key).unwrap_or(0) + 1;
+    env.storage().instance().set(&counter_key, &template_id);
+
+    env.storage()
+        .persistent()
+        .set(&(symbol_short!("TEMPLATE"), template_id), template);
+
+    let mut template_ids = get_creator_templates(env, &template.creator);
+    template_ids.push_back(template_id);
+    env.storage()
+        .persistent()
+        .set(&(symbol_short!("TEMPLATES"), &template.creator), &template_ids);
+
+    template_id
+}
+
+/// Retrieves a group template.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `template_id` - The template
+///
+/// # Returns
+/// `Some(GroupTemplate)` if it exists, `None` otherwise
+pub fn get_template(env: &Env, template_id: u64) -> Option<crate::types::GroupTemplate> {
+    let key = (symbol_short!("TEMPLATE"), template_id);
+    env.storage().persistent().get(&key)
+}
+
+/// Stores metadata for a group template.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `template_id` - The template
+/// * `metadata` - The metadata given to groups created from the template
+pub fn store_template_metadata(env: &Env, template_id: u64, metadata: &crate::types::GroupMetadata) {
+    let key = (symbol_short!("TMPLMETA"), template_id);
+    env.storage().persistent().set(&key, metadata);
+}
+
+/// Retrieves metadata for a group template.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `template_id` - The template
+///
+/// # Returns
+/// `Some(GroupMetadata)` if it exists, `None` otherwise
+pub fn get_template_metadata(env: &Env, template_id: u64) -> Option<crate::types::GroupMetadata> {
+    let key = (symbol_short!("TMPLMETA"), template_id);
+    env.storage().persistent().get(&key)
+}
+
+/// Retrieves the IDs of the templates an address has saved.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `creator` - The address that saved the templates
+///
+/// # Returns
+/// Template IDs, oldest first; empty if none have been saved
+pub fn get_creator_templates(env: &Env, creator: &Address) -> Vec<u64> {
+    let key = (symbol_short!("TEMPLATES"), creator);
+    env.storage().persistent().get(&key).unwrap_or(Vec::new(env))
+}
+
+/// Retrieves the protocol treasury balance.
+///
+/// # Arguments
+/// * `env` - The contract environment
+///
+/// # Returns
+/// Treasury balance in stroops, defaults to 0 if not set
+pub fn get_treasury(env: &Env) -> i128 {
+    let key = symbol_short!("TREASURY");
+    env.storage().instance().get(&key).unwrap_or(0)
+}
+
+/// Adds an amount to the protocol treasury.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `amount` - Amount to add
+pub fn add_to_treasury(env: &Env, amount: i128) {
+    let key = symbol_short!("TREASURY");
+    let current = get_treasury(env);
+    env.storage().instance().set(&key, &(current + amount));
+}
+
+/// Retrieves the total bounties a keeper has earned.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `keeper` - The keeper's address
+///
+/// # Returns
+/// Total bounties in stroops, defaults to 0 if not set
+pub fn get_keeper_earnings(env: &Env, keeper: &Address) -> i128 {
+    let key = (symbol_short!("KEEPER"), keeper);
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
+/// Adds a bounty to a keeper's running total.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `keeper` - The keeper's address
+/// * `amount` - Bounty amount to add
+pub fn add_keeper_earnings(env: &Env, keeper: &Address, amount: i128) {
+    let key = (symbol_short!("KEEPER"), keeper);
+    let current = get_keeper_earnings(env, keeper);
+    env.storage().persistent().set(&key, &(current + amount));
+}
+
+/// Retrieves the last cycle a member has prepaid.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `group_id` - The group
+/// * `member` - The member's address
+///
+/// # Returns
+/// The last prepaid cycle, defaults to 0 if the member never prepaid
+pub fn get_prepaid_through(env: &Env, group_id: u64, member: &Address) -> u32 {
+    let key = (symbol_short!("PREPAID"), group_id, member);
+    env.storage().persistent().get(&key).unwrap_or(0)
+}
+
+/// Stores the last cycle a member has prepaid.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `group_id` - The group
+/// * `member` - The member's address
+/// * `cycle` - The last prepaid cycle
+pub fn store_prepaid_through(env: &Env, group_id: u64, member: &Address, cycle: u32) {
+    let key = (symbol_short!("PREPAID"), group_id, member);
+    env.storage().persistent().set(&key, &cycle);
+}
+
+/// Stores a member's auto-debit mandate.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `group_id` - The group
+/// * `mandate` - The mandate to store
+pub fn store_mandate(env: &Env, group_id: u64, mandate: &crate::types::AutoContributeMandate) {
+    let key = (symbol_short!("MANDATE"), group_id, &mandate.member);
+    env.storage().persistent().set(&key, mandate);
+}
+
+/// Retrieves a member's auto-debit mandate.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `group_id` - The group
+/// * `member` - The member's address
+///
+/// # Returns
+/// `Some(AutoContributeMandate)` if the member has one, `None` otherwise
+pub fn get_mandate(
+    env: &Env,
+    group_id: u64,
+    member: &Address,
+) -> Option<crate::types::AutoContributeMandate> {
+    let key = (symbol_short!("MANDATE"), group_id, member);
+    env.storage().persistent().get(&key)
+}
+
+/// Removes a member's auto-debit mandate.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `group_id` - The group
+/// * `member` - The member's address
+pub fn remove_mandate(env: &Env, group_id: u64, member: &Address) {
+    let key = (symbol_short!("MANDATE"), group_id, member);
+    env.storage().persistent().remove(&key);
+}
+
+/// Stores the addresses a member's payout is split across.
+///
+/// An empty list removes the splits, so the payout goes to the member.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `group_id` - The group
+/// * `member` - The member's address
+/// * `splits` - The payout splits
+pub fn store_payout_splits(
+    env: &Env,
+    group_id: u64,
+    member: &Address,
+    splits: &Vec<crate::types::PayoutSplit>,
+) {
+    let key = (symbol_short!("PAYSPLIT"), group_id, member);
+    if splits.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, splits);
+    }
+}
+
+/// Retrieves the addresses a member's payout is split across.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `group_id` - The group
+/// * `member` - The member's address
+///
+/// # Returns
+/// The member's payout splits, empty if the payout goes to the member
+pub fn get_payout_splits(
+    env: &Env,
+    group_id: u64,
+    member: &Address,
+) -> Vec<crate::types::PayoutSplit> {
+    let key = (symbol_short!("PAYSPLIT"), group_id, member);
+    env.storage().persistent().get(&key).unwrap_or(Vec::new(env))
+}
+
+/// Stores a member's pending swap proposal, replacing any earlier one.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `group_id` - The group
+/// * `proposal` - The swap proposal
+pub fn store_swap_proposal(env: &Env, group_id: u64, proposal: &crate::types::SwapProposal) {
+    let key = (symbol_short!("SWAP"), group_id, &proposal.from);
+    env.storage().persistent().set(&key, proposal);
+}
+
+/// Retrieves a member's pending swap proposal.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `group_id` - The group
+/// * `from` - The member who proposed the swap
+///
+/// # Returns
+/// `Some(SwapProposal)` if one is pending, `None` otherwise
+pub fn get_swap_proposal(
+    env: &Env,
+    group_id: u64,
+    from: &Address,
+) -> Option<crate::types::SwapProposal> {
+    let key = (symbol_short!("SWAP"), group_id, from);
+    env.storage().persistent().get(&key)
+}
+
+/// Removes a member's pending swap proposal.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `group_id` - The group
+/// * `from` - The member who proposed the swap
+pub fn remove_swap_proposal(env: &Env, group_id: u64, from: &Address) {
+    let key = (symbol_short!("SWAP"), group_id, from);
+    env.storage().persistent().remove(&key);
+}
+
+/// Stores the co-holders of a shared seat.
+///
+/// The first holder is the one listed in the group's member list; every holder is
+/// also mapped back to them so co-holders can be recognized as members.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `group_id` - The group
+/// * `holders` - The seat's co-holders, first holder first
+pub fn store_seat_holders(env: &Env, group_id: u64, holders: &Vec<Address>) {
+    let primary = holders.get_unchecked(0);
+    let key = (symbol_short!("HOLDERS"), group_id, &primary);
+    env.storage().persistent().set(&key, holders);
+    for holder in holders.iter() {
+        let key = (symbol_short!("SHSEAT"), group_id, holder);
+        env.storage().persistent().set(&key, &primary);
+    }
+}
+
+/// Retrieves the co-holders of a shared seat.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `group_id` - The group
+/// * `member` - The seat's holder, as listed in the group's member list
+///
+/// # Returns
+/// `Some(holders)` if the seat is shared, `None` otherwise
+pub fn get_seat_holders(env: &Env, group_id: u64, member: &Address) -> Option<Vec<Address>> {
+    let key = (symbol_short!("HOLDERS"), group_id, member);
+    env.storage().persistent().get(&key)
+}
+
+/// Retrieves the shared seat an address co-holds.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `group_id` - The group
+/// * `holder` - Any co-holder of the seat
+///
+/// # Returns
+/// `Some(primary)`, the holder listed in the member list, if the address co-holds
+/// a shared seat, `None` otherwise
+pub fn get_shared_seat(env: &Env, group_id: u64, holder: &Address) -> Option<Address> {
+    let key = (symbol_short!("SHSEAT"), group_id, holder);
+    env.storage().persistent().get(&key)
+}
+
+/// Moves everything recorded against a member's seat to a new address.
+///
+/// Moves the contribution flags and records for cycles `1..=last_cycle`, the penalty
+/// record, the payout-received flag, the payout record, the prepaid-through marker and
+/// any target savings balance,
+/// updating the `member` field of each record. Contributions the old address paid for
+/// itself now count as paid by the new address, which takes over the seat's refund
+/// and payout entitlement; sponsored contributions stay with the sponsor. Auto-debit mandates, payout splits,
+/// swap proposals and next-round opt-ins are authorizations by the old address, so
+/// they are removed rather than moved. Penalty shares already earned stay with the old address.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `group_id` - The group
+/// * `last_cycle` - The last cycle that may have records (current or last prepaid)
+/// * `from` - The address leaving the seat
+/// * `to` - The address taking over the seat
+pub fn transfer_member_records(
+    env: &Env,
+    group_id: u64,
+    last_cycle: u32,
+    from: &Address,
+    to: &Address,
+) {
+    let persistent = env.storage().persistent();
+
+    for cycle in 1..=last_cycle {
+        let flag_key = (symbol_short!("CONTRIB"), group_id, cycle, from);
+        if let Some(paid) = persistent.get::<_, bool>(&flag_key) {
+            persistent.remove(&flag_key);
+            store_contribution(env, group_id, cycle, to, paid);
+        }
+        if let Some(mut record) = get_contribution_detail(env, group_id, cycle, from) {
+            persistent.remove(&(symbol_short!("CONTREC"), group_id, cycle, from));
+            record.member = to.clone();
+            if record.payer == *from {
+                record.payer = to.clone();
+            }
+            store_contribution_detail(env, group_id, cycle, to, &record);
+        }
+    }
+
+    if let Some(mut record) = get_member_penalty(env, group_id, from) {
+        persistent.remove(&(symbol_short!("PENALTY"), group_id, from));
+        record.member = to.clone();
+        store_member_penalty(env, group_id, to, &record);
+    }
+
+    if has_received_payout(env, group_id, from) {
+        persistent.remove(&(symbol_short!("PAYOUT"), group_id, from));
+        mark_payout_received(env, group_id, to);
+    }
+
+    if let Some(mut record) = get_payout_record(env, group_id, from) {
+        persistent.remove(&(symbol_short!("PAYREC"), group_id, from));
+        record.member = to.clone();
+        store_payout_record(env, group_id, to, &record);
+    }
+
+    let prepaid_through = get_prepaid_through(env, group_id, from);
+    if prepaid_through > 0 {
+        persistent.remove(&(symbol_short!("PREPAID"), group_id, from));
+        store_prepaid_through(env, group_id, to, prepaid_through);
+    }
+
+    let savings = get_savings_balance(env, group_id, from);
+    if savings != 0 {
+        persistent.remove(&(symbol_short!("SAVINGS"), group_id, from));
+        add_savings_balance(env, group_id, to, savings);
+    }
+
+    let mut opt_ins = get_renewal_opt_ins(env, group_id);
+    if let Some(index) = opt_ins.first_index_of(from) {
+        opt_ins.remove(index);
+        store_renewal_opt_ins(env, group_id, &opt_ins);
+    }
+
+    remove_mandate(env, group_id, from);
+    persistent.remove(&(symbol_short!("PAYSPLIT"), group_id, from));
+    remove_swap_proposal(env, group_id, from);
+}
+
 /// Stores a refund request for a group.
 ///
 /// # Arguments
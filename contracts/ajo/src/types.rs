@@ -1,4 +1,4 @@
-use soroban_sdk::{contracttype, Address, Vec};
+use soroban_sdk::{contracttype, Address, BytesN, Vec};
 
 /// State of a group in its lifecycle.
 #[contracttype]
@@ -28,27 +28,35 @@ pub struct Group {
     /// Automatically added as the first member on creation.
     pub creator: Address,
 
-    /// Fixed contribution amount each member must pay per cycle, denominated in stroops.
+    /// Fixed contribution amount each member must pay per contribution period
+    /// (once per cycle unless `contribution_interval` is set), denominated in stroops.
     /// 1 XLM = 10,000,000 stroops.
     pub contribution_amount: i128,
 
-    /// Duration of each cycle in seconds.
+    /// Duration of each cycle in seconds, i.e. the payout interval.
     /// When a cycle ends, the next payout can be triggered.
     pub cycle_duration: u64,
 
+    /// Length of each contribution period in seconds, dividing `cycle_duration`
+    /// evenly. Each period has its own deadline and grace window. Zero when
+    /// contributions are collected once per cycle.
+    pub contribution_interval: u64,
+
     /// Maximum number of members allowed in the group.
     /// Must be between 2 and 100 (inclusive).
     pub max_members: u32,
 
-    /// Ordered list of member addresses.
-    /// Members receive payouts in the order they appear in this list.
+    /// Ordered list of member addresses, one entry per seat.
+    /// Members receive payouts in the order they appear in this list, so a member
+    /// holding several seats appears (and is paid) once per seat.
     pub members: Vec<Address>,
 
     /// Current cycle number, starts at 1 and increments after each payout.
     pub current_cycle: u32,
 
-    /// Zero-based index into `members` indicating who receives the next payout.
-    /// When `payout_index == members.len()`, the group is complete.
+    /// Zero-based index into `members` of the first seat paid by the next payout.
+    /// Each payout pays `recipients_per_cycle` seats from here. When
+    /// `payout_index >= members.len()`, the group is complete.
     pub payout_index: u32,
 
     /// Unix timestamp (seconds) when the group was created.
@@ -74,8 +82,312 @@ pub struct Group {
 
     /// Current state of the group (Active, Cancelled, or Complete).
     pub state: GroupState,
+
+    /// Commission paid to the creator for organizing the group.
+    /// Deducted from each payout; locked once the first member joins.
+    pub commission: OrganizerCommission,
+
+    /// Where late penalties collected in each cycle are sent.
+    /// Locked once the first member joins.
+    pub penalty_policy: PenaltyPolicy,
+
+    /// How the late penalty scales with lateness.
+    /// `Flat` applies `penalty_rate` regardless of how late the contribution is.
+    pub penalty_schedule: PenaltySchedule,
+
+    /// How the start of each cycle is determined.
+    pub cycle_schedule: CycleSchedule,
+
+    /// When non-zero, the current cycle started after its scheduled end and
+    /// contributions are due by this timestamp instead.
+    pub catch_up_end: u64,
+
+    /// Maximum bounty (in stroops) paid to a keeper who cranks a due payout.
+    /// Funded from the cycle's penalty pool, then from the organizer commission.
+    pub keeper_bounty: i128,
+
+    /// Token contributions are pulled in by auto-debit mandates.
+    /// `None` until the creator sets it; auto-debit is unavailable without it.
+    pub token: Option<Address>,
+
+    /// Whether seat transfers also need the creator's authorization.
+    pub seat_transfer_needs_approval: bool,
+
+    /// How many seats one address may hold. Each seat has its own payout turn
+    /// and owes `contribution_amount` times its tier every cycle. Defaults to 1.
+    pub max_seats_per_member: u32,
+
+    /// Highest contribution tier a seat may choose. Defaults to 1, so every seat
+    /// pays `contribution_amount`.
+    pub max_contribution_tier: u32,
+
+    /// Contribution tier of each seat, in the same order as `members`. A tier-n
+    /// seat owes n × `contribution_amount` each cycle and is paid n shares.
+    pub seat_tiers: Vec<u32>,
+
+    /// Pot left over from earlier cycles when a tiered payout was smaller than
+    /// that cycle's contributions, held to fund later larger payouts.
+    pub pot_surplus: i128,
+
+    /// Number of seats paid by each payout, each receiving an equal share of the
+    /// pot. The rotation completes in `members.len() / recipients_per_cycle`
+    /// cycles, rounded up. Defaults to 1.
+    pub recipients_per_cycle: u32,
+
+    /// Default insurance premium, in basis points of each contribution, paid into
+    /// the insurance fund for the group's token. Zero when the group isn't insured.
+    pub insurance_premium_bps: u32,
+
+    /// Vault contributions are deposited into to earn yield until the payout.
+    /// `None` keeps funds in the contract.
+    pub vault: Option<Address>,
+
+    /// Where yield earned in the vault goes at each payout.
+    pub yield_policy: YieldPolicy,
+
+    /// Whether the group rotates a pot or saves toward a target.
+    pub kind: GroupKind,
+
+    /// Number of cycles a target savings group runs before it matures.
+    /// Zero for rotating groups, which run one cycle per seat.
+    pub savings_cycles: u32,
+
+    /// Basis points (0-10,000) of a saver's balance forfeited by withdrawing before
+    /// maturity. Only used by target savings groups.
+    pub early_withdrawal_bps: u32,
+
+    /// Group this one renewed from, if it's a later round of an earlier group.
+    pub previous_group_id: Option<u64>,
+
+    /// Group created as the next round when this one completed, if any member
+    /// opted in.
+    pub next_group_id: Option<u64>,
+
+    /// Whether the next round's payout order is reshuffled rather than kept.
+    pub shuffle_on_renewal: bool,
+}
+
+/// Every term a creator can choose for a group, set all at once on creation.
+///
+/// Each field means the same as the [`Group`] field of the same name and is
+/// validated the same way as by its setter. Groups created by `create_group` start
+/// with `commission` `None`, `NextRecipient` penalties, a `Flat` penalty schedule,
+/// a `Rolling` cycle schedule, no token, vault or bounty, one seat per member at
+/// tier 1, one recipient per cycle, and no insurance premium.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GroupTerms {
+    /// Contribution per seat per period, in stroops.
+    pub contribution_amount: i128,
+
+    /// Duration of each cycle (the payout interval) in seconds.
+    pub cycle_duration: u64,
+
+    /// Length of each contribution period in seconds, or zero for one per cycle.
+    pub contribution_interval: u64,
+
+    /// Maximum number of seats.
+    pub max_members: u32,
+
+    /// Grace period after each deadline, in seconds.
+    pub grace_period: u64,
+
+    /// Late penalty rate as a percentage (0-100).
+    pub penalty_rate: u32,
+
+    /// Commission paid to the creator from each payout.
+    pub commission: OrganizerCommission,
+
+    /// Where late penalties are sent.
+    pub penalty_policy: PenaltyPolicy,
+
+    /// How the late penalty scales with lateness.
+    pub penalty_schedule: PenaltySchedule,
+
+    /// How the start of each cycle is determined.
+    pub cycle_schedule: CycleSchedule,
+
+    /// Maximum bounty paid to a keeper who cranks a due payout.
+    pub keeper_bounty: i128,
+
+    /// Token contributions are pulled in by auto-debit mandates.
+    pub token: Option<Address>,
+
+    /// Whether seat transfers also need the creator's authorization.
+    pub seat_transfer_needs_approval: bool,
+
+    /// How many seats one address may hold.
+    pub max_seats_per_member: u32,
+
+    /// Highest contribution tier a seat may choose.
+    pub max_contribution_tier: u32,
+
+    /// Number of seats paid by each payout.
+    pub recipients_per_cycle: u32,
+
+    /// Insurance premium in basis points of each contribution; needs `token`.
+    pub insurance_premium_bps: u32,
+
+    /// Vault contributions are deposited into until the payout.
+    pub vault: Option<Address>,
+
+    /// Where yield earned in the vault goes.
+    pub yield_policy: YieldPolicy,
+
+    /// Whether the group rotates a pot or saves toward a target.
+    pub kind: GroupKind,
+
+    /// Cycles a target savings group runs; zero for rotating groups.
+    pub savings_cycles: u32,
+
+    /// Basis points of a saver's balance forfeited by withdrawing early.
+    pub early_withdrawal_bps: u32,
+
+    /// Whether the next round's payout order is reshuffled.
+    pub shuffle_on_renewal: bool,
+}
+
+/// How cycle start times are determined.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum CycleSchedule {
+    /// Each cycle starts when the previous payout is executed, so late payouts
+    /// shift every later cycle.
+    Rolling,
+    /// Cycle N starts at `created_at + (N - 1) × cycle_duration`, regardless of
+    /// when payouts run.
+    Anchored,
+    /// Cycle N starts at the N-th timestamp in the list and ends when the next
+    /// one starts (the last cycle lasts `cycle_duration`).
+    Calendar(Vec<u64>),
+}
+
+/// How a late contribution's penalty scales with how late it is.
+///
+/// Lateness is measured from the end of the cycle. All rates are in basis
+/// points of `contribution_amount` (100 = 1%).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PenaltySchedule {
+    /// Flat `Group.penalty_rate` percent, however late the contribution is.
+    Flat,
+    /// Rate grows linearly for every started hour late, up to a cap.
+    Linear(LinearPenalty),
+    /// Rate steps up through tiers as lateness grows, up to a cap.
+    Stepped(SteppedPenalty),
+}
+
+/// Linear penalty accrual settings.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LinearPenalty {
+    /// Basis points added for each started hour late.
+    pub bps_per_hour: u32,
+    /// Maximum total rate in basis points.
+    pub cap_bps: u32,
+}
+
+/// Stepped penalty tiers.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SteppedPenalty {
+    /// Tiers in strictly ascending `late_after` order. The first tier must start at 0.
+    pub tiers: Vec<PenaltyTier>,
+    /// Maximum rate in basis points.
+    pub cap_bps: u32,
+}
+
+/// A single tier of a stepped penalty schedule.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PenaltyTier {
+    /// Seconds after the cycle end at which this tier starts to apply.
+    pub late_after: u64,
+    /// Penalty rate in basis points while this tier applies.
+    pub rate_bps: u32,
+}
+
+/// Basis points in 100%.
+pub const BPS_DENOMINATOR: u32 = 10_000;
+
+/// Maximum number of tiers in a stepped penalty schedule.
+pub const MAX_PENALTY_TIERS: u32 = 10;
+
+/// Destination for the late penalties collected in a cycle.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum PenaltyPolicy {
+    /// Penalties are added to the payout of the cycle's recipient.
+    NextRecipient = 0,
+    /// Penalties are split equally among members who contributed on time that cycle.
+    OnTimeContributors = 1,
+    /// Penalties accumulate in a group reserve split equally among members at completion.
+    GroupReserve = 2,
+    /// Penalties fund the insurance fund for the group's contribution token.
+    InsurancePool = 3,
+    /// Penalties are sent to the protocol treasury.
+    Treasury = 4,
+}
+
+/// Destination for the yield a group's vault earns between contributions and payout.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum YieldPolicy {
+    /// Yield is added to the payout of the cycle's recipient.
+    Recipient = 0,
+    /// Yield accumulates in the group reserve split equally among members at completion.
+    GroupReserve = 1,
+}
+
+/// How a group's contributions are paid out.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum GroupKind {
+    /// Each cycle's pot is paid to the next member in rotation.
+    Rotating = 0,
+    /// Nobody is paid during the cycles; once the group matures each member
+    /// withdraws their own savings plus a share of the group reserve.
+    TargetSavings = 1,
+}
+
+/// A group's holding in its vault.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VaultPosition {
+    /// The vault holding the funds.
+    pub vault: Address,
+
+    /// The group's share of everything the contract holds in the vault.
+    pub shares: i128,
+
+    /// Total deposited since the last withdrawal (in stroops).
+    pub principal: i128,
+}
+
+/// Commission charged by the group organizer (the creator) on every payout.
+///
+/// Traditional Esusu collectors take a cut for running the group. The commission
+/// is taken out of the pot at each `execute_payout` and sent to `Group.creator`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum OrganizerCommission {
+    /// No commission is charged.
+    None,
+    /// Commission in basis points of the base payout (100 = 1%).
+    BasisPoints(u32),
+    /// Fixed commission in stroops taken from each payout.
+    Fixed(i128),
 }
 
+/// Maximum organizer commission in basis points (20%).
+pub const MAX_COMMISSION_BPS: u32 = 2_000;
+
+/// Maximum default insurance premium in basis points (10%).
+pub const MAX_INSURANCE_PREMIUM_BPS: u32 = 1_000;
+
 /// Records a single member's contribution for a specific cycle.
 ///
 /// Written to persistent storage when a member calls `contribute()`.
@@ -97,15 +409,29 @@ pub struct ContributionRecord {
     /// `true` means the contribution has been recorded; `false` means pending.
     pub has_paid: bool,
 
+    /// Amount paid so far this cycle (in stroops). Reaches `contribution_amount`
+    /// for each seat the member holds once the last installment is paid.
+    pub amount_paid: i128,
+
     /// Unix timestamp (seconds) when the contribution was recorded.
     pub timestamp: u64,
 
     /// Whether this contribution was made late (during grace period).
     pub is_late: bool,
 
-    /// Penalty amount charged for late contribution (in stroops).
-    /// Zero if contribution was on time.
+    /// Penalty amount charged for late contribution (in stroops), summed over
+    /// all installments. Zero if contribution was on time.
     pub penalty_amount: i128,
+
+    /// Whether this contribution was paid in advance with `prepay`.
+    pub is_prepaid: bool,
+
+    /// Address that paid the contribution: the member, or a sponsor using
+    /// `contribute_for`. Refunds go back to this address.
+    pub payer: Address,
+
+    /// Seats paid individually with `contribute_seat`, by payout position.
+    pub seats_paid: Vec<u32>,
 }
 
 /// Tracks penalty statistics for a member across all cycles in a group.
@@ -147,13 +473,57 @@ pub struct PayoutRecord {
     /// The cycle number in which this payout was made.
     pub cycle: u32,
 
-    /// Total payout amount in stroops (`contribution_amount × member_count`).
+    /// Net amount received by the member in stroops, after the organizer commission.
     pub amount: i128,
 
+    /// Organizer commission deducted from this payout (in stroops).
+    pub commission: i128,
+
     /// Unix timestamp (seconds) when the payout was executed.
     pub timestamp: u64,
+
+    /// Where the net amount was sent. A single share to the member unless they
+    /// set payout splits.
+    pub shares: Vec<PayoutShare>,
+}
+
+/// One receiving address in a member's payout split.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayoutSplit {
+    /// Address receiving this part of the payout.
+    pub recipient: Address,
+
+    /// Share of the payout in basis points. All splits must total 10,000.
+    pub bps: u32,
+}
+
+/// Amount actually sent to one address when a payout is executed.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayoutShare {
+    /// Address that received the amount.
+    pub recipient: Address,
+
+    /// Amount sent in stroops.
+    pub amount: i128,
 }
 
+/// Maximum number of addresses a payout can be split across.
+pub const MAX_PAYOUT_SPLITS: u32 = 5;
+
+/// Maximum number of members who can share one seat.
+pub const MAX_SEAT_HOLDERS: u32 = 4;
+
+/// Highest contribution tier a group can offer.
+pub const MAX_CONTRIBUTION_TIER: u32 = 10;
+
+/// Maximum number of contribution periods in one cycle.
+pub const MAX_CONTRIBUTION_PERIODS: u32 = 31;
+
+/// Maximum number of cycles a target savings group can run.
+pub const MAX_SAVINGS_CYCLES: u32 = 100;
+
 /// Comprehensive snapshot of a group's current state.
 ///
 /// Returned by [`crate::contract::AjoContract::get_group_status`] to give callers a single
@@ -208,6 +578,43 @@ pub struct GroupStatus {
 
     /// Unix timestamp when grace period ends.
     pub grace_period_end_time: u64,
+
+    /// Commission terms charged by the organizer on each payout.
+    pub organizer_commission: OrganizerCommission,
+
+    /// Commission (in stroops) the organizer would take from the next payout.
+    pub commission_per_payout: i128,
+
+    /// Where late penalties are sent.
+    pub penalty_policy: PenaltyPolicy,
+
+    /// Penalties held in the group reserve until completion (in stroops).
+    pub penalty_reserve: i128,
+
+    /// How cycle start times are determined.
+    pub cycle_schedule: CycleSchedule,
+
+    /// Whether the current cycle started behind schedule and is catching up.
+    pub is_catching_up: bool,
+
+    /// Whether the payout for the current cycle can be executed now.
+    pub is_payout_due: bool,
+
+    /// Maximum bounty paid to a keeper who cranks the payout (in stroops).
+    pub keeper_bounty: i128,
+
+    /// Members still to be paid, in payout order, starting with the current recipient.
+    pub payout_order: Vec<Address>,
+
+    /// Current contribution period within the cycle (1-based); always 1 when
+    /// contributions are collected once per cycle.
+    pub current_period: u32,
+
+    /// Number of contribution periods in each cycle.
+    pub periods_per_cycle: u32,
+
+    /// Unix timestamp when the current contribution period's deadline passes.
+    pub period_end_time: u64,
 }
 
 /// Optional metadata for a group.
@@ -222,6 +629,21 @@ pub struct GroupMetadata {
     pub rules: soroban_sdk::String,
 }
 
+/// Saved group settings a creator can start new groups from.
+///
+/// Groups created from a template start with all of the template's terms, the
+/// same ones `clone_group` copies from a group, and its metadata if any (stored
+/// alongside, like a group's).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GroupTemplate {
+    /// Address that saved the template.
+    pub creator: Address,
+
+    /// Terms given to each group created from the template.
+    pub terms: GroupTerms,
+}
+
 pub const MAX_NAME_LENGTH: u32 = 50;
 pub const MAX_DESCRIPTION_LENGTH: u32 = 250;
 pub const MAX_RULES_LENGTH: u32 = 1000;
@@ -272,6 +694,70 @@ pub struct RefundVote {
     pub timestamp: u64,
 }
 
+/// A member's standing order to have contributions pulled from their token allowance.
+///
+/// The member approves the contract as a spender on the group's token; keepers then
+/// pull each due contribution without a fresh signature. No tokens move, so the
+/// mandate keeps its own tally of what it has pulled against the allowance.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AutoContributeMandate {
+    /// Member whose allowance is debited.
+    pub member: Address,
+
+    /// The group contributions are pulled for.
+    pub group_id: u64,
+
+    /// Cycles still to be pulled before the mandate expires.
+    pub remaining_cycles: u32,
+
+    /// Total pulled under this mandate so far, premiums included, in stroops.
+    pub pulled: i128,
+
+    /// Unix timestamp when the mandate was enabled.
+    pub enabled_at: u64,
+}
+
+/// A pending request from one member to swap payout positions with another.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SwapProposal {
+    /// Member who proposed the swap.
+    pub from: Address,
+
+    /// Member asked to accept the swap.
+    pub to: Address,
+
+    /// Unix timestamp when the swap was proposed.
+    pub proposed_at: u64,
+}
+
+/// A default covered by the insurance fund.
+///
+/// Recorded when a member who has already received their payout misses a
+/// contribution and the grace period expires, so the fund pays their balance.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InsuranceClaim {
+    /// Member whose contribution was covered.
+    pub member: Address,
+
+    /// The group the claim was paid to.
+    pub group_id: u64,
+
+    /// The cycle the member defaulted on.
+    pub cycle: u32,
+
+    /// Amount paid out of the fund (in stroops).
+    pub amount: i128,
+
+    /// Token the fund paid in.
+    pub token: Address,
+
+    /// Unix timestamp when the claim was paid.
+    pub timestamp: u64,
+}
+
 /// Records a refund transaction.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -279,7 +765,7 @@ pub struct RefundRecord {
     /// The group this refund is for.
     pub group_id: u64,
 
-    /// Address of the member receiving the refund.
+    /// Address receiving the refund: the member, or whoever paid on their behalf.
     pub member: Address,
 
     /// Amount refunded in stroops.
@@ -305,8 +791,864 @@ pub enum RefundReason {
     EmergencyRefund = 2,
 }
 
+/// Longest window given to a cycle that starts behind schedule (24 hours).
+pub const CATCH_UP_WINDOW: u64 = 86_400;
+
 /// Voting period duration in seconds (7 days).
 pub const VOTING_PERIOD: u64 = 604_800;
 
 /// Minimum approval percentage required for refund (51%).
 pub const REFUND_APPROVAL_THRESHOLD: u32 = 51;
+
+/// Version of the event schema, carried by every event payload. Bumped whenever
+/// a payload's fields change.
+pub const EVENT_SCHEMA_VERSION: u32 = 1;
+
+/// Event payload: An admin action on the whole contract: `init`, `paused` and `unpaused`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminEvent {
+    /// Event schema version ([`EVENT_SCHEMA_VERSION`]).
+    pub version: u32,
+
+    /// The group, or zero for events not tied to a group.
+    pub group_id: u64,
+
+    /// The contract admin.
+    pub admin: Address,
+}
+
+/// Event payload: The contract's Wasm was upgraded: `upgraded`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UpgradeEvent {
+    /// Event schema version ([`EVENT_SCHEMA_VERSION`]).
+    pub version: u32,
+
+    /// The group, or zero for events not tied to a group.
+    pub group_id: u64,
+
+    /// The contract admin.
+    pub admin: Address,
+
+    /// Hash of the new Wasm code.
+    pub wasm_hash: BytesN<32>,
+}
+
+/// Event payload: A group was created: `created`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GroupCreatedEvent {
+    /// Event schema version ([`EVENT_SCHEMA_VERSION`]).
+    pub version: u32,
+
+    /// The group, or zero for events not tied to a group.
+    pub group_id: u64,
+
+    /// The group's creator.
+    pub creator: Address,
+
+    /// Members the group starts with, in payout order: the creator, or the
+    /// opted-in members of a renewed group.
+    pub members: Vec<Address>,
+
+    /// Unix timestamp (seconds) when the group was created and its first cycle started.
+    pub created_at: u64,
+
+    /// Amount each member contributes per cycle, in stroops.
+    pub contribution_amount: i128,
+
+    /// Duration of each cycle in seconds.
+    pub cycle_duration: u64,
+
+    /// Maximum number of members.
+    pub max_members: u32,
+
+    /// Grace period after each cycle ends, in seconds.
+    pub grace_period: u64,
+
+    /// Late penalty rate as a percentage.
+    pub penalty_rate: u32,
+}
+
+/// Event payload: A member acted on the group: `joined`, `autooff` and `renewopt`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MemberEvent {
+    /// Event schema version ([`EVENT_SCHEMA_VERSION`]).
+    pub version: u32,
+
+    /// The group, or zero for events not tied to a group.
+    pub group_id: u64,
+
+    /// The member.
+    pub member: Address,
+}
+
+/// Event payload: An amount paid by or to a member in a cycle: `contrib`, `autopull`, `autofail`,
+/// `premium`, `insclaim`, `payout`, `commpaid` and `penshare`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MemberAmountEvent {
+    /// Event schema version ([`EVENT_SCHEMA_VERSION`]).
+    pub version: u32,
+
+    /// The group, or zero for events not tied to a group.
+    pub group_id: u64,
+
+    /// The cycle (or first prepaid cycle) the amount is for.
+    pub cycle: u32,
+
+    /// The member paying or being paid.
+    pub member: Address,
+
+    /// The amount in stroops.
+    pub amount: i128,
+}
+
+/// Event payload: A member paid part of their contribution: `install`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InstallmentEvent {
+    /// Event schema version ([`EVENT_SCHEMA_VERSION`]).
+    pub version: u32,
+
+    /// The group, or zero for events not tied to a group.
+    pub group_id: u64,
+
+    /// The cycle the installment is for.
+    pub cycle: u32,
+
+    /// The member.
+    pub member: Address,
+
+    /// Amount paid in this installment.
+    pub amount: i128,
+
+    /// Amount still owed for the cycle.
+    pub outstanding: i128,
+}
+
+/// Event payload: A third party paid a member's contribution: `sponsored`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SponsoredEvent {
+    /// Event schema version ([`EVENT_SCHEMA_VERSION`]).
+    pub version: u32,
+
+    /// The group, or zero for events not tied to a group.
+    pub group_id: u64,
+
+    /// The cycle the contribution is for.
+    pub cycle: u32,
+
+    /// The address paying.
+    pub payer: Address,
+
+    /// The member credited.
+    pub member: Address,
+
+    /// The amount paid.
+    pub amount: i128,
+}
+
+/// Event payload: An amount with a penalty attached: `late` (contribution and late penalty),
+/// `savewdraw` (savings withdrawn and early-withdrawal penalty) and `pendistr`
+/// (base payout and penalty bonus).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PenaltyEvent {
+    /// Event schema version ([`EVENT_SCHEMA_VERSION`]).
+    pub version: u32,
+
+    /// The group, or zero for events not tied to a group.
+    pub group_id: u64,
+
+    /// The cycle.
+    pub cycle: u32,
+
+    /// The member.
+    pub member: Address,
+
+    /// The contribution, withdrawal or base payout.
+    pub amount: i128,
+
+    /// The penalty charged, forfeited or distributed.
+    pub penalty: i128,
+}
+
+/// Event payload: The group's contribution token was set: `tokenset`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenSetEvent {
+    /// Event schema version ([`EVENT_SCHEMA_VERSION`]).
+    pub version: u32,
+
+    /// The group, or zero for events not tied to a group.
+    pub group_id: u64,
+
+    /// The token contract.
+    pub token: Address,
+}
+
+/// Event payload: A member enabled an auto-debit mandate: `autoon`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MandateEvent {
+    /// Event schema version ([`EVENT_SCHEMA_VERSION`]).
+    pub version: u32,
+
+    /// The group, or zero for events not tied to a group.
+    pub group_id: u64,
+
+    /// The member.
+    pub member: Address,
+
+    /// Number of cycles the mandate may pull.
+    pub max_cycles: u32,
+}
+
+/// Event payload: A member prepaid future cycles: `prepaid`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PrepaidEvent {
+    /// Event schema version ([`EVENT_SCHEMA_VERSION`]).
+    pub version: u32,
+
+    /// The group, or zero for events not tied to a group.
+    pub group_id: u64,
+
+    /// The member.
+    pub member: Address,
+
+    /// First prepaid cycle.
+    pub first_cycle: u32,
+
+    /// Last prepaid cycle.
+    pub last_cycle: u32,
+
+    /// Total paid.
+    pub amount: i128,
+}
+
+/// Event payload: A member changed where their payout is sent: `splitset`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SplitsSetEvent {
+    /// Event schema version ([`EVENT_SCHEMA_VERSION`]).
+    pub version: u32,
+
+    /// The group, or zero for events not tied to a group.
+    pub group_id: u64,
+
+    /// The member.
+    pub member: Address,
+
+    /// Number of addresses the payout is split across; zero sends it to the member.
+    pub split_count: u32,
+}
+
+/// Event payload: Part of a payout was sent to one address: `payshare`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayoutShareEvent {
+    /// Event schema version ([`EVENT_SCHEMA_VERSION`]).
+    pub version: u32,
+
+    /// The group, or zero for events not tied to a group.
+    pub group_id: u64,
+
+    /// The payout's cycle.
+    pub cycle: u32,
+
+    /// The member being paid.
+    pub member: Address,
+
+    /// The address receiving this share.
+    pub recipient: Address,
+
+    /// The share in stroops.
+    pub amount: i128,
+}
+
+/// Event payload: The creator turned a setting on or off: `seatappr` and `renewshuf`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FlagSetEvent {
+    /// Event schema version ([`EVENT_SCHEMA_VERSION`]).
+    pub version: u32,
+
+    /// The group, or zero for events not tied to a group.
+    pub group_id: u64,
+
+    /// Whether the setting is on.
+    pub enabled: bool,
+}
+
+/// Event payload: The creator set a numeric limit: `seatmax`, `tiermax` and `payees`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LimitSetEvent {
+    /// Event schema version ([`EVENT_SCHEMA_VERSION`]).
+    pub version: u32,
+
+    /// The group, or zero for events not tied to a group.
+    pub group_id: u64,
+
+    /// The new limit.
+    pub value: u32,
+}
+
+/// Event payload: The creator opted the group into the insurance fund: `insure`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InsuranceSetEvent {
+    /// Event schema version ([`EVENT_SCHEMA_VERSION`]).
+    pub version: u32,
+
+    /// The group, or zero for events not tied to a group.
+    pub group_id: u64,
+
+    /// Token of the insurance fund.
+    pub token: Address,
+
+    /// Premium in basis points of each contribution.
+    pub premium_bps: u32,
+}
+
+/// Event payload: The creator set the group's vault: `vaultset`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VaultSetEvent {
+    /// Event schema version ([`EVENT_SCHEMA_VERSION`]).
+    pub version: u32,
+
+    /// The group, or zero for events not tied to a group.
+    pub group_id: u64,
+
+    /// The vault contract.
+    pub vault: Address,
+
+    /// Where yield goes at each payout.
+    pub yield_policy: YieldPolicy,
+}
+
+/// Event payload: The creator set separate contribution and payout intervals: `intervals`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct IntervalsSetEvent {
+    /// Event schema version ([`EVENT_SCHEMA_VERSION`]).
+    pub version: u32,
+
+    /// The group, or zero for events not tied to a group.
+    pub group_id: u64,
+
+    /// Seconds between contributions.
+    pub contribution_interval: u64,
+
+    /// Seconds between payouts.
+    pub payout_interval: u64,
+}
+
+/// Event payload: A member chose a seat's contribution tier: `seattier`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SeatTierSetEvent {
+    /// Event schema version ([`EVENT_SCHEMA_VERSION`]).
+    pub version: u32,
+
+    /// The group, or zero for events not tied to a group.
+    pub group_id: u64,
+
+    /// The seat's holder.
+    pub member: Address,
+
+    /// The seat's position in the payout order.
+    pub seat: u32,
+
+    /// The chosen tier.
+    pub tier: u32,
+}
+
+/// Event payload: The creator made the group a target savings group: `savings`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TargetSavingsSetEvent {
+    /// Event schema version ([`EVENT_SCHEMA_VERSION`]).
+    pub version: u32,
+
+    /// The group, or zero for events not tied to a group.
+    pub group_id: u64,
+
+    /// Number of cycles before the group matures.
+    pub savings_cycles: u32,
+
+    /// Basis points forfeited by withdrawing early.
+    pub early_withdrawal_bps: u32,
+}
+
+/// Event payload: A target savings cycle closed: `saveclose`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SavingsCycleClosedEvent {
+    /// Event schema version ([`EVENT_SCHEMA_VERSION`]).
+    pub version: u32,
+
+    /// The group, or zero for events not tied to a group.
+    pub group_id: u64,
+
+    /// The closed cycle.
+    pub cycle: u32,
+
+    /// Number of savers who didn't contribute in full.
+    pub missed: u32,
+}
+
+/// Event payload: A contribution was deposited into the vault (`vaultin`), or the vault rejected
+/// it (`vaultfail`).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VaultAmountEvent {
+    /// Event schema version ([`EVENT_SCHEMA_VERSION`]).
+    pub version: u32,
+
+    /// The group, or zero for events not tied to a group.
+    pub group_id: u64,
+
+    /// The cycle the contribution is for.
+    pub cycle: u32,
+
+    /// The amount in stroops.
+    pub amount: i128,
+}
+
+/// Event payload: The group's funds were withdrawn from its vault: `vaultout`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VaultWithdrawalEvent {
+    /// Event schema version ([`EVENT_SCHEMA_VERSION`]).
+    pub version: u32,
+
+    /// The group, or zero for events not tied to a group.
+    pub group_id: u64,
+
+    /// The cycle being paid out.
+    pub cycle: u32,
+
+    /// Amount withdrawn, including yield.
+    pub withdrawn: i128,
+
+    /// Yield earned, negative for a loss.
+    pub vault_yield: i128,
+}
+
+/// Event payload: Co-holders joined sharing one seat: `seatshare`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SeatSharedEvent {
+    /// Event schema version ([`EVENT_SCHEMA_VERSION`]).
+    pub version: u32,
+
+    /// The group, or zero for events not tied to a group.
+    pub group_id: u64,
+
+    /// The co-holders; the first holds the seat.
+    pub holders: Vec<Address>,
+}
+
+/// Event payload: An action between two members: `seatxfer` (seat handed over) and `swapprop`
+/// (swap proposed).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MemberPairEvent {
+    /// Event schema version ([`EVENT_SCHEMA_VERSION`]).
+    pub version: u32,
+
+    /// The group, or zero for events not tied to a group.
+    pub group_id: u64,
+
+    /// The member acting.
+    pub from: Address,
+
+    /// The other member.
+    pub to: Address,
+}
+
+/// Event payload: Two members swapped payout positions: `swapped`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SwapEvent {
+    /// Event schema version ([`EVENT_SCHEMA_VERSION`]).
+    pub version: u32,
+
+    /// The group, or zero for events not tied to a group.
+    pub group_id: u64,
+
+    /// The member who proposed the swap.
+    pub from: Address,
+
+    /// The member who accepted it.
+    pub to: Address,
+
+    /// The cycle `from` is now paid in.
+    pub from_cycle: u32,
+
+    /// The cycle `to` is now paid in.
+    pub to_cycle: u32,
+}
+
+/// Event payload: The creator set the organizer commission: `commset`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CommissionSetEvent {
+    /// Event schema version ([`EVENT_SCHEMA_VERSION`]).
+    pub version: u32,
+
+    /// The group, or zero for events not tied to a group.
+    pub group_id: u64,
+
+    /// The commission taken from each payout.
+    pub commission: OrganizerCommission,
+}
+
+/// Event payload: The creator set the keeper bounty: `bountyset`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BountySetEvent {
+    /// Event schema version ([`EVENT_SCHEMA_VERSION`]).
+    pub version: u32,
+
+    /// The group, or zero for events not tied to a group.
+    pub group_id: u64,
+
+    /// Maximum bounty per payout, in stroops.
+    pub bounty: i128,
+}
+
+/// Event payload: A keeper cranked a payout: `cranked`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct KeeperCrankedEvent {
+    /// Event schema version ([`EVENT_SCHEMA_VERSION`]).
+    pub version: u32,
+
+    /// The group, or zero for events not tied to a group.
+    pub group_id: u64,
+
+    /// The cycle paid out.
+    pub cycle: u32,
+
+    /// The keeper.
+    pub keeper: Address,
+
+    /// Bounty paid to the keeper.
+    pub bounty: i128,
+
+    /// Seconds the payout had been due.
+    pub overdue_by: u64,
+}
+
+/// Event payload: A batched crank skipped a group that wasn't ready: `skipped`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CrankSkippedEvent {
+    /// Event schema version ([`EVENT_SCHEMA_VERSION`]).
+    pub version: u32,
+
+    /// The group, or zero for events not tied to a group.
+    pub group_id: u64,
+
+    /// The keeper.
+    pub keeper: Address,
+
+    /// Code of the error that made the group not ready.
+    pub reason: u32,
+}
+
+/// Event payload: A completed group renewed into its next round: `renewed`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GroupRenewedEvent {
+    /// Event schema version ([`EVENT_SCHEMA_VERSION`]).
+    pub version: u32,
+
+    /// The group, or zero for events not tied to a group.
+    pub group_id: u64,
+
+    /// The successor group.
+    pub next_group_id: u64,
+
+    /// Number of members who opted in.
+    pub members: u32,
+}
+
+/// Event payload: A creator saved a group template: `template`. Not tied to a group, so
+/// `group_id` is zero.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TemplateSavedEvent {
+    /// Event schema version ([`EVENT_SCHEMA_VERSION`]).
+    pub version: u32,
+
+    /// The group, or zero for events not tied to a group.
+    pub group_id: u64,
+
+    /// The new template.
+    pub template_id: u64,
+
+    /// The address that saved it.
+    pub creator: Address,
+}
+
+/// Event payload: A group was created from a template (`fromtmpl`) or copied from another
+/// group (`cloned`).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GroupSourceEvent {
+    /// Event schema version ([`EVENT_SCHEMA_VERSION`]).
+    pub version: u32,
+
+    /// The group, or zero for events not tied to a group.
+    pub group_id: u64,
+
+    /// The template or source group.
+    pub source_id: u64,
+}
+
+/// Event payload: A cycle started (`cycle`) or the group completed (`complete`).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CycleEvent {
+    /// Event schema version ([`EVENT_SCHEMA_VERSION`]).
+    pub version: u32,
+
+    /// The group, or zero for events not tied to a group.
+    pub group_id: u64,
+
+    /// The cycle started, or the group's last cycle.
+    pub cycle: u32,
+
+    /// When the cycle started, or when the group completed.
+    pub timestamp: u64,
+}
+
+/// Event payload: The creator set the cycle schedule: `cyclesch`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CycleScheduleSetEvent {
+    /// Event schema version ([`EVENT_SCHEMA_VERSION`]).
+    pub version: u32,
+
+    /// The group, or zero for events not tied to a group.
+    pub group_id: u64,
+
+    /// The new schedule.
+    pub schedule: CycleSchedule,
+}
+
+/// Event payload: A cycle started behind schedule and must catch up: `catchup`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CatchUpEvent {
+    /// Event schema version ([`EVENT_SCHEMA_VERSION`]).
+    pub version: u32,
+
+    /// The group, or zero for events not tied to a group.
+    pub group_id: u64,
+
+    /// The cycle.
+    pub cycle: u32,
+
+    /// When the cycle should have started.
+    pub scheduled_start: u64,
+
+    /// End of the window given to catch up.
+    pub catch_up_end: u64,
+}
+
+/// Event payload: The creator cancelled the group: `cancel`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GroupCancelledEvent {
+    /// Event schema version ([`EVENT_SCHEMA_VERSION`]).
+    pub version: u32,
+
+    /// The group, or zero for events not tied to a group.
+    pub group_id: u64,
+
+    /// The creator.
+    pub creator: Address,
+
+    /// Number of seats refunded.
+    pub member_count: u32,
+
+    /// Contribution amount per seat.
+    pub refund_per_member: i128,
+}
+
+/// Event payload: The creator set the penalty policy: `penpolicy`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PenaltyPolicySetEvent {
+    /// Event schema version ([`EVENT_SCHEMA_VERSION`]).
+    pub version: u32,
+
+    /// The group, or zero for events not tied to a group.
+    pub group_id: u64,
+
+    /// The new policy.
+    pub policy: PenaltyPolicy,
+}
+
+/// Event payload: The creator set the penalty schedule: `pensched`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PenaltyScheduleSetEvent {
+    /// Event schema version ([`EVENT_SCHEMA_VERSION`]).
+    pub version: u32,
+
+    /// The group, or zero for events not tied to a group.
+    pub group_id: u64,
+
+    /// The new schedule.
+    pub schedule: PenaltySchedule,
+}
+
+/// Event payload: Penalties were moved into a pool: `penpooled`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PenaltyPooledEvent {
+    /// Event schema version ([`EVENT_SCHEMA_VERSION`]).
+    pub version: u32,
+
+    /// The group, or zero for events not tied to a group.
+    pub group_id: u64,
+
+    /// The cycle the penalties were collected in.
+    pub cycle: u32,
+
+    /// The policy deciding the pool.
+    pub policy: PenaltyPolicy,
+
+    /// The amount pooled.
+    pub amount: i128,
+}
+
+/// Event payload: A member requested a refund vote: `refreq`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RefundRequestedEvent {
+    /// Event schema version ([`EVENT_SCHEMA_VERSION`]).
+    pub version: u32,
+
+    /// The group, or zero for events not tied to a group.
+    pub group_id: u64,
+
+    /// The member requesting.
+    pub requester: Address,
+
+    /// When voting ends.
+    pub voting_deadline: u64,
+}
+
+/// Event payload: A member voted on a refund request: `refvote`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RefundVoteEvent {
+    /// Event schema version ([`EVENT_SCHEMA_VERSION`]).
+    pub version: u32,
+
+    /// The group, or zero for events not tied to a group.
+    pub group_id: u64,
+
+    /// The member voting.
+    pub voter: Address,
+
+    /// Whether they voted for the refund.
+    pub in_favor: bool,
+}
+
+/// Event payload: A refund was paid: `refund`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RefundEvent {
+    /// Event schema version ([`EVENT_SCHEMA_VERSION`]).
+    pub version: u32,
+
+    /// The group, or zero for events not tied to a group.
+    pub group_id: u64,
+
+    /// The address refunded.
+    pub member: Address,
+
+    /// The amount refunded.
+    pub amount: i128,
+
+    /// Why the refund was paid.
+    pub reason: RefundReason,
+}
+
+/// Event payload: A refund request was rejected by vote: `refreject`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RefundRejectedEvent {
+    /// Event schema version ([`EVENT_SCHEMA_VERSION`]).
+    pub version: u32,
+
+    /// The group, or zero for events not tied to a group.
+    pub group_id: u64,
+
+    /// Votes for the refund.
+    pub votes_for: u32,
+
+    /// Votes against it.
+    pub votes_against: u32,
+}
+
+/// Event payload: A refund request was approved by vote and the group refunded: `refaccept`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RefundApprovedEvent {
+    /// Event schema version ([`EVENT_SCHEMA_VERSION`]).
+    pub version: u32,
+
+    /// The group, or zero for events not tied to a group.
+    pub group_id: u64,
+
+    /// Votes for the refund.
+    pub votes_for: u32,
+
+    /// Votes against it.
+    pub votes_against: u32,
+}
+
+/// Event payload: The admin refunded the group: `emrefund`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EmergencyRefundEvent {
+    /// Event schema version ([`EVENT_SCHEMA_VERSION`]).
+    pub version: u32,
+
+    /// The group, or zero for events not tied to a group.
+    pub group_id: u64,
+
+    /// The contract admin.
+    pub admin: Address,
+
+    /// Total amount refunded.
+    pub total_refunded: i128,
+}
+
+/// Event payload: The creator set the group's metadata: `metadata`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MetadataSetEvent {
+    /// Event schema version ([`EVENT_SCHEMA_VERSION`]).
+    pub version: u32,
+
+    /// The group, or zero for events not tied to a group.
+    pub group_id: u64,
+
+    /// The new metadata.
+    pub metadata: GroupMetadata,
+}
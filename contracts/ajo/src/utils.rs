@@ -1,6 +1,12 @@
 use soroban_sdk::{Address, Env, Vec};
 
-use crate::types::Group;
+use crate::events;
+use crate::types::{
+    CycleSchedule, Group, GroupKind, GroupMetadata, GroupState, GroupTerms, InsuranceClaim, OrganizerCommission, PayoutShare, PayoutSplit, PenaltyPolicy,
+    PenaltySchedule, YieldPolicy, BPS_DENOMINATOR, CATCH_UP_WINDOW, MAX_COMMISSION_BPS, MAX_CONTRIBUTION_PERIODS,
+    MAX_CONTRIBUTION_TIER, MAX_INSURANCE_PREMIUM_BPS, MAX_PAYOUT_SPLITS, MAX_PENALTY_TIERS, MAX_SAVINGS_CYCLES,
+    MAX_DESCRIPTION_LENGTH, MAX_NAME_LENGTH, MAX_RULES_LENGTH,
+};
 
 /// Returns `true` if `address` appears in the group's `members` list.
 ///
@@ -22,40 +28,756 @@ pub fn is_member(members: &Vec<Address>, address: &Address) -> bool {
     false
 }
 
+/// Returns the positions in the payout order of every seat held by `address`.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `members` - The ordered member list, one entry per seat
+/// * `address` - The member to look for
+///
+/// # Returns
+/// Seat positions in ascending order; empty if the address holds no seat
+pub fn member_seats(env: &Env, members: &Vec<Address>, address: &Address) -> Vec<u32> {
+    let mut seats = Vec::new(env);
+    for (index, member) in members.iter().enumerate() {
+        if member == *address {
+            seats.push_back(index as u32);
+        }
+    }
+    seats
+}
+
+/// Returns `true` if `address` holds a seat, alone or as a co-holder of a shared seat.
+///
+/// # Arguments
+/// * `env` - The contract environment (needed for storage reads)
+/// * `group` - The group
+/// * `address` - The address to look for
+pub fn holds_seat(env: &Env, group: &Group, address: &Address) -> bool {
+    is_member(&group.members, address)
+        || crate::storage::get_shared_seat(env, group.id, address).is_some()
+}
+
+/// Returns everyone paying into the seat listed under `member`.
+///
+/// # Arguments
+/// * `env` - The contract environment (needed for storage reads)
+/// * `group_id` - The group
+/// * `member` - The seat's holder, as listed in the group's member list
+///
+/// # Returns
+/// The co-holders of a shared seat, or just `member` otherwise
+pub fn seat_holders(env: &Env, group_id: u64, member: &Address) -> Vec<Address> {
+    crate::storage::get_seat_holders(env, group_id, member)
+        .unwrap_or_else(|| Vec::from_array(env, [member.clone()]))
+}
+
+/// Returns every address that owes contributions to the group, once each.
+///
+/// Members holding several seats are listed once, and each co-holder of a shared
+/// seat is listed, following the member list order.
+///
+/// # Arguments
+/// * `env` - The contract environment (needed for storage reads)
+/// * `group` - The group
+pub fn contributors(env: &Env, group: &Group) -> Vec<Address> {
+    let mut contributors = Vec::new(env);
+    for (index, member) in group.members.iter().enumerate() {
+        if group.members.first_index_of(&member) != Some(index as u32) {
+            continue;
+        }
+        contributors.append(&seat_holders(env, group.id, &member));
+    }
+    contributors
+}
+
+/// Returns the members of a target savings group who haven't withdrawn their savings.
+///
+/// # Arguments
+/// * `env` - The contract environment (needed for storage reads)
+/// * `group` - The group
+pub fn active_savers(env: &Env, group: &Group) -> Vec<Address> {
+    let mut savers = Vec::new(env);
+    for member in contributors(env, group).iter() {
+        if !crate::storage::has_received_payout(env, group.id, &member) {
+            savers.push_back(member);
+        }
+    }
+    savers
+}
+
+/// Returns the contribution tier of the seat at `seat`.
+///
+/// # Arguments
+/// * `group` - The group
+/// * `seat` - The seat's position in the payout order
+pub fn seat_tier(group: &Group, seat: u32) -> u32 {
+    group.seat_tiers.get(seat).unwrap_or(1)
+}
+
+/// Returns what the seat at `seat` owes each cycle: `contribution_amount` times its
+/// tier, for every contribution period in the cycle.
+///
+/// # Arguments
+/// * `group` - The group
+/// * `seat` - The seat's position in the payout order
+pub fn seat_contribution(group: &Group, seat: u32) -> i128 {
+    group.contribution_amount * seat_tier(group, seat) as i128 * contribution_periods(group) as i128
+}
+
+/// Returns the total owed by every seat each cycle.
+///
+/// # Arguments
+/// * `group` - The group
+pub fn cycle_pot(group: &Group) -> i128 {
+    (0..group.members.len()).map(|seat| seat_contribution(group, seat)).sum()
+}
+
+/// Returns the share of a seat's contribution owed by the holder at `index`.
+///
+/// Co-holders split the seat's contribution equally, with any remainder from
+/// integer division owed by the first holder.
+///
+/// # Arguments
+/// * `group` - The group
+/// * `holders` - The seat's co-holders
+/// * `index` - The holder's position in `holders`
+pub fn seat_share(group: &Group, holders: &Vec<Address>, index: u32) -> i128 {
+    let seat = group.members.first_index_of(holders.get_unchecked(0)).unwrap_or(0);
+    let amount = seat_contribution(group, seat);
+    let count = holders.len() as i128;
+    let share = amount / count;
+    if index == 0 {
+        amount - share * (count - 1)
+    } else {
+        share
+    }
+}
+
+/// Returns what a member owes each cycle.
+///
+/// That is the contribution of every seat held (see [`seat_contribution`]), or the
+/// member's share of a shared seat (see [`seat_share`]).
+///
+/// # Arguments
+/// * `env` - The contract environment (needed for storage reads)
+/// * `group` - The group
+/// * `member` - The member's address
+pub fn member_obligation(env: &Env, group: &Group, member: &Address) -> i128 {
+    if let Some(primary) = crate::storage::get_shared_seat(env, group.id, member) {
+        let holders = seat_holders(env, group.id, &primary);
+        let index = holders.first_index_of(member).unwrap_or(0);
+        return seat_share(group, &holders, index);
+    }
+    member_seats(env, &group.members, member)
+        .iter()
+        .map(|seat| seat_contribution(group, seat))
+        .sum()
+}
+
+/// Returns `true` once any contribution has been made to the group.
+///
+/// # Arguments
+/// * `env` - The contract environment (needed for storage reads)
+/// * `group` - The group
+pub fn contributions_started(env: &Env, group: &Group) -> bool {
+    if group.current_cycle > 1 || group.payout_index > 0 {
+        return true;
+    }
+    contributors(env, group).iter().any(|member| {
+        crate::storage::get_contribution_detail(env, group.id, 1, &member).is_some()
+            || crate::storage::get_prepaid_through(env, group.id, &member) > 0
+    })
+}
+
 /// Returns `true` if every member of the group has contributed in the current cycle.
 ///
 /// Iterates over all members and short-circuits on the first missing contribution.
+/// A shared seat only counts once every co-holder has paid their share. A member
+/// with a pending insurance claim counts as paid, since the claim is paid alongside
+/// the payout.
 /// This is called by [`execute_payout`](crate::contract::AjoContract::execute_payout)
 /// to gate payout execution — a payout cannot proceed until this returns `true`.
 ///
 /// # Arguments
 /// * `env` - The contract environment (needed for storage reads)
 /// * `group` - The group whose current cycle contributions are being verified
+/// * `claims` - Insurance claims that will cover defaults this cycle (see
+///   [`insurance::plan_claims`](crate::insurance::plan_claims))
 ///
 /// # Returns
 /// `true` if all members have contributed, `false` otherwise
-pub fn all_members_contributed(env: &Env, group: &Group) -> bool {
+pub fn all_members_contributed(env: &Env, group: &Group, claims: &Vec<InsuranceClaim>) -> bool {
+    let has_paid = |member: Address| {
+        crate::storage::has_contributed(env, group.id, group.current_cycle, &member)
+            || claims.iter().any(|claim| claim.member == member)
+    };
     for member in group.members.iter() {
-        if !crate::storage::has_contributed(env, group.id, group.current_cycle, &member) {
+        let paid = match crate::storage::get_seat_holders(env, group.id, &member) {
+            Some(holders) => holders.iter().all(has_paid),
+            None => has_paid(member),
+        };
+        if !paid {
             return false;
         }
     }
     true
 }
 
-/// Calculates the total payout amount for a single cycle.
+/// Returns `Ok(())` if the group's current payout can be executed at `now`.
+///
+/// A payout is due once every member has contributed and the grace period has
+/// expired. A catch-up cycle is due as soon as everyone has contributed. A target
+/// savings cycle doesn't wait for missing contributions: it can be closed once the
+/// grace period has expired, or as soon as everyone has contributed in catch-up.
+///
+/// Nothing is written, so the insurance claims that would cover defaults are passed
+/// in rather than paid first.
+///
+/// # Arguments
+/// * `env` - The contract environment (needed for storage reads)
+/// * `group` - The group to check
+/// * `claims` - Insurance claims that will cover defaults this cycle
+/// * `now` - Current ledger timestamp
+///
+/// # Errors
+/// * `GroupCancelled` - If the group has been cancelled
+/// * `GroupComplete` - If the group has already completed all payouts
+/// * `IncompleteContributions` - If not all members have contributed
+/// * `InsufficientBalance` - If the pot and surplus can't fund a tiered payout
+/// * `OutsideCycleWindow` - If grace period has not expired yet
+pub fn check_payout_ready(
+    env: &Env,
+    group: &Group,
+    claims: &Vec<InsuranceClaim>,
+    now: u64,
+) -> Result<(), crate::errors::AjoError> {
+    if group.state == crate::types::GroupState::Cancelled {
+        return Err(crate::errors::AjoError::GroupCancelled);
+    }
+
+    if group.is_complete {
+        return Err(crate::errors::AjoError::GroupComplete);
+    }
+
+    if group.kind == GroupKind::TargetSavings {
+        if now < get_grace_period_end(group)
+            && (group.catch_up_end == 0 || !all_members_contributed(env, group, claims))
+        {
+            return Err(crate::errors::AjoError::OutsideCycleWindow);
+        }
+        return Ok(());
+    }
+
+    if !all_members_contributed(env, group, claims) {
+        return Err(crate::errors::AjoError::IncompleteContributions);
+    }
+
+    // A tiered payout may need surplus carried from earlier cycles
+    let payouts: i128 = cycle_recipients(group)
+        .map(|seat| calculate_payout_amount(group, seat))
+        .sum();
+    if payouts > cycle_pot(group) + group.pot_surplus {
+        return Err(crate::errors::AjoError::InsufficientBalance);
+    }
+
+    // Still within grace period - delay payout
+    if now < get_grace_period_end(group) && group.catch_up_end == 0 {
+        return Err(crate::errors::AjoError::OutsideCycleWindow);
+    }
+
+    Ok(())
+}
+
+/// Returns the number of cycles the group runs.
+///
+/// A rotating group runs one cycle per `recipients_per_cycle` seats, rounded up; a
+/// target savings group runs its configured number of savings cycles.
+///
+/// # Arguments
+/// * `group` - The group
+pub fn total_cycles(group: &Group) -> u32 {
+    match group.kind {
+        GroupKind::Rotating => group.members.len().div_ceil(group.recipients_per_cycle),
+        GroupKind::TargetSavings => group.savings_cycles,
+    }
+}
+
+/// Returns the most cycles the group can run, before every seat is taken.
+///
+/// # Arguments
+/// * `group` - The group
+pub fn max_cycles(group: &Group) -> u32 {
+    match group.kind {
+        GroupKind::Rotating => group.max_members.div_ceil(group.recipients_per_cycle),
+        GroupKind::TargetSavings => group.savings_cycles,
+    }
+}
+
+/// Calculates the payout amount for the seat at `seat`.
 ///
-/// The payout equals each member's fixed contribution multiplied by the total
-/// number of members. This ensures the recipient receives the full pool of contributions.
+/// The payout equals the seat's contribution multiplied by the total number of
+/// seats, shared by the `recipients_per_cycle` seats paid each cycle, so over the
+/// full rotation every seat receives what it paid in. Without tiers and with one
+/// recipient per cycle this is the full pool of contributions.
 ///
 /// # Arguments
 /// * `group` - The group whose payout is being calculated
+/// * `seat` - The recipient seat's position in the payout order
 ///
 /// # Returns
-/// Total payout in stroops (`contribution_amount × member_count`)
-pub fn calculate_payout_amount(group: &Group) -> i128 {
+/// Payout in stroops (`contribution_amount × tier × member_count / recipients_per_cycle`)
+pub fn calculate_payout_amount(group: &Group, seat: u32) -> i128 {
     let member_count = group.members.len() as i128;
-    group.contribution_amount * member_count
+    seat_contribution(group, seat) * member_count / group.recipients_per_cycle as i128
+}
+
+/// Returns the seats paid in the current cycle.
+///
+/// That is the next `recipients_per_cycle` seats in the payout order, or the
+/// seats left in a short final cycle.
+///
+/// # Arguments
+/// * `group` - The group
+pub fn cycle_recipients(group: &Group) -> core::ops::Range<u32> {
+    let end = group
+        .payout_index
+        .saturating_add(group.recipients_per_cycle)
+        .min(group.members.len());
+    group.payout_index..end.max(group.payout_index)
+}
+
+/// Returns the share of `amount` for the recipient at `index` out of `count`.
+///
+/// Any remainder from integer division goes to the first recipient.
+///
+/// # Arguments
+/// * `amount` - The amount being shared
+/// * `count` - Number of recipients
+/// * `index` - The recipient's position
+pub fn even_share(amount: i128, count: u32, index: u32) -> i128 {
+    let share = amount / count as i128;
+    if index == 0 {
+        amount - share * (count as i128 - 1)
+    } else {
+        share
+    }
+}
+
+/// Checks that every remaining payout in the rotation can be funded.
+///
+/// Walks the payout order from the current recipients, adding each cycle's pot to
+/// the group's surplus and taking out that cycle's payouts. Tiered groups must
+/// order their seats so the surplus never runs out; untiered groups always pass.
+///
+/// # Arguments
+/// * `group` - The group, with its current payout order and tiers
+///
+/// # Errors
+/// * `InsufficientBalance` - if some cycle's payout is larger than the pot and surplus
+pub fn validate_tier_funding(group: &Group) -> Result<(), crate::errors::AjoError> {
+    if group.kind == GroupKind::TargetSavings {
+        return Ok(());
+    }
+
+    let pot = cycle_pot(group);
+    let mut surplus = group.pot_surplus;
+    let mut next = group.clone();
+    while next.payout_index < next.members.len() {
+        surplus += pot;
+        for seat in cycle_recipients(&next) {
+            surplus -= calculate_payout_amount(&next, seat);
+        }
+        if surplus < 0 {
+            return Err(crate::errors::AjoError::InsufficientBalance);
+        }
+        next.payout_index += next.recipients_per_cycle;
+    }
+    Ok(())
+}
+
+/// Calculates the organizer commission taken from a payout.
+///
+/// Basis-point commissions are taken from `base_payout`; fixed commissions are
+/// capped at `base_payout` so the commission can never exceed the pot.
+///
+/// # Arguments
+/// * `commission` - The group's commission terms
+/// * `base_payout` - The payout before penalties and commission
+///
+/// # Returns
+/// Commission amount in stroops
+pub fn calculate_commission(commission: &OrganizerCommission, base_payout: i128) -> i128 {
+    match commission {
+        OrganizerCommission::None => 0,
+        OrganizerCommission::BasisPoints(bps) => apply_bps(base_payout, *bps),
+        OrganizerCommission::Fixed(amount) => (*amount).min(base_payout),
+    }
+}
+
+/// Validates organizer commission terms.
+///
+/// # Arguments
+/// * `commission` - The proposed commission terms
+///
+/// # Returns
+/// `Ok(())` if valid
+///
+/// # Errors
+/// * `InvalidCommission` - if basis points exceed [`MAX_COMMISSION_BPS`] or a fixed amount is negative
+pub fn validate_commission(commission: &OrganizerCommission) -> Result<(), crate::errors::AjoError> {
+    match commission {
+        OrganizerCommission::None => Ok(()),
+        OrganizerCommission::BasisPoints(bps) if *bps <= MAX_COMMISSION_BPS => Ok(()),
+        OrganizerCommission::Fixed(amount) if *amount >= 0 => Ok(()),
+        _ => Err(crate::errors::AjoError::InvalidCommission),
+    }
+}
+
+/// Returns the terms `create_group` gives a group, with the given parameters.
+///
+/// # Arguments
+/// * `contribution_amount` - Contribution per member per cycle, in stroops
+/// * `cycle_duration` - Duration of each cycle in seconds
+/// * `max_members` - Maximum number of members
+/// * `grace_period` - Grace period after each cycle ends, in seconds
+/// * `penalty_rate` - Late penalty rate as a percentage
+pub fn default_terms(
+    contribution_amount: i128,
+    cycle_duration: u64,
+    max_members: u32,
+    grace_period: u64,
+    penalty_rate: u32,
+) -> GroupTerms {
+    GroupTerms {
+        contribution_amount,
+        cycle_duration,
+        contribution_interval: 0,
+        max_members,
+        grace_period,
+        penalty_rate,
+        commission: OrganizerCommission::None,
+        penalty_policy: PenaltyPolicy::NextRecipient,
+        penalty_schedule: PenaltySchedule::Flat,
+        cycle_schedule: CycleSchedule::Rolling,
+        keeper_bounty: 0,
+        token: None,
+        seat_transfer_needs_approval: false,
+        max_seats_per_member: 1,
+        max_contribution_tier: 1,
+        recipients_per_cycle: 1,
+        insurance_premium_bps: 0,
+        vault: None,
+        yield_policy: YieldPolicy::Recipient,
+        kind: GroupKind::Rotating,
+        savings_cycles: 0,
+        early_withdrawal_bps: 0,
+        shuffle_on_renewal: false,
+    }
+}
+
+/// Returns the terms of an existing group.
+///
+/// # Arguments
+/// * `group` - The group whose terms are returned
+pub fn group_terms(group: &Group) -> GroupTerms {
+    GroupTerms {
+        contribution_amount: group.contribution_amount,
+        cycle_duration: group.cycle_duration,
+        contribution_interval: group.contribution_interval,
+        max_members: group.max_members,
+        grace_period: group.grace_period,
+        penalty_rate: group.penalty_rate,
+        commission: group.commission.clone(),
+        penalty_policy: group.penalty_policy,
+        penalty_schedule: group.penalty_schedule.clone(),
+        cycle_schedule: group.cycle_schedule.clone(),
+        keeper_bounty: group.keeper_bounty,
+        token: group.token.clone(),
+        seat_transfer_needs_approval: group.seat_transfer_needs_approval,
+        max_seats_per_member: group.max_seats_per_member,
+        max_contribution_tier: group.max_contribution_tier,
+        recipients_per_cycle: group.recipients_per_cycle,
+        insurance_premium_bps: group.insurance_premium_bps,
+        vault: group.vault.clone(),
+        yield_policy: group.yield_policy,
+        kind: group.kind,
+        savings_cycles: group.savings_cycles,
+        early_withdrawal_bps: group.early_withdrawal_bps,
+        shuffle_on_renewal: group.shuffle_on_renewal,
+    }
+}
+
+/// Validates a full set of group terms.
+///
+/// Both `create_group_with_terms` and the term setters (through
+/// [`update_terms`]) check terms here, so a group created with these terms is one
+/// the creator could have configured term by term.
+///
+/// # Arguments
+/// * `terms` - The proposed terms
+///
+/// # Returns
+/// `Ok(())` if valid
+///
+/// # Errors
+/// Any error of [`validate_group_params`], [`validate_penalty_params`],
+/// [`validate_intervals`], [`validate_commission`], [`validate_penalty_schedule`]
+/// and [`validate_cycle_schedule`], and:
+/// * `InvalidGroupTerms` - if the keeper bounty is negative, the seat limit or
+///   recipients per cycle is zero or exceeds `max_members`, the tier limit is zero or
+///   exceeds [`MAX_CONTRIBUTION_TIER`], or the premium exceeds
+///   [`MAX_INSURANCE_PREMIUM_BPS`]
+/// * `NoContributionToken` - if there is an insurance premium or `InsurancePool`
+///   penalty policy without a token
+/// * `InvalidCycleSchedule` - if a target savings group runs zero or more than
+///   [`MAX_SAVINGS_CYCLES`] cycles, or a rotating group has savings cycles
+/// * `InvalidPenaltyRate` - if the early withdrawal rate exceeds [`BPS_DENOMINATOR`]
+pub fn validate_group_terms(terms: &GroupTerms) -> Result<(), crate::errors::AjoError> {
+    use crate::errors::AjoError;
+
+    validate_group_params(terms.contribution_amount, terms.cycle_duration, terms.max_members)?;
+    validate_penalty_params(terms.grace_period, terms.penalty_rate)?;
+    if terms.contribution_interval != 0 {
+        validate_intervals(terms.contribution_interval, terms.cycle_duration)?;
+    }
+    validate_commission(&terms.commission)?;
+    validate_penalty_schedule(&terms.penalty_schedule)?;
+    if terms.keeper_bounty < 0
+        || !(1..=terms.max_members).contains(&terms.max_seats_per_member)
+        || !(1..=terms.max_members).contains(&terms.recipients_per_cycle)
+        || !(1..=MAX_CONTRIBUTION_TIER).contains(&terms.max_contribution_tier)
+    {
+        return Err(AjoError::InvalidGroupTerms);
+    }
+
+    if terms.token.is_none()
+        && (terms.insurance_premium_bps != 0 || terms.penalty_policy == PenaltyPolicy::InsurancePool)
+    {
+        return Err(AjoError::NoContributionToken);
+    }
+    if terms.insurance_premium_bps > MAX_INSURANCE_PREMIUM_BPS {
+        return Err(AjoError::InvalidGroupTerms);
+    }
+
+    let cycles = match terms.kind {
+        GroupKind::Rotating if terms.savings_cycles != 0 => {
+            return Err(AjoError::InvalidCycleSchedule);
+        }
+        GroupKind::Rotating => terms.max_members.div_ceil(terms.recipients_per_cycle),
+        GroupKind::TargetSavings => {
+            if terms.savings_cycles == 0 || terms.savings_cycles > MAX_SAVINGS_CYCLES {
+                return Err(AjoError::InvalidCycleSchedule);
+            }
+            terms.savings_cycles
+        }
+    };
+    if terms.early_withdrawal_bps > BPS_DENOMINATOR {
+        return Err(AjoError::InvalidPenaltyRate);
+    }
+    validate_cycle_schedule(&terms.cycle_schedule, cycles)
+}
+
+/// Builds a new group with the given terms.
+///
+/// The group starts its first cycle at `now` with the given seats, or at the
+/// first start time of a calendar schedule.
+///
+/// # Arguments
+/// * `terms` - The group's terms
+/// * `group_id` - ID of the new group
+/// * `creator` - Creator of the new group
+/// * `members` - The new group's seats, in payout order
+/// * `seat_tiers` - Contribution tier of each seat
+/// * `now` - Current ledger timestamp
+pub fn new_group(
+    terms: &GroupTerms,
+    group_id: u64,
+    creator: Address,
+    members: Vec<Address>,
+    seat_tiers: Vec<u32>,
+    now: u64,
+) -> Group {
+    let terms = terms.clone();
+    let mut group = Group {
+        id: group_id,
+        creator,
+        contribution_amount: terms.contribution_amount,
+        cycle_duration: terms.cycle_duration,
+        contribution_interval: terms.contribution_interval,
+        max_members: terms.max_members,
+        members,
+        current_cycle: 1,
+        payout_index: 0,
+        created_at: now,
+        cycle_start_time: now,
+        is_complete: false,
+        grace_period: terms.grace_period,
+        penalty_rate: terms.penalty_rate,
+        state: GroupState::Active,
+        commission: terms.commission,
+        penalty_policy: terms.penalty_policy,
+        penalty_schedule: terms.penalty_schedule,
+        cycle_schedule: terms.cycle_schedule,
+        catch_up_end: 0,
+        keeper_bounty: terms.keeper_bounty,
+        token: terms.token,
+        seat_transfer_needs_approval: terms.seat_transfer_needs_approval,
+        max_seats_per_member: terms.max_seats_per_member,
+        max_contribution_tier: terms.max_contribution_tier,
+        seat_tiers,
+        pot_surplus: 0,
+        recipients_per_cycle: terms.recipients_per_cycle,
+        insurance_premium_bps: terms.insurance_premium_bps,
+        vault: terms.vault,
+        yield_policy: terms.yield_policy,
+        kind: terms.kind,
+        savings_cycles: terms.savings_cycles,
+        early_withdrawal_bps: terms.early_withdrawal_bps,
+        previous_group_id: None,
+        next_group_id: None,
+        shuffle_on_renewal: terms.shuffle_on_renewal,
+    };
+    group.cycle_start_time = get_scheduled_cycle_window(&group, 1).0;
+    group
+}
+
+/// Builds a new group with the same terms as an existing one.
+///
+/// Every setting is copied, and the new group starts its first cycle at `now` with
+/// the given seats. A calendar schedule belongs to the original group's dates, so
+/// the new group uses a rolling one instead.
+///
+/// # Arguments
+/// * `group` - The group whose terms are copied
+/// * `group_id` - ID of the new group
+/// * `creator` - Creator of the new group
+/// * `members` - The new group's seats, in payout order
+/// * `seat_tiers` - Contribution tier of each seat
+/// * `now` - Current ledger timestamp
+pub fn copy_group_terms(
+    group: &Group,
+    group_id: u64,
+    creator: Address,
+    members: Vec<Address>,
+    seat_tiers: Vec<u32>,
+    now: u64,
+) -> Group {
+    let mut terms = group_terms(group);
+    if let CycleSchedule::Calendar(_) = terms.cycle_schedule {
+        terms.cycle_schedule = CycleSchedule::Rolling;
+    }
+    new_group(&terms, group_id, creator, members, seat_tiers, now)
+}
+
+/// Validates the length of each metadata field.
+///
+/// # Errors
+/// * `MetadataTooLong` - if any field exceeds its length limit
+pub fn validate_metadata(metadata: &GroupMetadata) -> Result<(), crate::errors::AjoError> {
+    if metadata.name.len() > MAX_NAME_LENGTH
+        || metadata.description.len() > MAX_DESCRIPTION_LENGTH
+        || metadata.rules.len() > MAX_RULES_LENGTH
+    {
+        return Err(crate::errors::AjoError::MetadataTooLong);
+    }
+    Ok(())
+}
+
+/// Returns `Ok(())` if the group's terms can still be changed by the creator.
+///
+/// Terms are locked as soon as anyone other than the creator joins (or a payout
+/// has been made), so every member joins on the terms disclosed to them.
+///
+/// # Arguments
+/// * `group` - The group being reconfigured
+///
+/// # Errors
+/// * `GroupTermsLocked` - if another member has already joined
+pub fn ensure_terms_unlocked(group: &Group) -> Result<(), crate::errors::AjoError> {
+    if group.members.len() > 1 || group.payout_index > 0 {
+        return Err(crate::errors::AjoError::GroupTermsLocked);
+    }
+    Ok(())
+}
+
+/// Replaces the terms of a group whose terms are still unlocked.
+///
+/// Members, cycles and history are kept. The first cycle is rescheduled, since
+/// the new terms may change when it starts.
+///
+/// # Arguments
+/// * `group` - The group being reconfigured
+/// * `terms` - The group's new terms
+pub fn apply_terms(group: &mut Group, terms: GroupTerms) {
+    group.contribution_amount = terms.contribution_amount;
+    group.cycle_duration = terms.cycle_duration;
+    group.contribution_interval = terms.contribution_interval;
+    group.max_members = terms.max_members;
+    group.grace_period = terms.grace_period;
+    group.penalty_rate = terms.penalty_rate;
+    group.commission = terms.commission;
+    group.penalty_policy = terms.penalty_policy;
+    group.penalty_schedule = terms.penalty_schedule;
+    group.cycle_schedule = terms.cycle_schedule;
+    group.keeper_bounty = terms.keeper_bounty;
+    group.token = terms.token;
+    group.seat_transfer_needs_approval = terms.seat_transfer_needs_approval;
+    group.max_seats_per_member = terms.max_seats_per_member;
+    group.max_contribution_tier = terms.max_contribution_tier;
+    group.recipients_per_cycle = terms.recipients_per_cycle;
+    group.insurance_premium_bps = terms.insurance_premium_bps;
+    group.vault = terms.vault;
+    group.yield_policy = terms.yield_policy;
+    group.kind = terms.kind;
+    group.savings_cycles = terms.savings_cycles;
+    group.early_withdrawal_bps = terms.early_withdrawal_bps;
+    group.shuffle_on_renewal = terms.shuffle_on_renewal;
+    group.cycle_start_time = get_scheduled_cycle_window(group, 1).0;
+}
+
+/// Changes a group's terms on behalf of its creator.
+///
+/// Shared by the term setters: checks the contract isn't paused, authenticates the
+/// creator and checks the terms are still unlocked, then applies `change` and
+/// validates the resulting terms as a whole before storing them, so a setter
+/// accepts exactly the terms `create_group_with_terms` would.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `group_id` - The group being reconfigured
+/// * `change` - Updates the terms, failing on arguments its setter rejects outright
+///
+/// # Returns
+/// The updated group
+///
+/// # Errors
+/// * `ContractPaused` - if the contract is paused
+/// * `GroupNotFound` - if the group does not exist
+/// * `GroupTermsLocked` - if another member has already joined
+/// * Any error of `change` or [`validate_group_terms`]
+pub fn update_terms(
+    env: &Env,
+    group_id: u64,
+    change: impl FnOnce(&mut GroupTerms) -> Result<(), crate::errors::AjoError>,
+) -> Result<Group, crate::errors::AjoError> {
+    crate::pausable::ensure_not_paused(env)?;
+
+    let mut group =
+        crate::storage::get_group(env, group_id).ok_or(crate::errors::AjoError::GroupNotFound)?;
+
+    // Require creator authentication
+    group.creator.require_auth();
+
+    ensure_terms_unlocked(&group)?;
+
+    let mut terms = group_terms(&group);
+    change(&mut terms)?;
+    validate_group_terms(&terms)?;
+
+    apply_terms(&mut group, terms);
+    crate::storage::store_group(env, group_id, &group);
+    Ok(group)
 }
 
 /// Returns the current ledger timestamp in seconds since Unix epoch.
@@ -134,8 +856,8 @@ pub fn validate_group_params(
 
 /// Returns the start and end timestamps for the group's current cycle window.
 ///
-/// The cycle window is `[cycle_start_time, cycle_start_time + cycle_duration)`.
-/// This is a pure calculation that does not read the ledger clock.
+/// The cycle window is `[cycle_start_time, cycle_end)`, where the end comes from
+/// [`get_cycle_end`]. This is a pure calculation that does not read the ledger clock.
 ///
 /// # Arguments
 /// * `group` - The group whose cycle window is being computed
@@ -144,9 +866,254 @@ pub fn validate_group_params(
 /// # Returns
 /// A `(start, end)` tuple of Unix timestamps in seconds
 pub fn get_cycle_window(group: &Group, _current_time: u64) -> (u64, u64) {
-    let cycle_start = group.cycle_start_time;
-    let cycle_end = cycle_start + group.cycle_duration;
-    (cycle_start, cycle_end)
+    (group.cycle_start_time, get_cycle_end(group))
+}
+
+/// Returns the timestamp at which the current cycle's contribution window closes.
+///
+/// - A cycle that is catching up ends at `catch_up_end`.
+/// - A calendar cycle ends when the next calendar cycle starts (the last one
+///   lasts `cycle_duration`).
+/// - Otherwise the cycle ends `cycle_duration` after `cycle_start_time`.
+///
+/// # Arguments
+/// * `group` - The group whose cycle end is being computed
+///
+/// # Returns
+/// Unix timestamp when the current cycle ends
+pub fn get_cycle_end(group: &Group) -> u64 {
+    if group.catch_up_end > 0 {
+        return group.catch_up_end;
+    }
+
+    match &group.cycle_schedule {
+        CycleSchedule::Calendar(_) => get_scheduled_cycle_window(group, group.current_cycle).1,
+        _ => group.cycle_start_time + group.cycle_duration,
+    }
+}
+
+/// Returns the scheduled `(start, end)` of a cycle under an anchored or calendar schedule.
+///
+/// Anchored cycle N runs from `created_at + (N - 1) × cycle_duration` for one
+/// `cycle_duration`. Calendar cycle N starts at the N-th timestamp and ends at the
+/// next one, or after `cycle_duration` if it is the last.
+///
+/// # Arguments
+/// * `group` - The group whose schedule is being evaluated
+/// * `cycle` - The 1-based cycle number
+///
+/// # Returns
+/// A `(start, end)` tuple of Unix timestamps in seconds
+pub fn get_scheduled_cycle_window(group: &Group, cycle: u32) -> (u64, u64) {
+    match &group.cycle_schedule {
+        CycleSchedule::Calendar(starts) => {
+            let start = starts.get(cycle - 1).unwrap_or(group.cycle_start_time);
+            let end = starts.get(cycle).unwrap_or(start + group.cycle_duration);
+            (start, end)
+        }
+        _ => {
+            let start = group.created_at + (cycle as u64 - 1) * group.cycle_duration;
+            (start, start + group.cycle_duration)
+        }
+    }
+}
+
+/// Advances the group to its next cycle and sets the new cycle's start time.
+///
+/// Rolling groups start the next cycle now. Anchored and calendar groups start it
+/// at its scheduled time, so late payouts don't shift the schedule. If the next
+/// cycle's scheduled window has already passed, it starts now in catch-up mode
+/// with a window of at most [`CATCH_UP_WINDOW`] so the group can get back on schedule.
+///
+/// # Arguments
+/// * `group` - The group to advance
+/// * `now` - Current ledger timestamp
+///
+/// # Returns
+/// `Some(scheduled_start)` if the new cycle is catching up, `None` otherwise
+pub fn start_next_cycle(group: &mut Group, now: u64) -> Option<u64> {
+    group.current_cycle += 1;
+    group.catch_up_end = 0;
+
+    if group.cycle_schedule == CycleSchedule::Rolling {
+        group.cycle_start_time = now;
+        return None;
+    }
+
+    let (start, end) = get_scheduled_cycle_window(group, group.current_cycle);
+    if end > now {
+        group.cycle_start_time = start;
+        None
+    } else {
+        group.cycle_start_time = now;
+        group.catch_up_end = now + (end - start).min(CATCH_UP_WINDOW);
+        Some(start)
+    }
+}
+
+/// Validates a group's contribution and payout intervals.
+///
+/// # Arguments
+/// * `contribution_interval` - Length of each contribution period in seconds
+/// * `payout_interval` - Length of each cycle in seconds
+///
+/// # Returns
+/// `Ok(())` if valid
+///
+/// # Errors
+/// * `CycleDurationZero` - if either interval is zero
+/// * `InvalidCycleSchedule` - if the contribution interval doesn't evenly divide the
+///   payout interval, or there would be more than [`MAX_CONTRIBUTION_PERIODS`] periods
+pub fn validate_intervals(
+    contribution_interval: u64,
+    payout_interval: u64,
+) -> Result<(), crate::errors::AjoError> {
+    if contribution_interval == 0 || payout_interval == 0 {
+        return Err(crate::errors::AjoError::CycleDurationZero);
+    }
+    if !payout_interval.is_multiple_of(contribution_interval)
+        || payout_interval / contribution_interval > MAX_CONTRIBUTION_PERIODS as u64
+    {
+        return Err(crate::errors::AjoError::InvalidCycleSchedule);
+    }
+    Ok(())
+}
+
+/// Validates a cycle schedule for a group.
+///
+/// # Arguments
+/// * `schedule` - The proposed schedule
+/// * `cycles` - The most cycles the group can run (see [`max_cycles`])
+///
+/// # Returns
+/// `Ok(())` if valid
+///
+/// # Errors
+/// * `InvalidCycleSchedule` - if calendar start times aren't strictly ascending or
+///   there are fewer than `cycles` of them
+pub fn validate_cycle_schedule(
+    schedule: &CycleSchedule,
+    cycles: u32,
+) -> Result<(), crate::errors::AjoError> {
+    if let CycleSchedule::Calendar(starts) = schedule {
+        if starts.len() < cycles {
+            return Err(crate::errors::AjoError::InvalidCycleSchedule);
+        }
+
+        let mut previous: Option<u64> = None;
+        for start in starts.iter() {
+            if previous.is_some_and(|previous| start <= previous) {
+                return Err(crate::errors::AjoError::InvalidCycleSchedule);
+            }
+            previous = Some(start);
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns the number of contribution periods in each cycle.
+///
+/// # Arguments
+/// * `group` - The group
+pub fn contribution_periods(group: &Group) -> u32 {
+    if group.contribution_interval == 0 {
+        return 1;
+    }
+    (group.cycle_duration / group.contribution_interval) as u32
+}
+
+/// Returns the contribution period of the current cycle that `current_time` falls in.
+///
+/// Periods are numbered from 1. Times before the cycle starts fall in the first
+/// period, and times after the last period's deadline in the last.
+///
+/// # Arguments
+/// * `group` - The group
+/// * `current_time` - The timestamp to evaluate
+pub fn current_period(group: &Group, current_time: u64) -> u32 {
+    let periods = contribution_periods(group);
+    if periods == 1 || current_time < group.cycle_start_time {
+        return 1;
+    }
+    let elapsed = (current_time - group.cycle_start_time) / group.contribution_interval;
+    (elapsed as u32 + 1).min(periods)
+}
+
+/// Returns the deadline of contribution period `period` in the current cycle.
+///
+/// The last period always ends with the cycle (see [`get_cycle_end`]).
+///
+/// # Arguments
+/// * `group` - The group
+/// * `period` - The period, numbered from 1
+pub fn get_period_end(group: &Group, period: u32) -> u64 {
+    let cycle_end = get_cycle_end(group);
+    if period >= contribution_periods(group) {
+        return cycle_end;
+    }
+    (group.cycle_start_time + period as u64 * group.contribution_interval).min(cycle_end)
+}
+
+/// Returns how much of a cycle's `obligation` is due by the end of period `period`.
+///
+/// # Arguments
+/// * `group` - The group
+/// * `obligation` - What the member owes for the whole cycle
+/// * `period` - The period, numbered from 1
+pub fn due_by_period(group: &Group, obligation: i128, period: u32) -> i128 {
+    obligation * period as i128 / contribution_periods(group) as i128
+}
+
+/// Checks a payment against the deadlines of the cycle's contribution periods.
+///
+/// With one period per cycle this is [`check_contribution_timing`]. Otherwise the
+/// payment is late if it is made after the deadline of the earliest period the
+/// member hasn't fully paid. Only the part of the payment covering periods whose
+/// deadline has passed is penalized, with lateness measured from that earliest
+/// deadline, and once its grace window has also passed the payment is too late.
+///
+/// # Arguments
+/// * `group` - The group being contributed to
+/// * `current_time` - The timestamp of the payment
+/// * `amount_paid` - What the member has already paid this cycle
+/// * `obligation` - What the member owes for the whole cycle
+/// * `amount` - The amount being paid
+///
+/// # Returns
+/// `(is_late, penalty_amount, is_too_late)` tuple
+pub fn check_period_timing(
+    group: &Group,
+    current_time: u64,
+    amount_paid: i128,
+    obligation: i128,
+    amount: i128,
+) -> (bool, i128, bool) {
+    let periods = contribution_periods(group);
+    if periods == 1 {
+        let (is_late, penalty) = check_contribution_timing(group, current_time, amount);
+        let too_late = is_late && penalty == 0 && !is_within_grace_period(group, current_time);
+        return (is_late, penalty, too_late);
+    }
+
+    let first_unpaid = (1..=periods)
+        .find(|period| due_by_period(group, obligation, *period) > amount_paid)
+        .unwrap_or(periods);
+    let deadline = get_period_end(group, first_unpaid);
+    if current_time < deadline {
+        return (false, 0, false);
+    }
+    if current_time >= deadline + group.grace_period {
+        return (true, 0, true);
+    }
+
+    let passed = (1..=periods)
+        .filter(|period| get_period_end(group, *period) <= current_time)
+        .count() as u32;
+    let arrears = due_by_period(group, obligation, passed) - amount_paid;
+    let penalty =
+        calculate_scheduled_penalty(group, amount.min(arrears), current_time - deadline);
+    (true, penalty, false)
 }
 
 /// Returns `true` if `current_time` falls within the group's active cycle window.
@@ -178,7 +1145,7 @@ pub fn is_within_cycle_window(group: &Group, current_time: u64) -> bool {
 /// # Returns
 /// `true` if current_time is within the grace period, `false` otherwise
 pub fn is_within_grace_period(group: &Group, current_time: u64) -> bool {
-    let cycle_end = group.cycle_start_time + group.cycle_duration;
+    let cycle_end = get_cycle_end(group);
     let grace_end = cycle_end + group.grace_period;
     current_time >= cycle_end && current_time < grace_end
 }
@@ -191,7 +1158,7 @@ pub fn is_within_grace_period(group: &Group, current_time: u64) -> bool {
 /// # Returns
 /// Unix timestamp when grace period ends
 pub fn get_grace_period_end(group: &Group) -> u64 {
-    group.cycle_start_time + group.cycle_duration + group.grace_period
+    get_cycle_end(group) + group.grace_period
 }
 
 /// Calculates the penalty amount for a late contribution.
@@ -210,26 +1177,75 @@ pub fn calculate_penalty(contribution_amount: i128, penalty_rate: u32) -> i128 {
     (contribution_amount * penalty_rate as i128) / 100
 }
 
+/// Applies a rate in basis points to an amount.
+///
+/// # Arguments
+/// * `amount` - The base amount
+/// * `bps` - Rate in basis points (100 = 1%)
+///
+/// # Returns
+/// `amount × bps / 10_000`, rounded down
+pub fn apply_bps(amount: i128, bps: u32) -> i128 {
+    (amount * bps as i128) / BPS_DENOMINATOR as i128
+}
+
+/// Calculates the penalty on `amount` paid `seconds_late` after the cycle ended.
+///
+/// - `Flat` applies `penalty_rate` percent, however late the contribution is.
+/// - `Linear` charges `bps_per_hour` for every started hour late, so a contribution
+///   one minute late is charged for one hour.
+/// - `Stepped` charges the rate of the last tier whose `late_after` has been reached.
+///
+/// Linear and stepped rates are capped at the schedule's `cap_bps`.
+///
+/// # Arguments
+/// * `group` - The group being contributed to
+/// * `amount` - The amount paid late
+/// * `seconds_late` - Seconds elapsed since the cycle ended
+///
+/// # Returns
+/// Penalty amount in stroops
+pub fn calculate_scheduled_penalty(group: &Group, amount: i128, seconds_late: u64) -> i128 {
+    match &group.penalty_schedule {
+        PenaltySchedule::Flat => calculate_penalty(amount, group.penalty_rate),
+        PenaltySchedule::Linear(linear) => {
+            let hours_late = seconds_late / 3600 + 1;
+            let rate = (hours_late * linear.bps_per_hour as u64).min(linear.cap_bps as u64);
+            apply_bps(amount, rate as u32)
+        }
+        PenaltySchedule::Stepped(stepped) => {
+            let mut rate = 0;
+            for tier in stepped.tiers.iter() {
+                if seconds_late >= tier.late_after {
+                    rate = tier.rate_bps;
+                }
+            }
+            apply_bps(amount, rate.min(stepped.cap_bps))
+        }
+    }
+}
+
 /// Checks if a contribution is late and calculates penalty if applicable.
 ///
 /// Returns a tuple of (is_late, penalty_amount).
 /// - If within cycle window: (false, 0)
-/// - If within grace period: (true, calculated_penalty)
+/// - If within grace period: (true, penalty from the group's [`PenaltySchedule`])
 /// - If after grace period: returns error via caller
 ///
 /// # Arguments
 /// * `group` - The group being contributed to
 /// * `current_time` - The timestamp of the contribution
+/// * `amount` - The amount being paid, which late penalties are charged on
 ///
 /// # Returns
 /// `(is_late, penalty_amount)` tuple
-pub fn check_contribution_timing(group: &Group, current_time: u64) -> (bool, i128) {
+pub fn check_contribution_timing(group: &Group, current_time: u64, amount: i128) -> (bool, i128) {
     if is_within_cycle_window(group, current_time) {
         // On time - no penalty
         (false, 0)
     } else if is_within_grace_period(group, current_time) {
-        // Late but within grace period - apply penalty
-        let penalty = calculate_penalty(group.contribution_amount, group.penalty_rate);
+        // Late but within grace period - apply scheduled penalty
+        let penalty = calculate_scheduled_penalty(group, amount, current_time - get_cycle_end(group));
         (true, penalty)
     } else {
         // Too late - caller should return error
@@ -237,6 +1253,49 @@ pub fn check_contribution_timing(group: &Group, current_time: u64) -> (bool, i12
     }
 }
 
+/// Validates a penalty schedule.
+///
+/// # Arguments
+/// * `schedule` - The proposed penalty schedule
+///
+/// # Returns
+/// `Ok(())` if valid
+///
+/// # Errors
+/// * `InvalidGroupTerms` - if a rate or cap exceeds 100%, or stepped tiers are
+///   empty, too many, don't start at 0, or aren't strictly ascending
+pub fn validate_penalty_schedule(
+    schedule: &PenaltySchedule,
+) -> Result<(), crate::errors::AjoError> {
+    let valid = match schedule {
+        PenaltySchedule::Flat => true,
+        PenaltySchedule::Linear(linear) => {
+            linear.bps_per_hour <= BPS_DENOMINATOR && linear.cap_bps <= BPS_DENOMINATOR
+        }
+        PenaltySchedule::Stepped(stepped) => {
+            let mut valid = !stepped.tiers.is_empty()
+                && stepped.tiers.len() <= MAX_PENALTY_TIERS
+                && stepped.cap_bps <= BPS_DENOMINATOR;
+            let mut previous: Option<u64> = None;
+            for tier in stepped.tiers.iter() {
+                let in_order = match previous {
+                    None => tier.late_after == 0,
+                    Some(late_after) => tier.late_after > late_after,
+                };
+                valid = valid && in_order && tier.rate_bps <= BPS_DENOMINATOR;
+                previous = Some(tier.late_after);
+            }
+            valid
+        }
+    };
+
+    if valid {
+        Ok(())
+    } else {
+        Err(crate::errors::AjoError::InvalidGroupTerms)
+    }
+}
+
 /// Validates grace period and penalty rate parameters.
 ///
 /// # Arguments
@@ -266,6 +1325,45 @@ pub fn validate_penalty_params(
     Ok(())
 }
 
+/// Totals everything paid into a group that hasn't been paid out yet, by payer.
+///
+/// Covers each member's current cycle (including installments) plus any cycles
+/// they have prepaid beyond it. Sponsored contributions are credited to the
+/// sponsor, so refunds go back to whoever actually paid. Payers are listed in
+/// the order they are first found, following the member list. A member holding
+/// several seats is counted once, since one record covers all their seats, and
+/// every co-holder of a shared seat is covered.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `group` - The group
+///
+/// # Returns
+/// `(payer, amount)` pairs, one per payer with a non-zero refund
+pub fn collect_refunds(env: &Env, group: &Group) -> Vec<(Address, i128)> {
+    let mut refunds: Vec<(Address, i128)> = Vec::new(env);
+    for member in contributors(env, group).iter() {
+        let prepaid_through = crate::storage::get_prepaid_through(env, group.id, &member);
+        for cycle in group.current_cycle..=group.current_cycle.max(prepaid_through) {
+            let Some(record) = crate::storage::get_contribution_detail(env, group.id, cycle, &member)
+            else {
+                continue;
+            };
+            if record.amount_paid == 0 {
+                continue;
+            }
+            match refunds.iter().position(|(payer, _)| payer == record.payer) {
+                Some(index) => {
+                    let (payer, amount) = refunds.get(index as u32).unwrap();
+                    refunds.set(index as u32, (payer, amount + record.amount_paid));
+                }
+                None => refunds.push_back((record.payer, record.amount_paid)),
+            }
+        }
+    }
+    refunds
+}
+
 /// Updates or creates a member's penalty record after a contribution.
 ///
 /// # Arguments
@@ -301,9 +1399,305 @@ pub fn update_member_penalty_record(
 
     // Calculate reliability score: (on_time / total) * 100
     let total_contributions = record.on_time_count + record.late_count;
-    if total_contributions > 0 {
-        record.reliability_score = (record.on_time_count * 100) / total_contributions;
+    if let Some(score) = (record.on_time_count * 100).checked_div(total_contributions) {
+        record.reliability_score = score;
     }
 
     crate::storage::store_member_penalty(env, group_id, member, &record);
 }
+
+/// Distributes a cycle's penalty pool according to the group's [`PenaltyPolicy`].
+///
+/// Returns the part of the pool that should be added to the payout recipient's
+/// amount. Only [`PenaltyPolicy::NextRecipient`] returns a non-zero bonus; every
+/// other policy credits the penalties elsewhere and emits a distribution event.
+///
+/// If the policy is [`PenaltyPolicy::OnTimeContributors`] and nobody paid on time,
+/// the penalties are held in the group reserve instead.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `group` - The group whose cycle is being paid out
+/// * `cycle` - The cycle the penalties were collected in
+/// * `pool` - Total penalties collected in the cycle
+///
+/// # Returns
+/// Penalty bonus for the payout recipient
+pub fn distribute_cycle_penalties(env: &Env, group: &Group, cycle: u32, pool: i128) -> i128 {
+    if pool <= 0 {
+        return 0;
+    }
+
+    match group.penalty_policy {
+        PenaltyPolicy::NextRecipient => pool,
+        PenaltyPolicy::OnTimeContributors => {
+            let mut on_time = Vec::new(env);
+            for member in contributors(env, group).iter() {
+                if let Some(record) =
+                    crate::storage::get_contribution_detail(env, group.id, cycle, &member)
+                {
+                    if !record.is_late {
+                        on_time.push_back(member);
+                    }
+                }
+            }
+
+            if on_time.is_empty() {
+                add_to_group_reserve(env, group.id, cycle, pool);
+            } else {
+                split_penalties(env, group.id, cycle, &on_time, pool);
+            }
+            0
+        }
+        PenaltyPolicy::GroupReserve => {
+            add_to_group_reserve(env, group.id, cycle, pool);
+            0
+        }
+        PenaltyPolicy::InsurancePool => {
+            // Only groups with a token can choose this policy
+            let Some(token) = &group.token else {
+                return pool;
+            };
+            crate::storage::add_to_insurance_fund(env, token, pool);
+            events::emit_penalty_pooled(env, group.id, cycle, PenaltyPolicy::InsurancePool, pool);
+            0
+        }
+        PenaltyPolicy::Treasury => {
+            crate::storage::add_to_treasury(env, pool);
+            events::emit_penalty_pooled(env, group.id, cycle, PenaltyPolicy::Treasury, pool);
+            0
+        }
+    }
+}
+
+/// Splits a group's penalty reserve equally among all members.
+///
+/// Called when the group completes. The reserve is emptied afterwards. A target
+/// savings group splits it among the savers who haven't withdrawn early, and a
+/// negative reserve (a vault loss) is deducted from their shares the same way.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `group` - The group that has just completed
+pub fn release_group_reserve(env: &Env, group: &Group) {
+    let reserve = crate::storage::get_group_reserve(env, group.id);
+    if group.kind == GroupKind::TargetSavings {
+        let savers = active_savers(env, group);
+        if reserve == 0 || savers.is_empty() {
+            return;
+        }
+        if reserve > 0 {
+            split_penalties(env, group.id, group.current_cycle, &savers, reserve);
+        } else {
+            let count = savers.len() as i128;
+            let mut remainder = reserve - reserve / count * count;
+            for saver in savers.iter() {
+                crate::storage::add_penalty_earnings(env, group.id, &saver, reserve / count + remainder);
+                remainder = 0;
+            }
+        }
+        crate::storage::store_group_reserve(env, group.id, 0);
+        return;
+    }
+
+    if reserve > 0 {
+        split_penalties(env, group.id, group.current_cycle, &group.members, reserve);
+        crate::storage::store_group_reserve(env, group.id, 0);
+    }
+}
+
+/// Adds penalties to the group reserve and emits the pooled event.
+pub fn add_to_group_reserve(env: &Env, group_id: u64, cycle: u32, amount: i128) {
+    let reserve = crate::storage::get_group_reserve(env, group_id);
+    crate::storage::store_group_reserve(env, group_id, reserve + amount);
+    events::emit_penalty_pooled(env, group_id, cycle, PenaltyPolicy::GroupReserve, amount);
+}
+
+/// Validates a member's payout splits.
+///
+/// An empty list is valid and means the payout goes to the member.
+///
+/// # Arguments
+/// * `splits` - The proposed payout splits
+///
+/// # Returns
+/// `Ok(())` if valid
+///
+/// # Errors
+/// * `InvalidPayoutSplit` - if there are more than [`MAX_PAYOUT_SPLITS`] splits, a
+///   split has a zero share, or the shares don't total 100%
+pub fn validate_payout_splits(splits: &Vec<PayoutSplit>) -> Result<(), crate::errors::AjoError> {
+    if splits.is_empty() {
+        return Ok(());
+    }
+
+    let mut total: u32 = 0;
+    for split in splits.iter() {
+        if split.bps == 0 {
+            return Err(crate::errors::AjoError::InvalidPayoutSplit);
+        }
+        total = total.saturating_add(split.bps);
+    }
+
+    if splits.len() > MAX_PAYOUT_SPLITS || total != BPS_DENOMINATOR {
+        return Err(crate::errors::AjoError::InvalidPayoutSplit);
+    }
+
+    Ok(())
+}
+
+/// Returns `true` once a member's payout cycle has started (or they've been paid).
+///
+/// Members are paid in join order, so a member's payout cycle starts when their
+/// position is among the current cycle's recipients. For a member holding several
+/// seats, this is their first seat.
+///
+/// # Arguments
+/// * `group` - The group
+/// * `member` - The member's address
+pub fn has_payout_cycle_started(group: &Group, member: &Address) -> bool {
+    match group.members.first_index_of(member) {
+        Some(index) => index < cycle_recipients(group).end,
+        None => false,
+    }
+}
+
+/// Returns the payout positions of two members who may swap turns.
+///
+/// Both must be different members who are paid in a later cycle than the current
+/// one, so neither has been paid or is the current recipient. A member holding
+/// several seats swaps their next seat still waiting to be paid.
+///
+/// # Arguments
+/// * `group` - The group
+/// * `from` - The member proposing the swap
+/// * `to` - The member asked to swap
+///
+/// # Returns
+/// `(from_index, to_index)` positions in the payout order
+///
+/// # Errors
+/// * `GroupCancelled` - if the group has been cancelled
+/// * `GroupComplete` - if every member has been paid
+/// * `NotMember` - if either address isn't a member, or both are the same member
+/// * `AlreadyReceivedPayout` - if either member has been paid or is the current recipient
+pub fn get_swap_positions(
+    group: &Group,
+    from: &Address,
+    to: &Address,
+) -> Result<(u32, u32), crate::errors::AjoError> {
+    if group.state == crate::types::GroupState::Cancelled {
+        return Err(crate::errors::AjoError::GroupCancelled);
+    }
+    if group.is_complete {
+        return Err(crate::errors::AjoError::GroupComplete);
+    }
+
+    if from == to || !is_member(&group.members, from) || !is_member(&group.members, to) {
+        return Err(crate::errors::AjoError::NotMember);
+    }
+
+    let upcoming_seat = |member: &Address| {
+        (cycle_recipients(group).end..group.members.len())
+            .find(|index| group.members.get_unchecked(*index) == *member)
+    };
+    let (Some(from_index), Some(to_index)) = (upcoming_seat(from), upcoming_seat(to)) else {
+        return Err(crate::errors::AjoError::AlreadyReceivedPayout);
+    };
+
+    Ok((from_index, to_index))
+}
+
+/// Splits a net payout across the member's chosen addresses.
+///
+/// Any remainder from rounding goes to the first address so the full amount is
+/// always paid out. Without splits the whole amount goes to the member.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `member` - The member being paid
+/// * `splits` - The member's payout splits (may be empty)
+/// * `amount` - The net payout
+///
+/// # Returns
+/// The amount sent to each address
+pub fn split_payout(
+    env: &Env,
+    member: &Address,
+    splits: &Vec<PayoutSplit>,
+    amount: i128,
+) -> Vec<PayoutShare> {
+    let mut shares = Vec::new(env);
+    if splits.is_empty() {
+        shares.push_back(PayoutShare { recipient: member.clone(), amount });
+        return shares;
+    }
+
+    let mut remainder = amount;
+    for split in splits.iter() {
+        let share = apply_bps(amount, split.bps);
+        remainder -= share;
+        shares.push_back(PayoutShare { recipient: split.recipient, amount: share });
+    }
+
+    let mut first = shares.get(0).unwrap();
+    first.amount += remainder;
+    shares.set(0, first);
+    shares
+}
+
+/// Splits a shared seat's net payout among its co-holders.
+///
+/// Each co-holder receives the same proportion of the payout as their share of
+/// the seat's contribution (see [`seat_share`]). Any remainder from rounding goes
+/// to the first holder so the full amount is always paid out.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `group` - The group
+/// * `holders` - The seat's co-holders
+/// * `amount` - The net payout
+///
+/// # Returns
+/// The amount sent to each co-holder
+pub fn split_shared_payout(
+    env: &Env,
+    group: &Group,
+    holders: &Vec<Address>,
+    amount: i128,
+) -> Vec<PayoutShare> {
+    let seat = group.members.first_index_of(holders.get_unchecked(0)).unwrap_or(0);
+    let seat_amount = seat_contribution(group, seat);
+    let mut shares = Vec::new(env);
+    let mut remainder = amount;
+    for (index, holder) in holders.iter().enumerate() {
+        let share = amount * seat_share(group, holders, index as u32) / seat_amount;
+        remainder -= share;
+        shares.push_back(PayoutShare { recipient: holder, amount: share });
+    }
+
+    let mut first = shares.get(0).unwrap();
+    first.amount += remainder;
+    shares.set(0, first);
+    shares
+}
+
+/// Splits `amount` equally among `recipients`, crediting each share.
+///
+/// Any remainder from integer division goes to the first recipient so the
+/// full amount is always distributed.
+fn split_penalties(env: &Env, group_id: u64, cycle: u32, recipients: &Vec<Address>, amount: i128) {
+    let count = recipients.len() as i128;
+    let share = amount / count;
+    let mut remainder = amount - share * count;
+
+    for member in recipients.iter() {
+        let member_share = share + remainder;
+        remainder = 0;
+
+        if member_share > 0 {
+            crate::storage::add_penalty_earnings(env, group_id, &member, member_share);
+            events::emit_penalty_share(env, group_id, &member, cycle, member_share);
+        }
+    }
+}
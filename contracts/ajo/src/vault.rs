@@ -0,0 +1,146 @@
+use soroban_sdk::{contractclient, Address, Env};
+
+use crate::errors::AjoError;
+use crate::events;
+use crate::storage;
+use crate::types::{Group, VaultPosition, YieldPolicy};
+
+/// Interface a yield vault must implement to hold a group's idle funds.
+///
+/// Balances are tracked per depositor. The Ajo contract deposits and withdraws as
+/// itself for every group using the vault, and tracks each group's share of its
+/// balance.
+#[contractclient(name = "VaultClient")]
+pub trait Vault {
+    /// Deposit `amount` from `from` into the vault.
+    fn deposit(env: Env, from: Address, amount: i128);
+
+    /// Withdraw up to `amount` to `to`, returning the amount actually withdrawn.
+    fn withdraw(env: Env, to: Address, amount: i128) -> i128;
+
+    /// Current value held for `owner`, including any yield or losses.
+    fn balance(env: Env, owner: Address) -> i128;
+}
+
+/// Deposits a contribution payment into the group's vault.
+///
+/// The group is credited with vault shares in proportion to the vault's current
+/// balance, so yield already earned by other groups isn't diluted. A deposit the
+/// vault rejects is skipped with a `vaultfail` event and the funds stay in the
+/// contract, so a broken vault never blocks contributions.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `group` - The group
+/// * `cycle` - The cycle (or first prepaid cycle) the payment is for
+/// * `amount` - The contribution payment
+pub fn deposit(env: &Env, group: &Group, cycle: u32, amount: i128) {
+    let Some(vault) = &group.vault else {
+        return;
+    };
+    if amount <= 0 {
+        return;
+    }
+
+    let contract = env.current_contract_address();
+    let client = VaultClient::new(env, vault);
+    let balance = match client.try_balance(&contract) {
+        Ok(Ok(balance)) => balance,
+        _ => {
+            events::emit_vault_deposit_failed(env, group.id, cycle, amount);
+            return;
+        }
+    };
+    if !matches!(client.try_deposit(&contract, &amount), Ok(Ok(()))) {
+        events::emit_vault_deposit_failed(env, group.id, cycle, amount);
+        return;
+    }
+
+    let total_shares = storage::get_vault_shares(env, vault);
+    let shares = if total_shares == 0 || balance <= 0 {
+        amount
+    } else {
+        amount * total_shares / balance
+    };
+    storage::store_vault_shares(env, vault, total_shares + shares);
+
+    let mut position = storage::get_vault_position(env, group.id).unwrap_or(VaultPosition {
+        vault: vault.clone(),
+        shares: 0,
+        principal: 0,
+    });
+    position.shares += shares;
+    position.principal += amount;
+    storage::store_vault_position(env, group.id, &position);
+
+    events::emit_vault_deposit(env, group.id, cycle, amount);
+}
+
+/// Withdraws everything the group holds in its vault.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `group` - The group
+///
+/// # Returns
+/// The yield earned over the amount deposited; negative if the vault lost money
+///
+/// # Errors
+/// * `TransferFailed` - If the vault can't report its balance or rejects the withdrawal
+pub fn withdraw(env: &Env, group: &Group) -> Result<i128, AjoError> {
+    let Some(position) = storage::get_vault_position(env, group.id) else {
+        return Ok(0);
+    };
+
+    let contract = env.current_contract_address();
+    let client = VaultClient::new(env, &position.vault);
+    let balance = match client.try_balance(&contract) {
+        Ok(Ok(balance)) => balance,
+        _ => return Err(AjoError::TransferFailed),
+    };
+
+    let total_shares = storage::get_vault_shares(env, &position.vault);
+    let value = if total_shares == 0 {
+        0
+    } else {
+        balance.max(0) * position.shares / total_shares
+    };
+    let withdrawn = match client.try_withdraw(&contract, &value) {
+        Ok(Ok(withdrawn)) => withdrawn,
+        _ => return Err(AjoError::TransferFailed),
+    };
+
+    storage::store_vault_shares(env, &position.vault, total_shares - position.shares);
+    storage::remove_vault_position(env, group.id);
+
+    let vault_yield = withdrawn - position.principal;
+    events::emit_vault_withdrawal(env, group.id, group.current_cycle, withdrawn, vault_yield);
+
+    Ok(vault_yield)
+}
+
+/// Applies the group's yield policy to the yield withdrawn before a payout.
+///
+/// Losses always come out of the payout, since the pot itself is smaller.
+///
+/// # Arguments
+/// * `env` - The contract environment
+/// * `group` - The group being paid out
+/// * `vault_yield` - Yield from [`withdraw`], negative for a loss
+///
+/// # Returns
+/// The amount to add to the payout (negative to deduct a loss)
+pub fn distribute_yield(env: &Env, group: &Group, vault_yield: i128) -> i128 {
+    if vault_yield <= 0 {
+        return vault_yield;
+    }
+
+    match group.yield_policy {
+        YieldPolicy::Recipient => vault_yield,
+        YieldPolicy::GroupReserve => {
+            let reserve = storage::get_group_reserve(env, group.id);
+            storage::store_group_reserve(env, group.id, reserve + vault_yield);
+            0
+        }
+    }
+}
@@ -21,7 +21,7 @@ fn setup_test_env() -> (Env, AjoContractClient<'static>, Address, Address, Addre
 
 #[test]
 fn test_create_group() {
-    let (env, client, creator, _, _) = setup_test_env();
+    let (_env, client, creator, _, _) = setup_test_env();
 
     let contribution = 100_000_000i128; // 10 XLM
     let cycle_duration = 604_800u64; // 1 week in seconds
@@ -43,14 +43,14 @@ fn test_create_group() {
     assert_eq!(group.members.len(), 1);
     assert_eq!(group.current_cycle, 1);
     assert_eq!(group.payout_index, 0);
-    assert_eq!(group.is_complete, false);
+    assert!(!group.is_complete);
     assert_eq!(group.grace_period, grace_period);
     assert_eq!(group.penalty_rate, penalty_rate);
 }
 
 #[test]
 fn test_join_group() {
-    let (env, client, creator, member2, member3) = setup_test_env();
+    let (_env, client, creator, member2, member3) = setup_test_env();
 
     // Create group
     let group_id = client.create_group(&creator, &100_000_000i128, &604_800u64, &10u32, &86400u64, &5u32);
@@ -66,15 +66,15 @@ fn test_join_group() {
     assert_eq!(members.len(), 3);
 
     // Verify is_member checks
-    assert_eq!(client.is_member(&group_id, &creator), true);
-    assert_eq!(client.is_member(&group_id, &member2), true);
-    assert_eq!(client.is_member(&group_id, &member3), true);
+    assert!(client.is_member(&group_id, &creator));
+    assert!(client.is_member(&group_id, &member2));
+    assert!(client.is_member(&group_id, &member3));
 }
 
 #[test]
 #[should_panic]
 fn test_join_group_already_member() {
-    let (env, client, creator, _, _) = setup_test_env();
+    let (_env, client, creator, _, _) = setup_test_env();
 
     // Create group (creator is automatically a member)
     let group_id = client.create_group(&creator, &100_000_000i128, &604_800u64, &10u32, &86400u64, &5u32);
@@ -101,7 +101,7 @@ fn test_join_group_full() {
 
 #[test]
 fn test_contribution_flow() {
-    let (env, client, creator, member2, member3) = setup_test_env();
+    let (_env, client, creator, member2, member3) = setup_test_env();
 
     // Create group with 3 members max
     let group_id = client.create_group(&creator, &100_000_000i128, &604_800u64, &3u32, &86400u64, &5u32);
@@ -119,14 +119,14 @@ fn test_contribution_flow() {
 
     // All should have contributed
     for (_, has_paid) in status.iter() {
-        assert_eq!(has_paid, true);
+        assert!(has_paid);
     }
 }
 
 #[test]
 #[should_panic]
 fn test_double_contribution() {
-    let (env, client, creator, _, _) = setup_test_env();
+    let (_env, client, creator, _, _) = setup_test_env();
 
     let group_id = client.create_group(&creator, &100_000_000i128, &604_800u64, &3u32, &86400u64, &5u32);
 
@@ -140,7 +140,7 @@ fn test_double_contribution() {
 #[test]
 #[should_panic]
 fn test_payout_incomplete_contributions() {
-    let (env, client, creator, member2, _) = setup_test_env();
+    let (_env, client, creator, member2, _) = setup_test_env();
 
     // Create group with 2 members
     let group_id = client.create_group(&creator, &100_000_000i128, &604_800u64, &3u32, &86400u64, &5u32);
@@ -178,7 +178,7 @@ fn test_payout_execution() {
     let group = client.get_group(&group_id);
     assert_eq!(group.current_cycle, 2);
     assert_eq!(group.payout_index, 1);
-    assert_eq!(group.is_complete, false);
+    assert!(!group.is_complete);
 }
 
 #[test]
@@ -191,7 +191,7 @@ fn test_full_lifecycle() {
     client.join_group(&member3, &group_id);
 
     // Verify not complete initially
-    assert_eq!(client.is_complete(&group_id), false);
+    assert!(!client.is_complete(&group_id));
 
     // Cycle 1: Creator receives payout
     client.contribute(&creator, &group_id);
@@ -204,7 +204,7 @@ fn test_full_lifecycle() {
     });
     
     client.execute_payout(&group_id);
-    assert_eq!(client.is_complete(&group_id), false);
+    assert!(!client.is_complete(&group_id));
 
     // Cycle 2: Member 2 receives payout
     client.contribute(&creator, &group_id);
@@ -217,7 +217,7 @@ fn test_full_lifecycle() {
     });
     
     client.execute_payout(&group_id);
-    assert_eq!(client.is_complete(&group_id), false);
+    assert!(!client.is_complete(&group_id));
 
     // Cycle 3: Member 3 receives payout (final)
     client.contribute(&creator, &group_id);
@@ -232,17 +232,17 @@ fn test_full_lifecycle() {
     client.execute_payout(&group_id);
 
     // Group should now be complete
-    assert_eq!(client.is_complete(&group_id), true);
+    assert!(client.is_complete(&group_id));
 
     let group = client.get_group(&group_id);
-    assert_eq!(group.is_complete, true);
+    assert!(group.is_complete);
     assert_eq!(group.payout_index, 3);
 }
 
 #[test]
 #[should_panic]
 fn test_contribute_after_completion() {
-    let (env, client, creator, member2, member3) = setup_test_env();
+    let (_env, client, creator, member2, member3) = setup_test_env();
 
     // Create and complete a group
     let group_id = client.create_group(&creator, &100_000_000i128, &604_800u64, &3u32, &86400u64, &5u32);
@@ -264,7 +264,7 @@ fn test_contribute_after_completion() {
 #[test]
 #[should_panic]
 fn test_create_group_invalid_amount() {
-    let (env, client, creator, _, _) = setup_test_env();
+    let (_env, client, creator, _, _) = setup_test_env();
 
     // Try to create group with zero contribution
     client.create_group(&creator, &0i128, &604_800u64, &10u32, &86400u64, &5u32);
@@ -273,7 +273,7 @@ fn test_create_group_invalid_amount() {
 #[test]
 #[should_panic]
 fn test_create_group_invalid_duration() {
-    let (env, client, creator, _, _) = setup_test_env();
+    let (_env, client, creator, _, _) = setup_test_env();
 
     // Try to create group with zero duration
     client.create_group(&creator, &100_000_000i128, &0u64, &10u32, &86400u64, &5u32);
@@ -282,7 +282,7 @@ fn test_create_group_invalid_duration() {
 #[test]
 #[should_panic]
 fn test_create_group_invalid_max_members() {
-    let (env, client, creator, _, _) = setup_test_env();
+    let (_env, client, creator, _, _) = setup_test_env();
 
     // Try to create group with only 1 member max
     client.create_group(&creator, &100_000_000i128, &604_800u64, &1u32, &86400u64, &5u32);
@@ -302,7 +302,7 @@ fn test_contribute_not_member() {
 
 #[test]
 fn test_multiple_groups() {
-    let (env, client, creator, member2, _) = setup_test_env();
+    let (_env, client, creator, member2, _) = setup_test_env();
 
     // Create first group
     let group_id1 = client.create_group(&creator, &100_000_000i128, &604_800u64, &5u32, &86400u64, &5u32);
@@ -0,0 +1,274 @@
+#![cfg(test)]
+
+mod common;
+
+use soroban_ajo::{AjoContractClient, AjoError, MemberAmountEvent};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events, Ledger},
+    token::{StellarAssetClient, TokenClient},
+    Address, Env, IntoVal, Val, Vec,
+};
+
+const CONTRIBUTION: i128 = 100_000_000;
+const CYCLE: u64 = 604_800;
+const DAY: u64 = 86400;
+
+struct TestEnv {
+    env: Env,
+    client: AjoContractClient<'static>,
+    token: TokenClient<'static>,
+    creator: Address,
+    member2: Address,
+    group_id: u64,
+}
+
+/// Creates a full 2-member group that uses a freshly minted token for auto-debit
+fn setup_test_env() -> TestEnv {
+    let (env, client, [creator, member2]) = common::setup_test_env();
+
+    let issuer = Address::generate(&env);
+    let token_address = env.register_stellar_asset_contract_v2(issuer).address();
+    let token = TokenClient::new(&env, &token_address);
+    StellarAssetClient::new(&env, &token_address).mint(&member2, &(10 * CONTRIBUTION));
+
+    let group_id = client.create_group(&creator, &CONTRIBUTION, &CYCLE, &2u32, &DAY, &5u32);
+    client.set_contribution_token(&group_id, &token_address);
+    client.join_group(&member2, &group_id);
+
+    TestEnv { env, client, token, creator, member2, group_id }
+}
+
+/// Approves the Ajo contract to spend `amount` of the member's tokens
+fn approve(t: &TestEnv, amount: i128) {
+    t.token.approve(&t.member2, &t.client.address, &amount, &1_000);
+}
+
+#[test]
+fn test_keeper_pulls_contribution_from_allowance() {
+    let t = setup_test_env();
+    approve(&t, 3 * CONTRIBUTION);
+    t.client.enable_auto_contribute(&t.member2, &t.group_id, &3u32);
+
+    let keeper = Address::generate(&t.env);
+    assert_eq!(t.client.collect_auto_contributions(&keeper, &t.group_id), 1);
+
+    let record = t.client.get_contribution_detail(&t.group_id, &1u32, &t.member2);
+    assert!(record.has_paid);
+    assert!(!record.is_late);
+
+    // The pull is bookkeeping only, like every other contribution
+    assert_eq!(t.token.balance(&t.client.address), 0);
+    assert_eq!(t.token.balance(&t.member2), 10 * CONTRIBUTION);
+
+    let mandate = t.client.get_auto_contribute_mandate(&t.group_id, &t.member2);
+    assert_eq!(mandate.remaining_cycles, 2);
+
+    // Already paid this cycle, so nothing more is pulled
+    assert_eq!(t.client.collect_auto_contributions(&keeper, &t.group_id), 0);
+}
+
+#[test]
+fn test_payout_relies_on_mandate() {
+    let t = setup_test_env();
+    approve(&t, 3 * CONTRIBUTION);
+    t.client.enable_auto_contribute(&t.member2, &t.group_id, &3u32);
+
+    // Payouts don't pull on their own, so a keeper collects during the cycle
+    t.client.contribute(&t.creator, &t.group_id);
+    let keeper = Address::generate(&t.env);
+    assert_eq!(t.client.collect_auto_contributions(&keeper, &t.group_id), 1);
+
+    t.env.ledger().with_mut(|li| {
+        li.timestamp += CYCLE + DAY + 1;
+    });
+    t.client.execute_payout(&t.group_id);
+
+    let group = t.client.get_group(&t.group_id);
+    assert_eq!(group.current_cycle, 2);
+    let record = t.client.get_contribution_detail(&t.group_id, &1u32, &t.member2);
+    assert!(record.has_paid);
+    assert!(!record.is_late);
+}
+
+#[test]
+fn test_mandate_expires_after_max_cycles() {
+    let t = setup_test_env();
+    approve(&t, 3 * CONTRIBUTION);
+    t.client.enable_auto_contribute(&t.member2, &t.group_id, &1u32);
+
+    let keeper = Address::generate(&t.env);
+    t.client.collect_auto_contributions(&keeper, &t.group_id);
+
+    let result = t.client.try_get_auto_contribute_mandate(&t.group_id, &t.member2);
+    assert_eq!(result, Err(Ok(AjoError::MandateNotFound)));
+
+    t.client.contribute(&t.creator, &t.group_id);
+    t.env.ledger().with_mut(|li| {
+        li.timestamp += CYCLE + DAY + 1;
+    });
+    t.client.execute_payout(&t.group_id);
+
+    assert_eq!(t.client.collect_auto_contributions(&keeper, &t.group_id), 0);
+    let statuses = t.client.get_contribution_status(&t.group_id, &2u32);
+    assert!(!statuses.get(1).unwrap().1);
+}
+
+#[test]
+fn test_mandate_cycle_used_once_all_periods_are_pulled() {
+    let t = setup_test_env();
+    let group_id = t.client.create_group(&t.creator, &CONTRIBUTION, &CYCLE, &2u32, &DAY, &5u32);
+    t.client.set_contribution_token(&group_id, &t.token.address);
+    t.client.set_intervals(&group_id, &CYCLE, &(4 * CYCLE));
+    t.client.join_group(&t.member2, &group_id);
+    approve(&t, 4 * CONTRIBUTION);
+    t.client.enable_auto_contribute(&t.member2, &group_id, &2u32);
+
+    // One pull per weekly period, but only the last completes the cycle
+    let keeper = Address::generate(&t.env);
+    for period in 1..=4 {
+        assert_eq!(t.client.collect_auto_contributions(&keeper, &group_id), 1);
+        let mandate = t.client.get_auto_contribute_mandate(&group_id, &t.member2);
+        assert_eq!(mandate.remaining_cycles, if period < 4 { 2 } else { 1 });
+        t.env.ledger().with_mut(|li| {
+            li.timestamp += CYCLE;
+        });
+    }
+
+    let record = t.client.get_contribution_detail(&group_id, &1u32, &t.member2);
+    assert!(record.has_paid);
+    assert_eq!(record.amount_paid, 4 * CONTRIBUTION);
+}
+
+#[test]
+fn test_pulls_use_up_the_allowance() {
+    let t = setup_test_env();
+    approve(&t, CONTRIBUTION);
+    t.client.enable_auto_contribute(&t.member2, &t.group_id, &2u32);
+
+    let keeper = Address::generate(&t.env);
+    assert_eq!(t.client.collect_auto_contributions(&keeper, &t.group_id), 1);
+    let mandate = t.client.get_auto_contribute_mandate(&t.group_id, &t.member2);
+    assert_eq!(mandate.pulled, CONTRIBUTION);
+
+    t.client.contribute(&t.creator, &t.group_id);
+    t.env.ledger().with_mut(|li| {
+        li.timestamp += CYCLE + DAY + 1;
+    });
+    t.client.execute_payout(&t.group_id);
+
+    // The token's allowance is untouched, but the mandate has spent it
+    assert_eq!(t.token.allowance(&t.member2, &t.client.address), CONTRIBUTION);
+    assert_eq!(t.client.collect_auto_contributions(&keeper, &t.group_id), 0);
+
+    // A larger approval covers the tally plus the next pull
+    approve(&t, 2 * CONTRIBUTION);
+    assert_eq!(t.client.collect_auto_contributions(&keeper, &t.group_id), 1);
+    let record = t.client.get_contribution_detail(&t.group_id, &2u32, &t.member2);
+    assert!(record.has_paid);
+}
+
+#[test]
+fn test_failed_pull_falls_back_to_late_penalty() {
+    let t = setup_test_env();
+
+    // Mandate without enough allowance
+    approve(&t, CONTRIBUTION - 1);
+    t.client.enable_auto_contribute(&t.member2, &t.group_id, &3u32);
+
+    let keeper = Address::generate(&t.env);
+    assert_eq!(t.client.collect_auto_contributions(&keeper, &t.group_id), 0);
+
+    let expected_topics: Vec<Val> = (symbol_short!("autofail"), t.group_id).into_val(&t.env);
+    let event = t
+        .env
+        .events()
+        .all()
+        .iter()
+        .find(|(_, topics, _)| *topics == expected_topics)
+        .expect("failed pull event emitted");
+    let failed: MemberAmountEvent = event.2.into_val(&t.env);
+    assert_eq!(failed.cycle, 1);
+    assert_eq!(failed.member, t.member2);
+    assert_eq!(failed.amount, CONTRIBUTION);
+
+    // Member pays manually during the grace period and is penalized as usual
+    t.env.ledger().with_mut(|li| {
+        li.timestamp += CYCLE + 3600;
+    });
+    t.client.contribute(&t.member2, &t.group_id);
+
+    let record = t.client.get_contribution_detail(&t.group_id, &1u32, &t.member2);
+    assert!(record.is_late);
+    assert_eq!(record.penalty_amount, 5_000_000);
+    assert_eq!(t.token.balance(&t.client.address), 0);
+}
+
+#[test]
+fn test_pull_during_grace_period_is_penalized() {
+    let t = setup_test_env();
+    approve(&t, CONTRIBUTION);
+    t.client.enable_auto_contribute(&t.member2, &t.group_id, &1u32);
+
+    t.env.ledger().with_mut(|li| {
+        li.timestamp += CYCLE + 3600;
+    });
+    let keeper = Address::generate(&t.env);
+    assert_eq!(t.client.collect_auto_contributions(&keeper, &t.group_id), 1);
+
+    let record = t.client.get_contribution_detail(&t.group_id, &1u32, &t.member2);
+    assert!(record.is_late);
+    assert_eq!(record.penalty_amount, 5_000_000);
+
+    // Nothing is pulled once the grace period has expired
+    t.env.ledger().with_mut(|li| {
+        li.timestamp += DAY;
+    });
+    assert_eq!(t.client.collect_auto_contributions(&keeper, &t.group_id), 0);
+}
+
+#[test]
+fn test_revoked_mandate_stops_pulls_even_when_paused() {
+    let t = setup_test_env();
+    approve(&t, 3 * CONTRIBUTION);
+    t.client.enable_auto_contribute(&t.member2, &t.group_id, &3u32);
+
+    let admin = Address::generate(&t.env);
+    t.client.initialize(&admin);
+    t.client.pause();
+    t.client.revoke_auto_contribute(&t.member2, &t.group_id);
+    t.client.unpause();
+
+    let keeper = Address::generate(&t.env);
+    assert_eq!(t.client.collect_auto_contributions(&keeper, &t.group_id), 0);
+    assert_eq!(t.token.balance(&t.member2), 10 * CONTRIBUTION);
+
+    let result = t.client.try_revoke_auto_contribute(&t.member2, &t.group_id);
+    assert_eq!(result, Err(Ok(AjoError::MandateNotFound)));
+}
+
+#[test]
+fn test_invalid_mandates_rejected() {
+    let t = setup_test_env();
+
+    let result = t.client.try_enable_auto_contribute(&t.member2, &t.group_id, &0u32);
+    assert_eq!(result, Err(Ok(AjoError::InvalidMandate)));
+
+    let outsider = Address::generate(&t.env);
+    let result = t.client.try_enable_auto_contribute(&outsider, &t.group_id, &1u32);
+    assert_eq!(result, Err(Ok(AjoError::NotMember)));
+
+    // Groups without a token can't use auto-debit
+    let group_id = t.client.create_group(&t.creator, &CONTRIBUTION, &CYCLE, &2u32, &DAY, &5u32);
+    let result = t.client.try_enable_auto_contribute(&t.creator, &group_id, &1u32);
+    assert_eq!(result, Err(Ok(AjoError::NoContributionToken)));
+}
+
+#[test]
+fn test_contribution_token_locked_after_join() {
+    let t = setup_test_env();
+
+    let other_token = Address::generate(&t.env);
+    let result = t.client.try_set_contribution_token(&t.group_id, &other_token);
+    assert_eq!(result, Err(Ok(AjoError::GroupTermsLocked)));
+}
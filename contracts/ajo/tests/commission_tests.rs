@@ -0,0 +1,163 @@
+#![cfg(test)]
+
+mod common;
+
+use soroban_ajo::{AjoError, MemberAmountEvent, OrganizerCommission};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Events, Ledger},
+    IntoVal, Val, Vec,
+};
+
+use common::{complete_cycle, setup_test_env};
+
+#[test]
+fn test_default_group_has_no_commission() {
+    let (_env, client, [creator, _, _]) = setup_test_env();
+
+    let group_id = client.create_group(&creator, &100_000_000i128, &604_800u64, &3u32, &86400u64, &5u32);
+
+    let group = client.get_group(&group_id);
+    assert_eq!(group.commission, OrganizerCommission::None);
+
+    let status = client.get_group_status(&group_id);
+    assert_eq!(status.organizer_commission, OrganizerCommission::None);
+    assert_eq!(status.commission_per_payout, 0);
+}
+
+#[test]
+fn test_basis_points_commission_disclosed_before_join() {
+    let (_env, client, [creator, member2, member3]) = setup_test_env();
+
+    let group_id = client.create_group(&creator, &100_000_000i128, &604_800u64, &3u32, &86400u64, &5u32);
+    client.set_organizer_commission(&group_id, &OrganizerCommission::BasisPoints(250));
+
+    // Prospective members see the terms before joining
+    let group = client.get_group(&group_id);
+    assert_eq!(group.commission, OrganizerCommission::BasisPoints(250));
+
+    client.join_group(&member2, &group_id);
+    client.join_group(&member3, &group_id);
+
+    // 2.5% of a 30 XLM pot
+    let status = client.get_group_status(&group_id);
+    assert_eq!(status.organizer_commission, OrganizerCommission::BasisPoints(250));
+    assert_eq!(status.commission_per_payout, 7_500_000);
+}
+
+#[test]
+fn test_recipient_receives_net_payout_with_basis_points() {
+    let (env, client, [creator, member2, member3]) = setup_test_env();
+
+    let group_id = client.create_group(&creator, &100_000_000i128, &604_800u64, &3u32, &86400u64, &5u32);
+    client.set_organizer_commission(&group_id, &OrganizerCommission::BasisPoints(250));
+    client.join_group(&member2, &group_id);
+    client.join_group(&member3, &group_id);
+
+    complete_cycle(&env, &client, group_id, &[&creator, &member2, &member3]);
+
+    let record = client.get_payout_record(&group_id, &creator);
+    assert_eq!(record.commission, 7_500_000);
+    assert_eq!(record.amount, 300_000_000 - 7_500_000);
+    assert_eq!(record.cycle, 1);
+
+    complete_cycle(&env, &client, group_id, &[&creator, &member2, &member3]);
+
+    let record = client.get_payout_record(&group_id, &member2);
+    assert_eq!(record.commission, 7_500_000);
+    assert_eq!(record.amount, 292_500_000);
+    assert_eq!(record.cycle, 2);
+}
+
+#[test]
+fn test_fixed_commission_with_late_penalty() {
+    let (env, client, [creator, member2, _]) = setup_test_env();
+
+    let group_id = client.create_group(&creator, &100_000_000i128, &604_800u64, &2u32, &86400u64, &5u32);
+    client.set_organizer_commission(&group_id, &OrganizerCommission::Fixed(1_000_000));
+    client.join_group(&member2, &group_id);
+
+    client.contribute(&creator, &group_id);
+
+    // Member2 pays 1 hour into the grace period (5% penalty)
+    env.ledger().with_mut(|li| {
+        li.timestamp += 604_800 + 3600;
+    });
+    client.contribute(&member2, &group_id);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp += 86400;
+    });
+    client.execute_payout(&group_id);
+
+    // Penalty goes to the recipient; commission only comes out of the base pot
+    let record = client.get_payout_record(&group_id, &creator);
+    assert_eq!(record.commission, 1_000_000);
+    assert_eq!(record.amount, 200_000_000 + 5_000_000 - 1_000_000);
+}
+
+#[test]
+fn test_commission_locked_after_first_member_joins() {
+    let (_env, client, [creator, member2, _]) = setup_test_env();
+
+    let group_id = client.create_group(&creator, &100_000_000i128, &604_800u64, &3u32, &86400u64, &5u32);
+    client.set_organizer_commission(&group_id, &OrganizerCommission::BasisPoints(100));
+    client.join_group(&member2, &group_id);
+
+    let result = client.try_set_organizer_commission(&group_id, &OrganizerCommission::BasisPoints(2_000));
+    assert_eq!(result, Err(Ok(AjoError::GroupTermsLocked)));
+
+    let group = client.get_group(&group_id);
+    assert_eq!(group.commission, OrganizerCommission::BasisPoints(100));
+}
+
+#[test]
+fn test_invalid_commission_rejected() {
+    let (_env, client, [creator, _, _]) = setup_test_env();
+
+    let group_id = client.create_group(&creator, &100_000_000i128, &604_800u64, &3u32, &86400u64, &5u32);
+
+    let result = client.try_set_organizer_commission(&group_id, &OrganizerCommission::BasisPoints(2_001));
+    assert_eq!(result, Err(Ok(AjoError::InvalidCommission)));
+
+    let result = client.try_set_organizer_commission(&group_id, &OrganizerCommission::Fixed(-1));
+    assert_eq!(result, Err(Ok(AjoError::InvalidCommission)));
+}
+
+#[test]
+fn test_fixed_commission_capped_at_pot() {
+    let (env, client, [creator, member2, _]) = setup_test_env();
+
+    let group_id = client.create_group(&creator, &1_000i128, &604_800u64, &2u32, &86400u64, &5u32);
+    client.set_organizer_commission(&group_id, &OrganizerCommission::Fixed(5_000));
+    client.join_group(&member2, &group_id);
+
+    complete_cycle(&env, &client, group_id, &[&creator, &member2]);
+
+    let record = client.get_payout_record(&group_id, &creator);
+    assert_eq!(record.commission, 2_000);
+    assert_eq!(record.amount, 0);
+}
+
+#[test]
+fn test_commission_paid_event() {
+    let (env, client, [creator, member2, _]) = setup_test_env();
+
+    let group_id = client.create_group(&creator, &100_000_000i128, &604_800u64, &2u32, &86400u64, &5u32);
+    client.set_organizer_commission(&group_id, &OrganizerCommission::BasisPoints(500));
+    client.join_group(&member2, &group_id);
+
+    complete_cycle(&env, &client, group_id, &[&creator, &member2]);
+
+    let expected_topics: Vec<Val> = (symbol_short!("commpaid"), group_id).into_val(&env);
+    let event = env
+        .events()
+        .all()
+        .iter()
+        .find(|(_, topics, _)| *topics == expected_topics)
+        .expect("commission event emitted");
+    let paid: MemberAmountEvent = event.2.into_val(&env);
+    assert_eq!(paid.cycle, 1);
+    assert_eq!(paid.member, creator);
+    assert_eq!(paid.amount, 10_000_000);
+}
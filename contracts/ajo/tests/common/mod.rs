@@ -0,0 +1,42 @@
+//! Helpers shared by the integration tests.
+//!
+//! Each test file declares `mod common;`, so anything a file doesn't use would
+//! otherwise be reported as dead code.
+#![allow(dead_code)]
+
+use soroban_ajo::{AjoContract, AjoContractClient};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Env,
+};
+
+/// Seconds from a cycle's start until its payout is due: a weekly cycle plus a
+/// one-day grace period
+const CYCLE_AND_GRACE: u64 = 604_800 + 86400;
+
+/// Helper function to create a test environment and contract, with `N` generated
+/// addresses to act as the creator and members
+pub fn setup_test_env<const N: usize>() -> (Env, AjoContractClient<'static>, [Address; N]) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, AjoContract);
+    let client = AjoContractClient::new(&env, &contract_id);
+
+    // Generate test addresses
+    let addresses = core::array::from_fn(|_| Address::generate(&env));
+
+    (env, client, addresses)
+}
+
+/// Helper function to contribute for every member and execute the payout of a
+/// group with weekly cycles and a one-day grace period
+pub fn complete_cycle(env: &Env, client: &AjoContractClient, group_id: u64, members: &[&Address]) {
+    for member in members {
+        client.contribute(member, &group_id);
+    }
+    env.ledger().with_mut(|li| {
+        li.timestamp += CYCLE_AND_GRACE + 1;
+    });
+    client.execute_payout(&group_id);
+}
@@ -0,0 +1,153 @@
+#![cfg(test)]
+
+mod common;
+
+use soroban_ajo::{AjoContractClient, AjoError, IntervalsSetEvent};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Events, Ledger},
+    Address, Env, IntoVal, Val, Vec,
+};
+
+use common::setup_test_env;
+
+const CONTRIBUTION: i128 = 100_000_000;
+const WEEK: u64 = 604_800;
+const MONTH: u64 = 4 * WEEK;
+const DAY: u64 = 86400;
+
+/// Creates a full 2-member group that collects weekly and pays out every 4 weeks
+fn create_weekly_group(client: &AjoContractClient, creator: &Address, member2: &Address) -> u64 {
+    let group_id = client.create_group(creator, &CONTRIBUTION, &WEEK, &2u32, &DAY, &5u32);
+    client.set_intervals(&group_id, &WEEK, &MONTH);
+    client.join_group(member2, &group_id);
+    group_id
+}
+
+/// Moves the ledger clock forward
+fn advance(env: &Env, seconds: u64) {
+    env.ledger().with_mut(|li| {
+        li.timestamp += seconds;
+    });
+}
+
+#[test]
+fn test_status_reports_current_period() {
+    let (env, client, [creator, member2]) = setup_test_env();
+    let group_id = create_weekly_group(&client, &creator, &member2);
+
+    let group = client.get_group(&group_id);
+    assert_eq!(group.cycle_duration, MONTH);
+    assert_eq!(group.contribution_interval, WEEK);
+
+    let status = client.get_group_status(&group_id);
+    assert_eq!(status.current_period, 1);
+    assert_eq!(status.periods_per_cycle, 4);
+    assert_eq!(status.period_end_time, group.cycle_start_time + WEEK);
+
+    advance(&env, WEEK + 1);
+    let status = client.get_group_status(&group_id);
+    assert_eq!(status.current_period, 2);
+    assert_eq!(status.period_end_time, group.cycle_start_time + 2 * WEEK);
+
+    let expected_topics: Vec<Val> = (symbol_short!("intervals"), group_id).into_val(&env);
+    let event = env
+        .events()
+        .all()
+        .iter()
+        .find(|(_, topics, _)| *topics == expected_topics)
+        .expect("intervals event emitted");
+    let intervals: IntervalsSetEvent = event.2.into_val(&env);
+    assert_eq!(intervals.contribution_interval, WEEK);
+    assert_eq!(intervals.payout_interval, MONTH);
+}
+
+#[test]
+fn test_payout_requires_every_period() {
+    let (env, client, [creator, member2]) = setup_test_env();
+    let group_id = create_weekly_group(&client, &creator, &member2);
+
+    client.contribute(&creator, &group_id);
+    assert_eq!(client.get_contribution_detail(&group_id, &1u32, &creator).amount_paid, CONTRIBUTION);
+    let result = client.try_contribute(&creator, &group_id);
+    assert_eq!(result, Err(Ok(AjoError::AlreadyContributed)));
+
+    for period in 1..=4 {
+        if period > 1 {
+            client.contribute(&creator, &group_id);
+        }
+        client.contribute(&member2, &group_id);
+        if period < 4 {
+            advance(&env, WEEK);
+        }
+    }
+
+    let record = client.get_contribution_detail(&group_id, &1u32, &member2);
+    assert!(record.has_paid && !record.is_late);
+    assert_eq!(record.amount_paid, 4 * CONTRIBUTION);
+
+    advance(&env, WEEK + DAY + 1);
+    client.execute_payout(&group_id);
+    assert_eq!(client.get_payout_record(&group_id, &creator).amount, 8 * CONTRIBUTION);
+}
+
+#[test]
+fn test_missing_period_blocks_payout() {
+    let (env, client, [creator, member2]) = setup_test_env();
+    let group_id = create_weekly_group(&client, &creator, &member2);
+
+    // Member2 skips the last week
+    for period in 1..=4 {
+        client.contribute(&creator, &group_id);
+        if period < 4 {
+            client.contribute(&member2, &group_id);
+        }
+        advance(&env, WEEK);
+    }
+
+    advance(&env, DAY + 1);
+    let result = client.try_execute_payout(&group_id);
+    assert_eq!(result, Err(Ok(AjoError::IncompleteContributions)));
+}
+
+#[test]
+fn test_overdue_period_penalized_on_arrears_only() {
+    let (env, client, [creator, member2]) = setup_test_env();
+    let group_id = create_weekly_group(&client, &creator, &member2);
+
+    // Member2 misses the first week and catches up early in the second
+    advance(&env, WEEK + 3600);
+    client.contribute(&member2, &group_id);
+
+    let record = client.get_contribution_detail(&group_id, &1u32, &member2);
+    assert_eq!(record.amount_paid, 2 * CONTRIBUTION);
+    assert!(record.is_late);
+    assert_eq!(record.penalty_amount, 5_000_000);
+}
+
+#[test]
+fn test_period_past_grace_rejected() {
+    let (env, client, [creator, member2]) = setup_test_env();
+    let group_id = create_weekly_group(&client, &creator, &member2);
+
+    advance(&env, WEEK + DAY + 1);
+    let result = client.try_contribute(&member2, &group_id);
+    assert_eq!(result, Err(Ok(AjoError::GracePeriodExpired)));
+}
+
+#[test]
+fn test_invalid_intervals_rejected() {
+    let (_env, client, [creator, member2]) = setup_test_env();
+    let group_id = client.create_group(&creator, &CONTRIBUTION, &WEEK, &2u32, &DAY, &5u32);
+
+    let result = client.try_set_intervals(&group_id, &0u64, &MONTH);
+    assert_eq!(result, Err(Ok(AjoError::CycleDurationZero)));
+    let result = client.try_set_intervals(&group_id, &(3 * DAY), &WEEK);
+    assert_eq!(result, Err(Ok(AjoError::InvalidCycleSchedule)));
+    let result = client.try_set_intervals(&group_id, &3600u64, &(32 * 3600));
+    assert_eq!(result, Err(Ok(AjoError::InvalidCycleSchedule)));
+
+    client.join_group(&member2, &group_id);
+    let result = client.try_set_intervals(&group_id, &WEEK, &MONTH);
+    assert_eq!(result, Err(Ok(AjoError::GroupTermsLocked)));
+}
@@ -0,0 +1,130 @@
+#![cfg(test)]
+
+mod common;
+
+use soroban_ajo::{AjoContractClient, AjoError, SeatTierSetEvent};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Events, Ledger},
+    vec, Address, IntoVal, Val, Vec,
+};
+
+use common::{complete_cycle, setup_test_env};
+
+const CONTRIBUTION: i128 = 100_000_000;
+const CYCLE: u64 = 604_800;
+const DAY: u64 = 86400;
+
+/// Creates a group allowing tiers up to 3x
+fn create_tiered_group(client: &AjoContractClient, creator: &Address, max_members: u32) -> u64 {
+    let group_id = client.create_group(creator, &CONTRIBUTION, &CYCLE, &max_members, &DAY, &5u32);
+    client.set_max_contribution_tier(&group_id, &3u32);
+    group_id
+}
+
+#[test]
+fn test_tiered_seats_pay_and_receive_proportionally() {
+    let (env, client, [creator, member2, _member3]) = setup_test_env();
+    let group_id = create_tiered_group(&client, &creator, 2);
+    client.join_group(&member2, &group_id);
+    client.set_seat_tier(&member2, &group_id, &1u32, &3u32);
+
+    let group = client.get_group(&group_id);
+    assert_eq!(group.max_contribution_tier, 3);
+    assert_eq!(group.seat_tiers, vec![&env, 1u32, 3u32]);
+
+    client.contribute(&creator, &group_id);
+    client.contribute(&member2, &group_id);
+    let record = client.get_contribution_detail(&group_id, &1u32, &member2);
+    assert_eq!(record.amount_paid, 3 * CONTRIBUTION);
+
+    // The smaller first payout leaves a surplus for the larger second one
+    env.ledger().with_mut(|li| {
+        li.timestamp += CYCLE + DAY + 1;
+    });
+    client.execute_payout(&group_id);
+    assert_eq!(client.get_payout_record(&group_id, &creator).amount, 2 * CONTRIBUTION);
+    assert_eq!(client.get_group(&group_id).pot_surplus, 2 * CONTRIBUTION);
+
+    complete_cycle(&env, &client, group_id, &[&creator, &member2]);
+    assert_eq!(client.get_payout_record(&group_id, &member2).amount, 6 * CONTRIBUTION);
+    assert_eq!(client.get_group(&group_id).pot_surplus, 0);
+    assert!(client.is_complete(&group_id));
+
+    let expected_topics: Vec<Val> = (symbol_short!("seattier"), group_id).into_val(&env);
+    let event = env
+        .events()
+        .all()
+        .iter()
+        .find(|(_, topics, _)| *topics == expected_topics)
+        .expect("seat tier event emitted");
+    let set: SeatTierSetEvent = event.2.into_val(&env);
+    assert_eq!((set.member, set.seat, set.tier), (member2, 1, 3));
+}
+
+#[test]
+fn test_unfunded_payout_order_rejected() {
+    let (_env, client, [creator, member2, _member3]) = setup_test_env();
+    let group_id = create_tiered_group(&client, &creator, 2);
+
+    // A large first payout can't be funded once a smaller seat joins
+    client.set_seat_tier(&creator, &group_id, &0u32, &3u32);
+    let result = client.try_join_group(&member2, &group_id);
+    assert_eq!(result, Err(Ok(AjoError::InsufficientBalance)));
+
+    client.set_seat_tier(&creator, &group_id, &0u32, &1u32);
+    client.join_group(&member2, &group_id);
+    let result = client.try_set_seat_tier(&creator, &group_id, &0u32, &2u32);
+    assert_eq!(result, Err(Ok(AjoError::InsufficientBalance)));
+}
+
+#[test]
+fn test_swap_keeps_tiers_and_funding() {
+    let (_env, client, [creator, member2, member3]) = setup_test_env();
+    let group_id = create_tiered_group(&client, &creator, 3);
+    client.join_group(&member2, &group_id);
+    client.join_group(&member3, &group_id);
+    client.set_seat_tier(&member3, &group_id, &2u32, &2u32);
+
+    // Moving the 2x seat ahead of a 1x seat would leave its payout unfunded
+    client.propose_swap(&group_id, &member2, &member3);
+    let result = client.try_accept_swap(&group_id, &member2, &member3);
+    assert_eq!(result, Err(Ok(AjoError::InsufficientBalance)));
+
+    // Swapping equal tiers is fine, and each member keeps their tier
+    client.set_seat_tier(&member2, &group_id, &1u32, &2u32);
+    client.accept_swap(&group_id, &member2, &member3);
+    let group = client.get_group(&group_id);
+    assert_eq!(group.members.get(1).unwrap(), member3);
+    assert_eq!(group.seat_tiers.get(1).unwrap(), 2);
+}
+
+#[test]
+fn test_invalid_tiers_rejected() {
+    let (_env, client, [creator, member2, _member3]) = setup_test_env();
+    let group_id = client.create_group(&creator, &CONTRIBUTION, &CYCLE, &2u32, &DAY, &5u32);
+
+    let result = client.try_set_max_contribution_tier(&group_id, &0u32);
+    assert_eq!(result, Err(Ok(AjoError::InvalidGroupTerms)));
+    let result = client.try_set_max_contribution_tier(&group_id, &11u32);
+    assert_eq!(result, Err(Ok(AjoError::InvalidGroupTerms)));
+
+    // Untiered groups only allow tier 1
+    let result = client.try_set_seat_tier(&creator, &group_id, &0u32, &2u32);
+    assert_eq!(result, Err(Ok(AjoError::InvalidGroupTerms)));
+
+    client.set_max_contribution_tier(&group_id, &3u32);
+    client.join_group(&member2, &group_id);
+    let result = client.try_set_max_contribution_tier(&group_id, &2u32);
+    assert_eq!(result, Err(Ok(AjoError::GroupTermsLocked)));
+
+    let result = client.try_set_seat_tier(&member2, &group_id, &1u32, &0u32);
+    assert_eq!(result, Err(Ok(AjoError::ContributionAmountZero)));
+    let result = client.try_set_seat_tier(&member2, &group_id, &0u32, &2u32);
+    assert_eq!(result, Err(Ok(AjoError::NotMember)));
+
+    // Tiers lock once contributions start
+    client.contribute(&creator, &group_id);
+    let result = client.try_set_seat_tier(&member2, &group_id, &1u32, &2u32);
+    assert_eq!(result, Err(Ok(AjoError::GroupTermsLocked)));
+}
@@ -0,0 +1,199 @@
+#![cfg(test)]
+
+mod common;
+
+use soroban_ajo::{AjoContractClient, AjoError, CycleSchedule};
+use soroban_sdk::{testutils::Ledger, vec, Address, Env};
+
+use common::setup_test_env;
+
+const CONTRIBUTION: i128 = 100_000_000;
+const CYCLE: u64 = 604_800;
+const GRACE: u64 = 86400;
+
+/// Creates a 3-member group with the given cycle schedule
+fn create_scheduled_group(
+    client: &AjoContractClient,
+    members: &[&Address; 3],
+    schedule: &CycleSchedule,
+) -> u64 {
+    let group_id = client.create_group(members[0], &CONTRIBUTION, &CYCLE, &3u32, &GRACE, &5u32);
+    client.set_cycle_schedule(&group_id, schedule);
+    client.join_group(members[1], &group_id);
+    client.join_group(members[2], &group_id);
+    group_id
+}
+
+fn set_time(env: &Env, timestamp: u64) {
+    env.ledger().with_mut(|li| {
+        li.timestamp = timestamp;
+    });
+}
+
+fn contribute_all(client: &AjoContractClient, group_id: u64, members: &[&Address; 3]) {
+    for member in members {
+        client.contribute(member, &group_id);
+    }
+}
+
+#[test]
+fn test_rolling_schedule_drifts_with_late_payout() {
+    let (env, client, [creator, member2, member3]) = setup_test_env();
+    let members = [&creator, &member2, &member3];
+    let group_id = create_scheduled_group(&client, &members, &CycleSchedule::Rolling);
+    let start = env.ledger().timestamp();
+
+    contribute_all(&client, group_id, &members);
+
+    // Payout runs three days after the grace period ends
+    let payout_time = start + CYCLE + GRACE + 3 * 86400;
+    set_time(&env, payout_time);
+    client.execute_payout(&group_id);
+
+    assert_eq!(client.get_group(&group_id).cycle_start_time, payout_time);
+}
+
+#[test]
+fn test_anchored_schedule_ignores_late_payout() {
+    let (env, client, [creator, member2, member3]) = setup_test_env();
+    let members = [&creator, &member2, &member3];
+    let group_id = create_scheduled_group(&client, &members, &CycleSchedule::Anchored);
+    let start = env.ledger().timestamp();
+
+    contribute_all(&client, group_id, &members);
+
+    set_time(&env, start + CYCLE + GRACE + 3 * 86400);
+    client.execute_payout(&group_id);
+
+    // Cycle 2 still starts exactly one cycle after creation
+    let status = client.get_group_status(&group_id);
+    assert_eq!(status.current_cycle, 2);
+    assert_eq!(status.cycle_start_time, start + CYCLE);
+    assert_eq!(status.cycle_end_time, start + 2 * CYCLE);
+    assert!(!status.is_catching_up);
+
+    contribute_all(&client, group_id, &members);
+    set_time(&env, start + 2 * CYCLE + GRACE);
+    client.execute_payout(&group_id);
+
+    assert_eq!(client.get_group(&group_id).cycle_start_time, start + 2 * CYCLE);
+}
+
+#[test]
+fn test_anchored_schedule_catches_up_after_missed_cycles() {
+    let (env, client, [creator, member2, member3]) = setup_test_env();
+    let members = [&creator, &member2, &member3];
+    let group_id = create_scheduled_group(&client, &members, &CycleSchedule::Anchored);
+    let start = env.ledger().timestamp();
+
+    contribute_all(&client, group_id, &members);
+
+    // Payout for cycle 1 only runs after cycle 2's window has fully elapsed
+    let payout_time = start + 2 * CYCLE + 3600;
+    set_time(&env, payout_time);
+    client.execute_payout(&group_id);
+
+    let status = client.get_group_status(&group_id);
+    assert_eq!(status.current_cycle, 2);
+    assert!(status.is_catching_up);
+    assert_eq!(status.cycle_start_time, payout_time);
+    assert_eq!(status.cycle_end_time, payout_time + 86400);
+
+    // Catch-up contributions are on time and the payout doesn't wait for grace
+    contribute_all(&client, group_id, &members);
+    let record = client.get_contribution_detail(&group_id, &2u32, &member2);
+    assert!(!record.is_late);
+    client.execute_payout(&group_id);
+
+    // Cycle 3 is back on the anchored schedule
+    let status = client.get_group_status(&group_id);
+    assert_eq!(status.current_cycle, 3);
+    assert!(!status.is_catching_up);
+    assert_eq!(status.cycle_start_time, start + 2 * CYCLE);
+    assert_eq!(status.cycle_end_time, start + 3 * CYCLE);
+}
+
+#[test]
+fn test_late_contribution_in_catch_up_cycle() {
+    let (env, client, [creator, member2, member3]) = setup_test_env();
+    let members = [&creator, &member2, &member3];
+    let group_id = create_scheduled_group(&client, &members, &CycleSchedule::Anchored);
+    let start = env.ledger().timestamp();
+
+    contribute_all(&client, group_id, &members);
+    let payout_time = start + 2 * CYCLE + 3600;
+    set_time(&env, payout_time);
+    client.execute_payout(&group_id);
+
+    client.contribute(&creator, &group_id);
+    client.contribute(&member2, &group_id);
+
+    // Missing the catch-up window falls back to normal grace-period penalties
+    set_time(&env, payout_time + 86400 + 60);
+    client.contribute(&member3, &group_id);
+    let record = client.get_contribution_detail(&group_id, &2u32, &member3);
+    assert!(record.is_late);
+    assert_eq!(record.penalty_amount, 5_000_000);
+}
+
+#[test]
+fn test_calendar_schedule_uses_explicit_start_times() {
+    let (env, client, [creator, member2, member3]) = setup_test_env();
+    let members = [&creator, &member2, &member3];
+    let start = env.ledger().timestamp();
+
+    // Irregular month lengths: 31, 28 and 31 days
+    let day = 86400u64;
+    let starts = vec![&env, start + day, start + 32 * day, start + 60 * day];
+    let group_id = create_scheduled_group(&client, &members, &CycleSchedule::Calendar(starts));
+
+    let status = client.get_group_status(&group_id);
+    assert_eq!(status.cycle_start_time, start + day);
+    assert_eq!(status.cycle_end_time, start + 32 * day);
+
+    set_time(&env, start + day);
+    contribute_all(&client, group_id, &members);
+    set_time(&env, start + 33 * day + 600);
+    client.execute_payout(&group_id);
+
+    let status = client.get_group_status(&group_id);
+    assert_eq!(status.cycle_start_time, start + 32 * day);
+    assert_eq!(status.cycle_end_time, start + 60 * day);
+
+    contribute_all(&client, group_id, &members);
+    set_time(&env, start + 61 * day);
+    client.execute_payout(&group_id);
+
+    // Last calendar cycle lasts one cycle_duration
+    let status = client.get_group_status(&group_id);
+    assert_eq!(status.cycle_start_time, start + 60 * day);
+    assert_eq!(status.cycle_end_time, start + 60 * day + CYCLE);
+}
+
+#[test]
+fn test_invalid_calendar_rejected() {
+    let (env, client, [creator, _, _]) = setup_test_env();
+    let start = env.ledger().timestamp();
+
+    let group_id = client.create_group(&creator, &CONTRIBUTION, &CYCLE, &3u32, &GRACE, &5u32);
+
+    // Fewer start times than possible cycles
+    let too_few = CycleSchedule::Calendar(vec![&env, start, start + CYCLE]);
+    let result = client.try_set_cycle_schedule(&group_id, &too_few);
+    assert_eq!(result, Err(Ok(AjoError::InvalidCycleSchedule)));
+
+    // Start times must be strictly ascending
+    let unordered = CycleSchedule::Calendar(vec![&env, start, start + CYCLE, start + CYCLE]);
+    let result = client.try_set_cycle_schedule(&group_id, &unordered);
+    assert_eq!(result, Err(Ok(AjoError::InvalidCycleSchedule)));
+}
+
+#[test]
+fn test_cycle_schedule_locked_after_join() {
+    let (_env, client, [creator, member2, member3]) = setup_test_env();
+    let members = [&creator, &member2, &member3];
+    let group_id = create_scheduled_group(&client, &members, &CycleSchedule::Anchored);
+
+    let result = client.try_set_cycle_schedule(&group_id, &CycleSchedule::Rolling);
+    assert_eq!(result, Err(Ok(AjoError::GroupTermsLocked)));
+}
@@ -0,0 +1,366 @@
+#![cfg(test)]
+
+mod common;
+
+use soroban_ajo::{
+    AjoContractClient, CycleSchedule, GroupKind, GroupTemplate, GroupTerms,
+    LinearPenalty, OrganizerCommission, PayoutSplit, PenaltyPolicy, PenaltySchedule, YieldPolicy,
+    EVENT_SCHEMA_VERSION,
+};
+use soroban_sdk::{
+    testutils::{Address as _, Events, Ledger},
+    token::StellarAssetClient,
+    vec, Address, Env, IntoVal, Map, String, Symbol, Val,
+};
+
+use common::setup_test_env;
+
+const CONTRIBUTION: i128 = 100_000_000;
+const CYCLE: u64 = 604_800;
+const DAY: u64 = 86400;
+
+/// The terms `create_group` gives a three-member group
+fn default_terms() -> GroupTerms {
+    GroupTerms {
+        contribution_amount: CONTRIBUTION,
+        cycle_duration: CYCLE,
+        contribution_interval: 0,
+        max_members: 3,
+        grace_period: DAY,
+        penalty_rate: 5,
+        commission: OrganizerCommission::None,
+        penalty_policy: PenaltyPolicy::NextRecipient,
+        penalty_schedule: PenaltySchedule::Flat,
+        cycle_schedule: CycleSchedule::Rolling,
+        keeper_bounty: 0,
+        token: None,
+        seat_transfer_needs_approval: false,
+        max_seats_per_member: 1,
+        max_contribution_tier: 1,
+        recipients_per_cycle: 1,
+        insurance_premium_bps: 0,
+        vault: None,
+        yield_policy: YieldPolicy::Recipient,
+        kind: GroupKind::Rotating,
+        savings_cycles: 0,
+        early_withdrawal_bps: 0,
+        shuffle_on_renewal: false,
+    }
+}
+
+/// Runs `call` and asserts the contract emitted exactly the `expected` events, in
+/// order, each following the schema: a `(name, group_id)` topic and a payload
+/// carrying the schema version and the same group id.
+fn assert_emits<R>(
+    env: &Env,
+    client: &AjoContractClient,
+    expected: &[&str],
+    call: impl FnOnce() -> R,
+) -> R {
+    let start = env.events().all().len();
+    let result = call();
+
+    let mut emitted = std::vec::Vec::new();
+    for (contract, topics, data) in env.events().all().iter().skip(start as usize) {
+        if contract != client.address {
+            continue;
+        }
+        assert_eq!(topics.len(), 2);
+        let name: Symbol = topics.get_unchecked(0).into_val(env);
+        let group_id: u64 = topics.get_unchecked(1).into_val(env);
+        let payload: Map<Symbol, Val> = data.into_val(env);
+        let version: u32 = payload.get_unchecked(Symbol::new(env, "version")).into_val(env);
+        let payload_group_id: u64 = payload.get_unchecked(Symbol::new(env, "group_id")).into_val(env);
+        assert_eq!(version, EVENT_SCHEMA_VERSION);
+        assert_eq!(payload_group_id, group_id);
+        emitted.push(name);
+    }
+
+    let expected: std::vec::Vec<Symbol> = expected.iter().map(|name| Symbol::new(env, name)).collect();
+    assert_eq!(emitted, expected);
+    result
+}
+
+/// Advances past the current cycle's grace period
+fn expire_grace_period(env: &Env) {
+    env.ledger().with_mut(|li| {
+        li.timestamp += CYCLE + DAY + 1;
+    });
+}
+
+#[test]
+fn test_admin_entry_points() {
+    let (env, client, [creator, _member2, _member3]) = setup_test_env();
+
+    assert_emits(&env, &client, &["init"], || client.initialize(&creator));
+    assert_emits(&env, &client, &["paused"], || client.pause());
+    assert_emits(&env, &client, &["unpaused"], || client.unpause());
+
+    // Failed calls emit nothing
+    assert_emits(&env, &client, &[], || client.try_initialize(&creator).is_err());
+}
+
+#[test]
+fn test_group_setting_entry_points() {
+    let (env, client, [creator, member2, _member3]) = setup_test_env();
+    let token = Address::generate(&env);
+
+    let group_id = assert_emits(&env, &client, &["created"], || {
+        client.create_group(&creator, &CONTRIBUTION, &CYCLE, &3u32, &DAY, &5u32)
+    });
+    assert_emits(&env, &client, &["tokenset"], || client.set_contribution_token(&group_id, &token));
+    assert_emits(&env, &client, &["seatappr"], || client.set_seat_transfer_approval(&group_id, &true));
+    assert_emits(&env, &client, &["seatmax"], || client.set_max_seats_per_member(&group_id, &2u32));
+    assert_emits(&env, &client, &["intervals"], || client.set_intervals(&group_id, &DAY, &CYCLE));
+    assert_emits(&env, &client, &["tiermax"], || client.set_max_contribution_tier(&group_id, &2u32));
+    assert_emits(&env, &client, &["payees"], || client.set_recipients_per_cycle(&group_id, &1u32));
+    assert_emits(&env, &client, &["renewshuf"], || client.set_renewal_shuffle(&group_id, &true));
+    assert_emits(&env, &client, &["insure"], || client.set_insurance_premium(&group_id, &100u32));
+    assert_emits(&env, &client, &["vaultset"], || {
+        client.set_vault(&group_id, &Address::generate(&env), &YieldPolicy::GroupReserve)
+    });
+    assert_emits(&env, &client, &["bountyset"], || client.set_keeper_bounty(&group_id, &1_000i128));
+    assert_emits(&env, &client, &["commset"], || {
+        client.set_organizer_commission(&group_id, &OrganizerCommission::BasisPoints(100))
+    });
+    assert_emits(&env, &client, &["penpolicy"], || {
+        client.set_penalty_policy(&group_id, &PenaltyPolicy::GroupReserve)
+    });
+    assert_emits(&env, &client, &["pensched"], || {
+        client.set_penalty_schedule(&group_id, &PenaltySchedule::Flat)
+    });
+    assert_emits(&env, &client, &["cyclesch"], || {
+        client.set_cycle_schedule(&group_id, &CycleSchedule::Anchored)
+    });
+    let name = String::from_str(&env, "Market traders");
+    assert_emits(&env, &client, &["metadata"], || {
+        client.set_group_metadata(&group_id, &name, &name, &name)
+    });
+
+    let savings_id = client.create_group(&creator, &CONTRIBUTION, &CYCLE, &2u32, &DAY, &5u32);
+    assert_emits(&env, &client, &["savings"], || client.set_target_savings(&savings_id, &2u32, &1_000u32));
+
+    // Templates aren't tied to a group; groups made from them or copied are
+    let terms = GroupTerms { seat_transfer_needs_approval: true, max_seats_per_member: 2, ..default_terms() };
+    let template = GroupTemplate { creator: creator.clone(), terms };
+    let template_id = assert_emits(&env, &client, &["template"], || client.save_template(&template, &None));
+    assert_emits(&env, &client, &["created", "seatappr", "seatmax", "fromtmpl"], || {
+        client.create_group_from_template(&member2, &template_id)
+    });
+    let cloned = [
+        "created", "tokenset", "intervals", "commset", "penpolicy", "bountyset", "seatappr", "seatmax",
+        "tiermax", "insure", "vaultset", "cyclesch", "renewshuf", "cloned",
+    ];
+    assert_emits(&env, &client, &cloned, || client.clone_group(&member2, &group_id));
+}
+
+#[test]
+fn test_group_terms_entry_point() {
+    let (env, client, [creator, _member2, _member3]) = setup_test_env();
+
+    let terms = GroupTerms {
+        contribution_interval: DAY,
+        commission: OrganizerCommission::BasisPoints(100),
+        penalty_policy: PenaltyPolicy::GroupReserve,
+        penalty_schedule: PenaltySchedule::Linear(LinearPenalty { bps_per_hour: 10, cap_bps: 1_000 }),
+        cycle_schedule: CycleSchedule::Anchored,
+        keeper_bounty: 1_000,
+        token: Some(Address::generate(&env)),
+        seat_transfer_needs_approval: true,
+        max_seats_per_member: 2,
+        max_contribution_tier: 2,
+        recipients_per_cycle: 2,
+        insurance_premium_bps: 100,
+        vault: Some(Address::generate(&env)),
+        yield_policy: YieldPolicy::GroupReserve,
+        kind: GroupKind::TargetSavings,
+        savings_cycles: 2,
+        early_withdrawal_bps: 1_000,
+        shuffle_on_renewal: true,
+        ..default_terms()
+    };
+    let expected = [
+        "created", "tokenset", "intervals", "commset", "penpolicy", "pensched", "bountyset",
+        "seatappr", "seatmax", "tiermax", "payees", "insure", "vaultset", "savings", "cyclesch",
+        "renewshuf",
+    ];
+    assert_emits(&env, &client, &expected, || client.create_group_with_terms(&creator, &terms));
+}
+
+#[test]
+fn test_membership_entry_points() {
+    let (env, client, [creator, member2, member3]) = setup_test_env();
+    let group_id = client.create_group(&creator, &CONTRIBUTION, &CYCLE, &4u32, &DAY, &5u32);
+    client.set_max_contribution_tier(&group_id, &2u32);
+
+    let half_a = Address::generate(&env);
+    let half_b = Address::generate(&env);
+    assert_emits(&env, &client, &["joined"], || client.join_group(&member2, &group_id));
+    assert_emits(&env, &client, &["joined", "seatshare"], || {
+        client.join_shared_seat(&group_id, &vec![&env, half_a.clone(), half_b.clone()])
+    });
+    assert_emits(&env, &client, &["joined"], || client.join_group(&member3, &group_id));
+
+    assert_emits(&env, &client, &["seattier"], || client.set_seat_tier(&member3, &group_id, &3u32, &1u32));
+    let splits = vec![&env, PayoutSplit { recipient: Address::generate(&env), bps: 10_000 }];
+    assert_emits(&env, &client, &["splitset"], || client.set_payout_splits(&member2, &group_id, &splits));
+    assert_emits(&env, &client, &["renewopt"], || client.opt_in_next_round(&member2, &group_id));
+
+    let newcomer = Address::generate(&env);
+    assert_emits(&env, &client, &["seatxfer"], || client.transfer_seat(&group_id, &member2, &newcomer));
+    assert_emits(&env, &client, &["swapprop"], || client.propose_swap(&group_id, &newcomer, &member3));
+    assert_emits(&env, &client, &["swapped"], || client.accept_swap(&group_id, &newcomer, &member3));
+}
+
+#[test]
+fn test_contribution_entry_points() {
+    let (env, client, [creator, member2, member3]) = setup_test_env();
+    let group_id = client.create_group(&creator, &CONTRIBUTION, &CYCLE, &3u32, &DAY, &5u32);
+    client.set_contribution_token(&group_id, &Address::generate(&env));
+    client.set_insurance_premium(&group_id, &100u32);
+    client.join_group(&member2, &group_id);
+    client.join_group(&member3, &group_id);
+
+    assert_emits(&env, &client, &["premium", "contrib"], || client.contribute(&creator, &group_id));
+    assert_emits(&env, &client, &["premium", "install"], || {
+        client.contribute_partial(&member2, &group_id, &(CONTRIBUTION / 2))
+    });
+    assert_emits(&env, &client, &["premium", "install", "contrib"], || {
+        client.contribute_partial(&member2, &group_id, &(CONTRIBUTION / 2))
+    });
+    let sponsor = Address::generate(&env);
+    assert_emits(&env, &client, &["premium", "sponsored", "contrib"], || {
+        client.contribute_for(&sponsor, &member3, &group_id)
+    });
+    assert_emits(&env, &client, &["premium", "prepaid"], || client.prepay(&member3, &group_id, &1u32));
+
+    // The prepaid cycle is credited when it starts
+    expire_grace_period(&env);
+    assert_emits(&env, &client, &["payout", "cycle", "contrib"], || client.execute_payout(&group_id));
+
+    // Paying after the cycle ends but within the grace period is late
+    env.ledger().with_mut(|li| {
+        li.timestamp += CYCLE + 60;
+    });
+    assert_emits(&env, &client, &["premium", "install", "late"], || {
+        client.contribute_seat(&member2, &group_id, &1u32)
+    });
+}
+
+#[test]
+fn test_payout_entry_points() {
+    let (env, client, [creator, member2, _member3]) = setup_test_env();
+    let keeper = Address::generate(&env);
+    let group_id = client.create_group(&creator, &CONTRIBUTION, &CYCLE, &2u32, &DAY, &5u32);
+    client.set_organizer_commission(&group_id, &OrganizerCommission::BasisPoints(100));
+    client.set_keeper_bounty(&group_id, &1_000_000i128);
+    client.join_group(&member2, &group_id);
+    client.opt_in_next_round(&member2, &group_id);
+
+    // A late contribution's penalty is added to the payout
+    client.contribute(&creator, &group_id);
+    env.ledger().with_mut(|li| {
+        li.timestamp += CYCLE + 60;
+    });
+    client.contribute(&member2, &group_id);
+    env.ledger().with_mut(|li| {
+        li.timestamp += DAY;
+    });
+    assert_emits(&env, &client, &["cranked", "commpaid", "pendistr", "payout", "cycle"], || {
+        client.crank(&keeper, &group_id)
+    });
+
+    // A batched crank skips a group that isn't ready and completes one that is
+    let waiting = client.create_group(&creator, &CONTRIBUTION, &CYCLE, &2u32, &DAY, &5u32);
+    client.join_group(&member2, &waiting);
+    client.contribute(&creator, &group_id);
+    client.contribute(&member2, &group_id);
+    expire_grace_period(&env);
+    assert_emits(
+        &env,
+        &client,
+        &["skipped", "cranked", "commpaid", "payout", "complete", "created", "renewed"],
+        || client.crank_many(&keeper, &vec![&env, waiting, group_id]),
+    );
+}
+
+#[test]
+fn test_auto_contribute_entry_points() {
+    let (env, client, [creator, member2, _member3]) = setup_test_env();
+    let keeper = Address::generate(&env);
+    let token = env.register_stellar_asset_contract_v2(Address::generate(&env)).address();
+    StellarAssetClient::new(&env, &token).mint(&member2, &(10 * CONTRIBUTION));
+    let group_id = client.create_group(&creator, &CONTRIBUTION, &CYCLE, &2u32, &DAY, &5u32);
+    client.set_contribution_token(&group_id, &token);
+    client.join_group(&member2, &group_id);
+
+    assert_emits(&env, &client, &["autoon"], || client.enable_auto_contribute(&member2, &group_id, &2u32));
+
+    // Without an allowance the pull fails
+    assert_emits(&env, &client, &["autofail"], || client.collect_auto_contributions(&keeper, &group_id));
+    assert_emits(&env, &client, &["autooff"], || client.revoke_auto_contribute(&member2, &group_id));
+}
+
+#[test]
+fn test_target_savings_entry_points() {
+    let (env, client, [creator, member2, _member3]) = setup_test_env();
+    let group_id = client.create_group(&creator, &CONTRIBUTION, &CYCLE, &2u32, &DAY, &5u32);
+    client.set_target_savings(&group_id, &2u32, &1_000u32);
+    client.join_group(&member2, &group_id);
+
+    client.contribute(&creator, &group_id);
+    client.contribute(&member2, &group_id);
+    expire_grace_period(&env);
+    assert_emits(&env, &client, &["saveclose", "cycle"], || client.execute_payout(&group_id));
+
+    // Withdrawing before maturity costs the early withdrawal penalty
+    assert_emits(&env, &client, &["penpooled", "savewdraw"], || client.withdraw_savings(&creator, &group_id));
+
+    // The penalty is shared with the remaining saver at maturity
+    client.contribute(&member2, &group_id);
+    expire_grace_period(&env);
+    assert_emits(&env, &client, &["saveclose", "penshare", "complete"], || client.execute_payout(&group_id));
+}
+
+#[test]
+fn test_refund_entry_points() {
+    let (env, client, [creator, member2, member3]) = setup_test_env();
+    client.initialize(&creator);
+
+    // A rejected refund vote is recorded
+    let group_id = client.create_group(&creator, &CONTRIBUTION, &CYCLE, &3u32, &DAY, &5u32);
+    client.join_group(&member2, &group_id);
+    client.join_group(&member3, &group_id);
+    client.contribute(&creator, &group_id);
+    expire_grace_period(&env);
+    assert_emits(&env, &client, &["refreq"], || client.request_refund(&member2, &group_id));
+    assert_emits(&env, &client, &["refvote"], || client.vote_refund(&member2, &group_id, &true));
+    client.vote_refund(&member3, &group_id, &false);
+    client.vote_refund(&creator, &group_id, &false);
+    expire_grace_period(&env);
+    assert_emits(&env, &client, &["refreject"], || client.execute_refund(&member2, &group_id));
+
+    // An approved one refunds each payer
+    let approved = client.create_group(&creator, &CONTRIBUTION, &CYCLE, &2u32, &DAY, &5u32);
+    client.join_group(&member2, &approved);
+    client.contribute(&creator, &approved);
+    client.contribute(&member2, &approved);
+    expire_grace_period(&env);
+    client.request_refund(&member2, &approved);
+    client.vote_refund(&member2, &approved, &true);
+    client.vote_refund(&creator, &approved, &true);
+    expire_grace_period(&env);
+    assert_emits(&env, &client, &["refund", "refund", "refaccept"], || {
+        client.execute_refund(&member2, &approved)
+    });
+
+    let cancelled = client.create_group(&creator, &CONTRIBUTION, &CYCLE, &2u32, &DAY, &5u32);
+    client.join_group(&member2, &cancelled);
+    client.contribute(&member2, &cancelled);
+    assert_emits(&env, &client, &["refund", "cancel"], || client.cancel_group(&creator, &cancelled));
+
+    let disputed = client.create_group(&creator, &CONTRIBUTION, &CYCLE, &2u32, &DAY, &5u32);
+    client.join_group(&member2, &disputed);
+    client.contribute(&creator, &disputed);
+    assert_emits(&env, &client, &["refund", "emrefund"], || client.emergency_refund(&creator, &disputed));
+}
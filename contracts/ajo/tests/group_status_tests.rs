@@ -35,13 +35,13 @@ fn test_group_status_initial_state() {
     // Verify initial state
     assert_eq!(status.group_id, group_id);
     assert_eq!(status.current_cycle, 1);
-    assert_eq!(status.has_next_recipient, true);
+    assert!(status.has_next_recipient);
     assert_eq!(status.next_recipient, creator.clone());
     assert_eq!(status.contributions_received, 0);
     assert_eq!(status.total_members, 3);
     assert_eq!(status.pending_contributors.len(), 3);
-    assert_eq!(status.is_complete, false);
-    assert_eq!(status.is_cycle_active, true);
+    assert!(!status.is_complete);
+    assert!(status.is_cycle_active);
 }
 
 #[test]
@@ -65,7 +65,7 @@ fn test_group_status_partial_contributions() {
     assert_eq!(status.total_members, 3);
     assert_eq!(status.pending_contributors.len(), 1);
     assert_eq!(status.pending_contributors.get(0).unwrap(), member3);
-    assert_eq!(status.is_complete, false);
+    assert!(!status.is_complete);
 }
 
 #[test]
@@ -89,7 +89,7 @@ fn test_group_status_all_contributed() {
     assert_eq!(status.contributions_received, 3);
     assert_eq!(status.total_members, 3);
     assert_eq!(status.pending_contributors.len(), 0);
-    assert_eq!(status.is_complete, false);
+    assert!(!status.is_complete);
 }
 
 #[test]
@@ -118,11 +118,11 @@ fn test_group_status_after_payout() {
 
     // Verify state after payout
     assert_eq!(status.current_cycle, 2);
-    assert_eq!(status.has_next_recipient, true);
+    assert!(status.has_next_recipient);
     assert_eq!(status.next_recipient, member2.clone());
     assert_eq!(status.contributions_received, 0); // Reset for new cycle
     assert_eq!(status.pending_contributors.len(), 3); // All pending again
-    assert_eq!(status.is_complete, false);
+    assert!(!status.is_complete);
 }
 
 #[test]
@@ -166,11 +166,11 @@ fn test_group_status_mid_lifecycle() {
 
     // Verify mid-lifecycle state
     assert_eq!(status.current_cycle, 3);
-    assert_eq!(status.has_next_recipient, true);
+    assert!(status.has_next_recipient);
     assert_eq!(status.next_recipient, member3.clone());
     assert_eq!(status.contributions_received, 1);
     assert_eq!(status.pending_contributors.len(), 2);
-    assert_eq!(status.is_complete, false);
+    assert!(!status.is_complete);
 }
 
 #[test]
@@ -200,8 +200,8 @@ fn test_group_status_completed() {
     let status = client.get_group_status(&group_id);
 
     // Verify completed state
-    assert_eq!(status.is_complete, true);
-    assert_eq!(status.has_next_recipient, false); // No next recipient when complete
+    assert!(status.is_complete);
+    assert!(!status.has_next_recipient); // No next recipient when complete
     assert_eq!(status.current_cycle, 3); // Stays at last cycle
 }
 
@@ -220,7 +220,7 @@ fn test_group_status_cycle_timing() {
         status.cycle_end_time - status.cycle_start_time,
         cycle_duration
     );
-    assert_eq!(status.is_cycle_active, true);
+    assert!(status.is_cycle_active);
     assert!(status.current_time >= status.cycle_start_time);
     assert!(status.current_time < status.cycle_end_time);
 }
@@ -240,7 +240,7 @@ fn test_group_status_cycle_expired() {
     let status = client.get_group_status(&group_id);
 
     // Verify cycle is no longer active
-    assert_eq!(status.is_cycle_active, false);
+    assert!(!status.is_cycle_active);
     assert!(status.current_time >= status.cycle_end_time);
 }
 
@@ -258,7 +258,7 @@ fn test_group_status_single_member_group() {
     assert_eq!(status.total_members, 1);
     assert_eq!(status.contributions_received, 0);
     assert_eq!(status.pending_contributors.len(), 1);
-    assert_eq!(status.has_next_recipient, true);
+    assert!(status.has_next_recipient);
     assert_eq!(status.next_recipient, creator.clone());
 }
 
@@ -327,7 +327,7 @@ fn test_group_status_multiple_cycles_tracking() {
 
     // Final status should show completion
     let final_status = client.get_group_status(&group_id);
-    assert_eq!(final_status.is_complete, true);
+    assert!(final_status.is_complete);
 }
 
 #[test]
@@ -365,7 +365,7 @@ fn test_group_status_invalid_group_id() {
         Err(Ok(err)) => {
             // Verify it's the correct error type by checking error code
             // GroupNotFound is error code 1
-            assert_eq!(format!("{:?}", err).contains("GroupNotFound"), true);
+            assert!(format!("{:?}", err).contains("GroupNotFound"));
         }
         _ => panic!("Expected GroupNotFound error"),
     }
@@ -449,8 +449,8 @@ fn test_group_status_placeholder_address_when_complete() {
     let status = client.get_group_status(&group_id);
 
     // Verify placeholder handling
-    assert_eq!(status.is_complete, true);
-    assert_eq!(status.has_next_recipient, false);
+    assert!(status.is_complete);
+    assert!(!status.has_next_recipient);
     // next_recipient contains placeholder (creator), but has_next_recipient is false
     assert_eq!(status.next_recipient, creator);
 }
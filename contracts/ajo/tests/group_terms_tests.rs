@@ -0,0 +1,175 @@
+#![cfg(test)]
+
+mod common;
+
+use soroban_ajo::{
+    AjoContractClient, AjoError, CycleSchedule, GroupKind, GroupTerms,
+    OrganizerCommission, PenaltyPolicy, PenaltySchedule, YieldPolicy,
+};
+use soroban_sdk::{
+    testutils::{Address as _, Events, Ledger},
+    vec, Address, Env, IntoVal, Symbol,
+};
+
+use common::setup_test_env;
+
+const CONTRIBUTION: i128 = 100_000_000;
+const CYCLE: u64 = 604_800;
+const DAY: u64 = 86400;
+
+/// The terms `create_group` gives a three-member group
+fn default_terms() -> GroupTerms {
+    GroupTerms {
+        contribution_amount: CONTRIBUTION,
+        cycle_duration: CYCLE,
+        contribution_interval: 0,
+        max_members: 3,
+        grace_period: DAY,
+        penalty_rate: 5,
+        commission: OrganizerCommission::None,
+        penalty_policy: PenaltyPolicy::NextRecipient,
+        penalty_schedule: PenaltySchedule::Flat,
+        cycle_schedule: CycleSchedule::Rolling,
+        keeper_bounty: 0,
+        token: None,
+        seat_transfer_needs_approval: false,
+        max_seats_per_member: 1,
+        max_contribution_tier: 1,
+        recipients_per_cycle: 1,
+        insurance_premium_bps: 0,
+        vault: None,
+        yield_policy: YieldPolicy::Recipient,
+        kind: GroupKind::Rotating,
+        savings_cycles: 0,
+        early_withdrawal_bps: 0,
+        shuffle_on_renewal: false,
+    }
+}
+
+/// Names of the events the contract has published since `start`
+fn event_names(env: &Env, client: &AjoContractClient, start: u32) -> std::vec::Vec<Symbol> {
+    env.events()
+        .all()
+        .iter()
+        .skip(start as usize)
+        .filter(|(contract, _, _)| *contract == client.address)
+        .map(|(_, topics, _)| topics.get_unchecked(0).into_val(env))
+        .collect()
+}
+
+#[test]
+fn test_default_terms_match_create_group() {
+    let (env, client, [creator, _, _]) = setup_test_env();
+
+    let group_id = client.create_group(&creator, &CONTRIBUTION, &CYCLE, &3u32, &DAY, &5u32);
+    let start = env.events().all().len();
+    let with_terms = client.create_group_with_terms(&creator, &default_terms());
+
+    // Only the creation is announced when every term is a default
+    assert_eq!(event_names(&env, &client, start), [Symbol::new(&env, "created")]);
+    let mut group = client.get_group(&with_terms);
+    group.id = group_id;
+    assert_eq!(group, client.get_group(&group_id));
+}
+
+#[test]
+fn test_commission_calendar_and_premium_set_at_creation() {
+    let (env, client, [creator, member2, member3]) = setup_test_env();
+    let token = Address::generate(&env);
+    let now = env.ledger().timestamp();
+    let starts = vec![&env, now + DAY, now + DAY + CYCLE, now + DAY + 2 * CYCLE];
+
+    let terms = GroupTerms {
+        commission: OrganizerCommission::BasisPoints(250),
+        cycle_schedule: CycleSchedule::Calendar(starts.clone()),
+        token: Some(token.clone()),
+        insurance_premium_bps: 1_000,
+        ..default_terms()
+    };
+    let start = env.events().all().len();
+    let group_id = client.create_group_with_terms(&creator, &terms);
+
+    let group = client.get_group(&group_id);
+    assert_eq!(group.commission, OrganizerCommission::BasisPoints(250));
+    assert_eq!(group.cycle_schedule, CycleSchedule::Calendar(starts));
+    assert_eq!(group.cycle_start_time, now + DAY);
+    assert_eq!(group.token, Some(token.clone()));
+    assert_eq!(group.insurance_premium_bps, 1_000);
+    let names: std::vec::Vec<Symbol> = ["created", "tokenset", "commset", "insure", "cyclesch"]
+        .iter()
+        .map(|name| Symbol::new(&env, name))
+        .collect();
+    assert_eq!(event_names(&env, &client, start), names);
+
+    // The terms apply as if they had been set one by one
+    client.join_group(&member2, &group_id);
+    client.join_group(&member3, &group_id);
+    let status = client.get_group_status(&group_id);
+    assert_eq!(status.commission_per_payout, 7_500_000);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = now + DAY;
+    });
+    client.contribute(&creator, &group_id);
+    assert_eq!(client.get_insurance_fund_balance(&token), 10_000_000);
+
+    // And are locked once members have joined
+    let result = client.try_set_organizer_commission(&group_id, &OrganizerCommission::None);
+    assert_eq!(result, Err(Ok(AjoError::GroupTermsLocked)));
+}
+
+#[test]
+fn test_invalid_terms_rejected() {
+    let (env, client, [creator, _, _]) = setup_test_env();
+    let token = Address::generate(&env);
+
+    let cases = [
+        (GroupTerms { max_members: 1, ..default_terms() }, AjoError::MaxMembersBelowMinimum),
+        (GroupTerms { penalty_rate: 101, ..default_terms() }, AjoError::InvalidPenaltyRate),
+        (
+            GroupTerms { commission: OrganizerCommission::BasisPoints(10_001), ..default_terms() },
+            AjoError::InvalidCommission,
+        ),
+        (GroupTerms { insurance_premium_bps: 100, ..default_terms() }, AjoError::NoContributionToken),
+        (
+            GroupTerms { penalty_policy: PenaltyPolicy::InsurancePool, ..default_terms() },
+            AjoError::NoContributionToken,
+        ),
+        (
+            GroupTerms { token: Some(token), insurance_premium_bps: 1_001, ..default_terms() },
+            AjoError::InvalidGroupTerms,
+        ),
+        (
+            GroupTerms { cycle_schedule: CycleSchedule::Calendar(vec![&env, DAY, 2 * DAY]), ..default_terms() },
+            AjoError::InvalidCycleSchedule,
+        ),
+        (GroupTerms { contribution_interval: 5 * DAY, ..default_terms() }, AjoError::InvalidCycleSchedule),
+        (GroupTerms { recipients_per_cycle: 4, ..default_terms() }, AjoError::InvalidGroupTerms),
+        (GroupTerms { max_contribution_tier: 0, ..default_terms() }, AjoError::InvalidGroupTerms),
+        (GroupTerms { keeper_bounty: -1, ..default_terms() }, AjoError::InvalidGroupTerms),
+        (GroupTerms { savings_cycles: 3, ..default_terms() }, AjoError::InvalidCycleSchedule),
+        (
+            GroupTerms { kind: GroupKind::TargetSavings, ..default_terms() },
+            AjoError::InvalidCycleSchedule,
+        ),
+    ];
+    for (terms, error) in cases {
+        let result = client.try_create_group_with_terms(&creator, &terms);
+        assert_eq!(result, Err(Ok(error)), "{terms:?}");
+    }
+}
+
+#[test]
+fn test_setters_validate_the_terms_as_a_whole() {
+    let (env, client, [creator]) = setup_test_env();
+    let now = env.ledger().timestamp();
+    let group_id = client.create_group(&creator, &CONTRIBUTION, &CYCLE, &4u32, &DAY, &5u32);
+    client.set_recipients_per_cycle(&group_id, &2u32);
+    let starts = vec![&env, now + DAY, now + DAY + CYCLE];
+    client.set_cycle_schedule(&group_id, &CycleSchedule::Calendar(starts));
+
+    // One recipient per cycle needs four cycles, which the calendar doesn't cover
+    let result = client.try_set_recipients_per_cycle(&group_id, &1u32);
+    assert_eq!(result, Err(Ok(AjoError::InvalidCycleSchedule)));
+    assert_eq!(client.get_group(&group_id).recipients_per_cycle, 2);
+}
@@ -0,0 +1,172 @@
+#![cfg(test)]
+
+mod common;
+
+use soroban_ajo::{AjoContractClient, AjoError, InstallmentEvent};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Events, Ledger},
+    Address, IntoVal, Val, Vec,
+};
+
+use common::setup_test_env;
+
+const CONTRIBUTION: i128 = 100_000_000;
+const CYCLE: u64 = 604_800;
+const DAY: u64 = 86400;
+
+/// Creates a 2-member group with a one-day grace period and a 5% flat penalty
+fn create_group(client: &AjoContractClient, creator: &Address, member2: &Address) -> u64 {
+    let group_id = client.create_group(creator, &CONTRIBUTION, &CYCLE, &2u32, &DAY, &5u32);
+    client.join_group(member2, &group_id);
+    group_id
+}
+
+#[test]
+fn test_installments_accumulate_until_complete() {
+    let (_env, client, [creator, member2]) = setup_test_env();
+    let group_id = create_group(&client, &creator, &member2);
+
+    client.contribute_partial(&member2, &group_id, &25_000_000);
+    client.contribute_partial(&member2, &group_id, &25_000_000);
+
+    let record = client.get_contribution_detail(&group_id, &1u32, &member2);
+    assert_eq!(record.amount_paid, 50_000_000);
+    assert!(!record.has_paid);
+
+    let statuses = client.get_contribution_status(&group_id, &1u32);
+    assert!(!statuses.get(1).unwrap().1);
+
+    client.contribute_partial(&member2, &group_id, &50_000_000);
+
+    let record = client.get_contribution_detail(&group_id, &1u32, &member2);
+    assert_eq!(record.amount_paid, CONTRIBUTION);
+    assert!(record.has_paid);
+    assert!(!record.is_late);
+
+    let statuses = client.get_contribution_status(&group_id, &1u32);
+    assert!(statuses.get(1).unwrap().1);
+}
+
+#[test]
+fn test_contribute_pays_outstanding_balance() {
+    let (_env, client, [creator, member2]) = setup_test_env();
+    let group_id = create_group(&client, &creator, &member2);
+
+    client.contribute_partial(&member2, &group_id, &30_000_000);
+    client.contribute(&member2, &group_id);
+
+    let record = client.get_contribution_detail(&group_id, &1u32, &member2);
+    assert_eq!(record.amount_paid, CONTRIBUTION);
+    assert!(record.has_paid);
+}
+
+#[test]
+fn test_partial_contributor_blocks_payout() {
+    let (env, client, [creator, member2]) = setup_test_env();
+    let group_id = create_group(&client, &creator, &member2);
+
+    client.contribute(&creator, &group_id);
+    client.contribute_partial(&member2, &group_id, &99_999_999);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp += CYCLE + DAY + 1;
+    });
+
+    let result = client.try_execute_payout(&group_id);
+    assert_eq!(result, Err(Ok(AjoError::IncompleteContributions)));
+}
+
+#[test]
+fn test_invalid_installments_rejected() {
+    let (_env, client, [creator, member2]) = setup_test_env();
+    let group_id = create_group(&client, &creator, &member2);
+
+    let result = client.try_contribute_partial(&member2, &group_id, &0);
+    assert_eq!(result, Err(Ok(AjoError::ContributionAmountZero)));
+
+    let result = client.try_contribute_partial(&member2, &group_id, &-1);
+    assert_eq!(result, Err(Ok(AjoError::ContributionAmountNegative)));
+
+    client.contribute_partial(&member2, &group_id, &60_000_000);
+    let result = client.try_contribute_partial(&member2, &group_id, &40_000_001);
+    assert_eq!(result, Err(Ok(AjoError::InstallmentExceedsBalance)));
+
+    client.contribute_partial(&member2, &group_id, &40_000_000);
+    let result = client.try_contribute_partial(&member2, &group_id, &1);
+    assert_eq!(result, Err(Ok(AjoError::AlreadyContributed)));
+}
+
+#[test]
+fn test_penalty_applies_only_to_outstanding_balance() {
+    let (env, client, [creator, member2]) = setup_test_env();
+    let group_id = create_group(&client, &creator, &member2);
+
+    client.contribute(&creator, &group_id);
+    client.contribute_partial(&member2, &group_id, &60_000_000);
+
+    // Remaining 40 XLM is paid during the grace period (5% flat penalty)
+    env.ledger().with_mut(|li| {
+        li.timestamp += CYCLE + 3600;
+    });
+    client.contribute(&member2, &group_id);
+
+    let record = client.get_contribution_detail(&group_id, &1u32, &member2);
+    assert!(record.is_late);
+    assert_eq!(record.penalty_amount, 2_000_000);
+    assert_eq!(client.get_cycle_penalty_pool(&group_id, &1u32), 2_000_000);
+
+    let penalty_record = client.get_member_penalty_record(&group_id, &member2);
+    assert_eq!(penalty_record.late_count, 1);
+    assert_eq!(penalty_record.total_penalties, 2_000_000);
+}
+
+#[test]
+fn test_installment_after_grace_period_rejected() {
+    let (env, client, [creator, member2]) = setup_test_env();
+    let group_id = create_group(&client, &creator, &member2);
+
+    client.contribute_partial(&member2, &group_id, &50_000_000);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp += CYCLE + DAY + 1;
+    });
+
+    let result = client.try_contribute_partial(&member2, &group_id, &50_000_000);
+    assert_eq!(result, Err(Ok(AjoError::GracePeriodExpired)));
+}
+
+#[test]
+fn test_cancellation_refunds_amount_paid() {
+    let (_env, client, [creator, member2]) = setup_test_env();
+    let group_id = create_group(&client, &creator, &member2);
+
+    client.contribute(&creator, &group_id);
+    client.contribute_partial(&member2, &group_id, &35_000_000);
+
+    client.cancel_group(&creator, &group_id);
+
+    assert_eq!(client.get_refund_record(&group_id, &creator).amount, CONTRIBUTION);
+    assert_eq!(client.get_refund_record(&group_id, &member2).amount, 35_000_000);
+}
+
+#[test]
+fn test_installment_event() {
+    let (env, client, [creator, member2]) = setup_test_env();
+    let group_id = create_group(&client, &creator, &member2);
+
+    client.contribute_partial(&member2, &group_id, &25_000_000);
+
+    let expected_topics: Vec<Val> = (symbol_short!("install"), group_id).into_val(&env);
+    let event = env
+        .events()
+        .all()
+        .iter()
+        .find(|(_, topics, _)| *topics == expected_topics)
+        .expect("installment event emitted");
+    let paid: InstallmentEvent = event.2.into_val(&env);
+    assert_eq!(paid.cycle, 1);
+    assert_eq!(paid.member, member2);
+    assert_eq!(paid.amount, 25_000_000);
+    assert_eq!(paid.outstanding, 75_000_000);
+}
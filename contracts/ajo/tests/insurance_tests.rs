@@ -0,0 +1,254 @@
+#![cfg(test)]
+
+mod common;
+
+use soroban_ajo::{AjoContractClient, AjoError, MemberAmountEvent};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events, Ledger},
+    vec, Address, Env, IntoVal, Val, Vec,
+};
+
+use common::setup_test_env;
+
+const CONTRIBUTION: i128 = 100_000_000;
+const CYCLE: u64 = 604_800;
+const DAY: u64 = 86400;
+const PREMIUM_BPS: u32 = 1_000;
+
+/// Creates a full 2-member group insured in `token`
+fn create_insured_group(
+    client: &AjoContractClient,
+    creator: &Address,
+    member2: &Address,
+    token: &Address,
+    contribution: i128,
+) -> u64 {
+    let group_id = client.create_group(creator, &contribution, &CYCLE, &2u32, &DAY, &5u32);
+    client.set_contribution_token(&group_id, token);
+    client.set_insurance_premium(&group_id, &PREMIUM_BPS);
+    client.join_group(member2, &group_id);
+    group_id
+}
+
+/// Advances past the grace period
+fn expire_grace_period(env: &Env) {
+    env.ledger().with_mut(|li| {
+        li.timestamp += CYCLE + DAY + 1;
+    });
+}
+
+#[test]
+fn test_premium_paid_into_fund_per_token() {
+    let (env, client, [creator, member2, token]) = setup_test_env();
+    let group_id = create_insured_group(&client, &creator, &member2, &token, CONTRIBUTION);
+    assert_eq!(client.get_group(&group_id).insurance_premium_bps, PREMIUM_BPS);
+
+    client.contribute(&creator, &group_id);
+    client.contribute_partial(&member2, &group_id, &(CONTRIBUTION / 2));
+
+    assert_eq!(client.get_insurance_fund_balance(&token), 15_000_000);
+    assert_eq!(client.get_insurance_fund_balance(&Address::generate(&env)), 0);
+
+    let expected_topics: Vec<Val> = (symbol_short!("premium"), group_id).into_val(&env);
+    let event = env
+        .events()
+        .all()
+        .iter()
+        .find(|(_, topics, _)| *topics == expected_topics)
+        .expect("premium event emitted");
+    let premium: MemberAmountEvent = event.2.into_val(&env);
+    assert_eq!((premium.cycle, premium.member, premium.amount), (1, creator, 10_000_000));
+}
+
+#[test]
+fn test_fund_covers_default_after_payout() {
+    let (env, client, [creator, member2, token]) = setup_test_env();
+    let group_id = create_insured_group(&client, &creator, &member2, &token, CONTRIBUTION);
+
+    // A larger group sharing the same token tops up the fund
+    let other_creator = Address::generate(&env);
+    let other_member = Address::generate(&env);
+    let other_group =
+        create_insured_group(&client, &other_creator, &other_member, &token, 10 * CONTRIBUTION);
+    client.contribute(&other_creator, &other_group);
+    client.contribute(&other_member, &other_group);
+
+    client.contribute(&creator, &group_id);
+    client.contribute(&member2, &group_id);
+    expire_grace_period(&env);
+    client.execute_payout(&group_id);
+    assert_eq!(client.get_insurance_fund_balance(&token), 220_000_000);
+
+    // The creator has been paid and stops contributing
+    client.contribute(&member2, &group_id);
+    expire_grace_period(&env);
+    assert!(client.is_payout_due(&group_id));
+    client.execute_payout(&group_id);
+
+    assert!(client.is_complete(&group_id));
+    assert_eq!(client.get_payout_record(&group_id, &member2).cycle, 2);
+    assert_eq!(client.get_insurance_fund_balance(&token), 130_000_000);
+
+    let claim = client.get_insurance_claim(&group_id, &2u32, &creator).unwrap();
+    assert_eq!(claim.amount, CONTRIBUTION);
+    assert_eq!(claim.token, token);
+
+    let record = client.get_contribution_detail(&group_id, &2u32, &creator);
+    assert!(record.has_paid && record.is_late);
+    assert_eq!(record.payer, client.address);
+    assert_eq!(client.get_member_penalty_record(&group_id, &creator).late_count, 1);
+
+    let expected_topics: Vec<Val> = (symbol_short!("insclaim"), group_id).into_val(&env);
+    let event = env
+        .events()
+        .all()
+        .iter()
+        .find(|(_, topics, _)| *topics == expected_topics)
+        .expect("insurance claim event emitted");
+    let paid: MemberAmountEvent = event.2.into_val(&env);
+    assert_eq!((paid.cycle, paid.member, paid.amount), (2, creator, CONTRIBUTION));
+}
+
+#[test]
+fn test_members_awaiting_payout_are_not_covered() {
+    let (env, client, [creator, member2, token]) = setup_test_env();
+    let group_id = create_insured_group(&client, &creator, &member2, &token, CONTRIBUTION);
+
+    let other_group = create_insured_group(
+        &client,
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &token,
+        10 * CONTRIBUTION,
+    );
+    let other_creator = client.get_group(&other_group).creator;
+    client.contribute(&other_creator, &other_group);
+
+    // Member2 hasn't been paid yet, so their default isn't insured
+    client.contribute(&creator, &group_id);
+    expire_grace_period(&env);
+    let result = client.try_execute_payout(&group_id);
+    assert_eq!(result, Err(Ok(AjoError::IncompleteContributions)));
+    assert!(client.get_insurance_claim(&group_id, &1u32, &member2).is_none());
+}
+
+#[test]
+fn test_member_with_a_seat_still_to_be_paid_is_not_covered() {
+    let (env, client, [creator, member2, token]) = setup_test_env();
+    let group_id = client.create_group(&creator, &CONTRIBUTION, &CYCLE, &3u32, &DAY, &5u32);
+    client.set_contribution_token(&group_id, &token);
+    client.set_insurance_premium(&group_id, &PREMIUM_BPS);
+    client.set_max_seats_per_member(&group_id, &2u32);
+    client.join_group(&member2, &group_id);
+    client.join_group(&member2, &group_id);
+
+    let other_group = create_insured_group(
+        &client,
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &token,
+        10 * CONTRIBUTION,
+    );
+    for member in client.list_members(&other_group).iter() {
+        client.contribute(&member, &other_group);
+    }
+
+    for _ in 0..2 {
+        client.contribute(&creator, &group_id);
+        client.contribute(&member2, &group_id);
+        expire_grace_period(&env);
+        client.execute_payout(&group_id);
+    }
+
+    // Member2's first seat has been paid, but the last cycle pays their second
+    client.contribute(&creator, &group_id);
+    expire_grace_period(&env);
+    let result = client.try_execute_payout(&group_id);
+    assert_eq!(result, Err(Ok(AjoError::IncompleteContributions)));
+    assert!(client.get_insurance_claim(&group_id, &3u32, &member2).is_none());
+}
+
+#[test]
+fn test_crank_many_covers_nothing_when_payout_is_not_ready() {
+    let (env, client, [creator, member2, member3, token, keeper]) = setup_test_env();
+    let group_id = client.create_group(&creator, &CONTRIBUTION, &CYCLE, &3u32, &DAY, &5u32);
+    client.set_contribution_token(&group_id, &token);
+    client.set_insurance_premium(&group_id, &PREMIUM_BPS);
+    client.join_group(&member2, &group_id);
+    client.join_group(&member3, &group_id);
+
+    // A larger group sharing the same token tops up the fund
+    let other_creator = Address::generate(&env);
+    let other_member = Address::generate(&env);
+    let other_group =
+        create_insured_group(&client, &other_creator, &other_member, &token, 10 * CONTRIBUTION);
+    client.contribute(&other_creator, &other_group);
+    client.contribute(&other_member, &other_group);
+
+    client.contribute(&creator, &group_id);
+    client.contribute(&member2, &group_id);
+    client.contribute(&member3, &group_id);
+    expire_grace_period(&env);
+    client.execute_payout(&group_id);
+    assert_eq!(client.get_insurance_fund_balance(&token), 230_000_000);
+
+    // The paid creator's default is insurable, but member3 hasn't paid either
+    client.contribute(&member2, &group_id);
+    expire_grace_period(&env);
+    assert!(!client.is_payout_due(&group_id));
+
+    // The skipped group keeps its fund and records no claim
+    let cranked = client.crank_many(&keeper, &vec![&env, group_id]);
+    assert!(cranked.is_empty());
+    assert_eq!(client.get_insurance_fund_balance(&token), 240_000_000);
+    assert!(client.get_insurance_claim(&group_id, &2u32, &creator).is_none());
+    assert_eq!(client.get_member_penalty_record(&group_id, &creator).late_count, 0);
+
+}
+
+#[test]
+fn test_default_not_covered_when_fund_is_short() {
+    let (env, client, [creator, member2, token]) = setup_test_env();
+    let group_id = create_insured_group(&client, &creator, &member2, &token, CONTRIBUTION);
+
+    client.contribute(&creator, &group_id);
+    client.contribute(&member2, &group_id);
+    expire_grace_period(&env);
+    client.execute_payout(&group_id);
+
+    client.contribute(&member2, &group_id);
+    expire_grace_period(&env);
+    let result = client.try_execute_payout(&group_id);
+    assert_eq!(result, Err(Ok(AjoError::IncompleteContributions)));
+    assert_eq!(client.get_insurance_fund_balance(&token), 30_000_000);
+}
+
+#[test]
+fn test_uninsured_group_pays_no_premium() {
+    let (_env, client, [creator, member2, token]) = setup_test_env();
+    let group_id = client.create_group(&creator, &CONTRIBUTION, &CYCLE, &2u32, &DAY, &5u32);
+    client.set_contribution_token(&group_id, &token);
+    client.join_group(&member2, &group_id);
+
+    client.contribute(&creator, &group_id);
+    client.prepay(&member2, &group_id, &1u32);
+    assert_eq!(client.get_insurance_fund_balance(&token), 0);
+}
+
+#[test]
+fn test_invalid_premiums_rejected() {
+    let (_env, client, [creator, member2, token]) = setup_test_env();
+    let group_id = client.create_group(&creator, &CONTRIBUTION, &CYCLE, &2u32, &DAY, &5u32);
+
+    let result = client.try_set_insurance_premium(&group_id, &PREMIUM_BPS);
+    assert_eq!(result, Err(Ok(AjoError::NoContributionToken)));
+
+    client.set_contribution_token(&group_id, &token);
+    let result = client.try_set_insurance_premium(&group_id, &(PREMIUM_BPS + 1));
+    assert_eq!(result, Err(Ok(AjoError::InvalidGroupTerms)));
+
+    client.join_group(&member2, &group_id);
+    let result = client.try_set_insurance_premium(&group_id, &PREMIUM_BPS);
+    assert_eq!(result, Err(Ok(AjoError::GroupTermsLocked)));
+}
@@ -57,7 +57,7 @@ fn test_full_lifecycle_create_join_contribute_payout_complete() {
     assert_eq!(group.members.len(), 1);
     assert_eq!(group.current_cycle, 1);
     assert_eq!(group.payout_index, 0);
-    assert_eq!(group.is_complete, false);
+    assert!(!group.is_complete);
 
     // Step 2: Join - remaining members join the group
     for member in &members[1..] {
@@ -69,7 +69,7 @@ fn test_full_lifecycle_create_join_contribute_payout_complete() {
     assert_eq!(member_list.len(), 5);
 
     for member in &members {
-        assert_eq!(client.is_member(&group_id, member), true);
+        assert!(client.is_member(&group_id, member));
     }
 
     // Step 3: Contribute & Payout - Complete all cycles
@@ -83,7 +83,7 @@ fn test_full_lifecycle_create_join_contribute_payout_complete() {
         let status = client.get_contribution_status(&group_id, &(cycle as u32));
         assert_eq!(status.len(), 5);
         for (_, has_paid) in status.iter() {
-            assert_eq!(has_paid, true);
+            assert!(has_paid);
         }
 
         // Execute payout
@@ -97,16 +97,16 @@ fn test_full_lifecycle_create_join_contribute_payout_complete() {
         if cycle < 5 {
             assert_eq!(group.current_cycle, (cycle + 1) as u32);
             assert_eq!(group.payout_index, cycle as u32);
-            assert_eq!(group.is_complete, false);
+            assert!(!group.is_complete);
         } else {
             // Step 4: Complete - After final cycle
-            assert_eq!(group.is_complete, true);
+            assert!(group.is_complete);
             assert_eq!(group.payout_index, 5);
         }
     }
 
     // Verify final completion state
-    assert_eq!(client.is_complete(&group_id), true);
+    assert!(client.is_complete(&group_id));
 }
 
 #[test]
@@ -159,13 +159,13 @@ fn test_multiple_groups_independent_lifecycle() {
         complete_cycle(&env, &client, &group_id1, &group1_members);
     }
 
-    assert_eq!(client.is_complete(&group_id1), true);
-    assert_eq!(client.is_complete(&group_id2), false);
+    assert!(client.is_complete(&group_id1));
+    assert!(!client.is_complete(&group_id2));
 
     // Verify group 2 is still at cycle 1 and independent
     let g2_final = client.get_group(&group_id2);
     assert_eq!(g2_final.current_cycle, 1);
-    assert_eq!(g2_final.is_complete, false);
+    assert!(!g2_final.is_complete);
 }
 
 #[test]
@@ -184,8 +184,8 @@ fn test_multiple_groups_with_overlapping_members() {
     client.join_group(&members[4], &group_id2);
 
     // Verify member 1 is in both groups
-    assert_eq!(client.is_member(&group_id1, &members[1]), true);
-    assert_eq!(client.is_member(&group_id2, &members[1]), true);
+    assert!(client.is_member(&group_id1, &members[1]));
+    assert!(client.is_member(&group_id2, &members[1]));
 
     // Member 1 can contribute to both groups independently
     client.contribute(&members[0], &group_id1);
@@ -291,7 +291,7 @@ fn test_failure_scenario_contribute_after_completion() {
         complete_cycle(&env, &client, &group_id, &members);
     }
 
-    assert_eq!(client.is_complete(&group_id), true);
+    assert!(client.is_complete(&group_id));
 
     // Try to contribute to completed group
     let result = client.try_contribute(&members[0], &group_id);
@@ -380,7 +380,7 @@ fn test_complex_scenario_partial_completion_with_recovery() {
 
     // Complete final cycle
     complete_cycle(&env, &client, &group_id, &members);
-    assert_eq!(client.is_complete(&group_id), true);
+    assert!(client.is_complete(&group_id));
 }
 
 #[test]
@@ -406,13 +406,13 @@ fn test_large_group_full_lifecycle() {
         let group = client.get_group(&group_id);
         if cycle < 10 {
             assert_eq!(group.current_cycle, (cycle + 1) as u32);
-            assert_eq!(group.is_complete, false);
+            assert!(!group.is_complete);
         } else {
-            assert_eq!(group.is_complete, true);
+            assert!(group.is_complete);
         }
     }
 
-    assert_eq!(client.is_complete(&group_id), true);
+    assert!(client.is_complete(&group_id));
 }
 
 #[test]
@@ -430,7 +430,7 @@ fn test_sequential_group_creation_and_completion() {
         complete_cycle(&env, &client, &group_id1, &members);
     }
 
-    assert_eq!(client.is_complete(&group_id1), true);
+    assert!(client.is_complete(&group_id1));
 
     // Create second group with same members
     let group_id2 = client.create_group(&members[0], &150_000_000i128, &604_800u64, &3u32, &86400u64, &5u32);
@@ -443,8 +443,8 @@ fn test_sequential_group_creation_and_completion() {
 
     let g2 = client.get_group(&group_id2);
     assert_eq!(g2.current_cycle, 2);
-    assert_eq!(g2.is_complete, false);
+    assert!(!g2.is_complete);
 
     // First group should still be complete
-    assert_eq!(client.is_complete(&group_id1), true);
+    assert!(client.is_complete(&group_id1));
 }
@@ -0,0 +1,218 @@
+#![cfg(test)]
+
+mod common;
+
+use soroban_ajo::{
+    AjoContractClient, AjoError, CrankSkippedEvent, KeeperCrankedEvent,
+    MemberAmountEvent, OrganizerCommission,
+};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Events, Ledger},
+    vec, Address, Env, IntoVal, Val, Vec,
+};
+
+use common::setup_test_env;
+
+const CONTRIBUTION: i128 = 100_000_000;
+const CYCLE: u64 = 604_800;
+const GRACE: u64 = 86400;
+
+/// Creates a 2-member group with the given keeper bounty and commission
+fn create_group_with_bounty(
+    client: &AjoContractClient,
+    creator: &Address,
+    member2: &Address,
+    bounty: i128,
+    commission: &OrganizerCommission,
+) -> u64 {
+    let group_id = client.create_group(creator, &CONTRIBUTION, &CYCLE, &2u32, &GRACE, &5u32);
+    client.set_keeper_bounty(&group_id, &bounty);
+    client.set_organizer_commission(&group_id, commission);
+    client.join_group(member2, &group_id);
+    group_id
+}
+
+fn advance_past_grace(env: &Env) {
+    env.ledger().with_mut(|li| {
+        li.timestamp += CYCLE + GRACE + 1;
+    });
+}
+
+#[test]
+fn test_crank_pays_bounty_from_commission() {
+    let (env, client, [creator, member2, keeper]) = setup_test_env();
+    let group_id = create_group_with_bounty(
+        &client,
+        &creator,
+        &member2,
+        1_000_000,
+        &OrganizerCommission::BasisPoints(100),
+    );
+
+    client.contribute(&creator, &group_id);
+    client.contribute(&member2, &group_id);
+    advance_past_grace(&env);
+
+    assert!(client.is_payout_due(&group_id));
+    let bounty = client.crank(&keeper, &group_id);
+    assert_eq!(bounty, 1_000_000);
+    assert_eq!(client.get_keeper_earnings(&keeper), 1_000_000);
+
+    // Recipient still pays the full commission; the organizer funds the bounty
+    let record = client.get_payout_record(&group_id, &creator);
+    assert_eq!(record.commission, 2_000_000);
+    assert_eq!(record.amount, 2 * CONTRIBUTION - 2_000_000);
+
+    let topics: Vec<Val> = (symbol_short!("commpaid"), group_id).into_val(&env);
+    let event = env
+        .events()
+        .all()
+        .iter()
+        .find(|(_, event_topics, _)| *event_topics == topics)
+        .expect("commission event emitted");
+    let organizer_share: MemberAmountEvent = event.2.into_val(&env);
+    assert_eq!(organizer_share.amount, 1_000_000);
+}
+
+#[test]
+fn test_crank_pays_bounty_from_penalties_first() {
+    let (env, client, [creator, member2, keeper]) = setup_test_env();
+    let group_id = create_group_with_bounty(
+        &client,
+        &creator,
+        &member2,
+        1_000_000,
+        &OrganizerCommission::BasisPoints(100),
+    );
+
+    client.contribute(&creator, &group_id);
+    env.ledger().with_mut(|li| {
+        li.timestamp += CYCLE + 60;
+    });
+    client.contribute(&member2, &group_id);
+    env.ledger().with_mut(|li| {
+        li.timestamp += GRACE;
+    });
+
+    assert_eq!(client.crank(&keeper, &group_id), 1_000_000);
+
+    // 5 XLM penalty pool funds the bounty; the recipient gets the remaining 4 XLM
+    let record = client.get_payout_record(&group_id, &creator);
+    assert_eq!(record.amount, 2 * CONTRIBUTION + 4_000_000 - 2_000_000);
+}
+
+#[test]
+fn test_crank_bounty_limited_by_available_funds() {
+    let (env, client, [creator, member2, keeper]) = setup_test_env();
+    let group_id =
+        create_group_with_bounty(&client, &creator, &member2, 1_000_000, &OrganizerCommission::None);
+
+    client.contribute(&creator, &group_id);
+    client.contribute(&member2, &group_id);
+    advance_past_grace(&env);
+
+    // No penalties and no commission: the payout still runs, bounty is zero
+    assert_eq!(client.crank(&keeper, &group_id), 0);
+    assert_eq!(client.get_payout_record(&group_id, &creator).amount, 2 * CONTRIBUTION);
+    assert_eq!(client.get_group(&group_id).current_cycle, 2);
+}
+
+#[test]
+fn test_crank_before_due_fails() {
+    let (_env, client, [creator, member2, keeper]) = setup_test_env();
+    let group_id =
+        create_group_with_bounty(&client, &creator, &member2, 1_000_000, &OrganizerCommission::None);
+
+    client.contribute(&creator, &group_id);
+    assert!(!client.is_payout_due(&group_id));
+
+    let result = client.try_crank(&keeper, &group_id);
+    assert_eq!(result, Err(Ok(AjoError::IncompleteContributions)));
+}
+
+#[test]
+fn test_crank_emits_overdue_time() {
+    let (env, client, [creator, member2, keeper]) = setup_test_env();
+    let group_id =
+        create_group_with_bounty(&client, &creator, &member2, 0, &OrganizerCommission::None);
+
+    client.contribute(&creator, &group_id);
+    client.contribute(&member2, &group_id);
+    env.ledger().with_mut(|li| {
+        li.timestamp += CYCLE + GRACE + 7200;
+    });
+    client.crank(&keeper, &group_id);
+
+    let topics: Vec<Val> = (symbol_short!("cranked"), group_id).into_val(&env);
+    let event = env
+        .events()
+        .all()
+        .iter()
+        .find(|(_, event_topics, _)| *event_topics == topics)
+        .expect("keeper event emitted");
+    let cranked: KeeperCrankedEvent = event.2.into_val(&env);
+    assert_eq!(cranked.cycle, 1);
+    assert_eq!(cranked.keeper, keeper);
+    assert_eq!(cranked.bounty, 0);
+    assert_eq!(cranked.overdue_by, 7200);
+}
+
+#[test]
+fn test_crank_many_skips_groups_not_ready() {
+    let (env, client, [creator, member2, keeper]) = setup_test_env();
+    let commission = OrganizerCommission::BasisPoints(100);
+    let ready = create_group_with_bounty(&client, &creator, &member2, 500_000, &commission);
+    let incomplete = create_group_with_bounty(&client, &creator, &member2, 500_000, &commission);
+    let also_ready = create_group_with_bounty(&client, &creator, &member2, 500_000, &commission);
+
+    for group_id in [ready, also_ready] {
+        client.contribute(&creator, &group_id);
+        client.contribute(&member2, &group_id);
+    }
+    client.contribute(&creator, &incomplete);
+    advance_past_grace(&env);
+
+    let cranked = client.crank_many(&keeper, &vec![&env, ready, incomplete, 999, also_ready]);
+    assert_eq!(cranked, vec![&env, ready, also_ready]);
+    assert_eq!(client.get_keeper_earnings(&keeper), 1_000_000);
+
+    let skipped: Vec<Val> = (symbol_short!("skipped"), incomplete).into_val(&env);
+    let event = env
+        .events()
+        .all()
+        .iter()
+        .find(|(_, event_topics, _)| *event_topics == skipped)
+        .expect("skip event emitted");
+    let skip: CrankSkippedEvent = event.2.into_val(&env);
+    assert_eq!(skip.reason, AjoError::IncompleteContributions as u32);
+
+    assert_eq!(client.get_group(&ready).current_cycle, 2);
+    assert_eq!(client.get_group(&incomplete).current_cycle, 1);
+}
+
+#[test]
+fn test_status_reports_payout_due() {
+    let (env, client, [creator, member2, _]) = setup_test_env();
+    let group_id =
+        create_group_with_bounty(&client, &creator, &member2, 250_000, &OrganizerCommission::None);
+
+    client.contribute(&creator, &group_id);
+    client.contribute(&member2, &group_id);
+
+    let status = client.get_group_status(&group_id);
+    assert!(!status.is_payout_due);
+    assert_eq!(status.keeper_bounty, 250_000);
+
+    advance_past_grace(&env);
+    assert!(client.get_group_status(&group_id).is_payout_due);
+}
+
+#[test]
+fn test_invalid_keeper_bounty() {
+    let (_env, client, [creator, _, _]) = setup_test_env();
+    let group_id = client.create_group(&creator, &CONTRIBUTION, &CYCLE, &2u32, &GRACE, &5u32);
+
+    let result = client.try_set_keeper_bounty(&group_id, &-1);
+    assert_eq!(result, Err(Ok(AjoError::InvalidGroupTerms)));
+}
@@ -0,0 +1,141 @@
+#![cfg(test)]
+
+mod common;
+
+use soroban_ajo::{AjoContractClient, AjoError};
+use soroban_sdk::{
+    vec, Address,
+};
+
+use common::{complete_cycle, setup_test_env};
+
+const CONTRIBUTION: i128 = 100_000_000;
+const CYCLE: u64 = 604_800;
+const DAY: u64 = 86400;
+
+/// Creates a full 3-seat group where member2 holds the second and third seats
+fn create_group(client: &AjoContractClient, creator: &Address, member2: &Address) -> u64 {
+    let group_id = client.create_group(creator, &CONTRIBUTION, &CYCLE, &3u32, &DAY, &5u32);
+    client.set_max_seats_per_member(&group_id, &2u32);
+    client.join_group(member2, &group_id);
+    client.join_group(member2, &group_id);
+    group_id
+}
+
+#[test]
+fn test_member_can_hold_several_seats() {
+    let (env, client, [creator, member2]) = setup_test_env();
+    let group_id = create_group(&client, &creator, &member2);
+
+    assert_eq!(client.get_group(&group_id).max_seats_per_member, 2);
+    assert_eq!(
+        client.list_members(&group_id),
+        vec![&env, creator.clone(), member2.clone(), member2.clone()]
+    );
+    assert_eq!(client.get_member_seats(&group_id, &member2), vec![&env, 1u32, 2u32]);
+    assert_eq!(client.get_member_seats(&group_id, &creator), vec![&env, 0u32]);
+
+    // The group is full and member2 is at the seat limit
+    let result = client.try_join_group(&member2, &group_id);
+    assert_eq!(result, Err(Ok(AjoError::AlreadyMember)));
+}
+
+#[test]
+fn test_seat_limit_enforced() {
+    let (env, client, [creator, member2]) = setup_test_env();
+    let group_id = client.create_group(&creator, &CONTRIBUTION, &CYCLE, &5u32, &DAY, &5u32);
+    client.set_max_seats_per_member(&group_id, &2u32);
+    client.join_group(&member2, &group_id);
+    client.join_group(&member2, &group_id);
+
+    let result = client.try_join_group(&member2, &group_id);
+    assert_eq!(result, Err(Ok(AjoError::AlreadyMember)));
+
+    // The creator can take a second seat too
+    client.join_group(&creator, &group_id);
+    assert_eq!(client.get_member_seats(&group_id, &creator), vec![&env, 0u32, 3u32]);
+}
+
+#[test]
+fn test_contribute_pays_every_seat_and_each_seat_is_paid_out() {
+    let (env, client, [creator, member2]) = setup_test_env();
+    let group_id = create_group(&client, &creator, &member2);
+    let members = [&creator, &member2];
+
+    client.contribute(&member2, &group_id);
+    let record = client.get_contribution_detail(&group_id, &1u32, &member2);
+    assert!(record.has_paid);
+    assert_eq!(record.amount_paid, 2 * CONTRIBUTION);
+
+    complete_cycle(&env, &client, group_id, &[&creator]);
+    assert_eq!(client.get_group_status(&group_id).next_recipient, member2);
+
+    complete_cycle(&env, &client, group_id, &members);
+    let record = client.get_payout_record(&group_id, &member2);
+    assert_eq!((record.cycle, record.amount), (2, 3 * CONTRIBUTION));
+
+    complete_cycle(&env, &client, group_id, &members);
+    let record = client.get_payout_record(&group_id, &member2);
+    assert_eq!((record.cycle, record.amount), (3, 3 * CONTRIBUTION));
+    assert!(client.is_complete(&group_id));
+}
+
+#[test]
+fn test_contribute_for_a_single_seat() {
+    let (_env, client, [creator, member2]) = setup_test_env();
+    let group_id = create_group(&client, &creator, &member2);
+
+    client.contribute_seat(&member2, &group_id, &2u32);
+    let record = client.get_contribution_detail(&group_id, &1u32, &member2);
+    assert!(!record.has_paid);
+    assert_eq!(record.amount_paid, CONTRIBUTION);
+
+    let result = client.try_contribute_seat(&member2, &group_id, &2u32);
+    assert_eq!(result, Err(Ok(AjoError::AlreadyContributed)));
+    let result = client.try_contribute_seat(&member2, &group_id, &0u32);
+    assert_eq!(result, Err(Ok(AjoError::NotMember)));
+
+    client.contribute_seat(&member2, &group_id, &1u32);
+    let record = client.get_contribution_detail(&group_id, &1u32, &member2);
+    assert!(record.has_paid);
+    assert_eq!(record.seats_paid.len(), 2);
+    assert_eq!(record.amount_paid, 2 * CONTRIBUTION);
+}
+
+#[test]
+fn test_cannot_take_extra_seat_after_paying() {
+    let (_env, client, [creator, member2]) = setup_test_env();
+    let group_id = client.create_group(&creator, &CONTRIBUTION, &CYCLE, &4u32, &DAY, &5u32);
+    client.set_max_seats_per_member(&group_id, &2u32);
+    client.join_group(&member2, &group_id);
+
+    client.contribute_partial(&member2, &group_id, &10_000_000);
+    let result = client.try_join_group(&member2, &group_id);
+    assert_eq!(result, Err(Ok(AjoError::AlreadyContributed)));
+}
+
+#[test]
+fn test_refund_counts_multi_seat_member_once() {
+    let (_env, client, [creator, member2]) = setup_test_env();
+    let group_id = create_group(&client, &creator, &member2);
+
+    client.contribute(&member2, &group_id);
+    client.cancel_group(&creator, &group_id);
+
+    assert_eq!(client.get_refund_record(&group_id, &member2).amount, 2 * CONTRIBUTION);
+}
+
+#[test]
+fn test_invalid_seat_limits_rejected() {
+    let (_env, client, [creator, member2]) = setup_test_env();
+    let group_id = client.create_group(&creator, &CONTRIBUTION, &CYCLE, &3u32, &DAY, &5u32);
+
+    let result = client.try_set_max_seats_per_member(&group_id, &0u32);
+    assert_eq!(result, Err(Ok(AjoError::InvalidGroupTerms)));
+    let result = client.try_set_max_seats_per_member(&group_id, &4u32);
+    assert_eq!(result, Err(Ok(AjoError::InvalidGroupTerms)));
+
+    client.join_group(&member2, &group_id);
+    let result = client.try_set_max_seats_per_member(&group_id, &2u32);
+    assert_eq!(result, Err(Ok(AjoError::GroupTermsLocked)));
+}
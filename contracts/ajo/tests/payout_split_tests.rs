@@ -0,0 +1,179 @@
+#![cfg(test)]
+
+mod common;
+
+use soroban_ajo::{
+    AjoContractClient, AjoError, PayoutShare, PayoutShareEvent, PayoutSplit,
+};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events},
+    vec, Address, IntoVal, Val, Vec,
+};
+
+use common::{complete_cycle, setup_test_env};
+
+const CONTRIBUTION: i128 = 100_000_000;
+const CYCLE: u64 = 604_800;
+const DAY: u64 = 86400;
+
+/// Creates a full 2-member group with a one-day grace period
+fn create_group(client: &AjoContractClient, creator: &Address, member2: &Address) -> u64 {
+    let group_id = client.create_group(creator, &CONTRIBUTION, &CYCLE, &2u32, &DAY, &5u32);
+    client.join_group(member2, &group_id);
+    group_id
+}
+
+#[test]
+fn test_payout_goes_to_member_by_default() {
+    let (env, client, [creator, member2]) = setup_test_env();
+    let group_id = create_group(&client, &creator, &member2);
+
+    assert!(client.get_payout_splits(&group_id, &creator).is_empty());
+
+    complete_cycle(&env, &client, group_id, &[&creator, &member2]);
+
+    let record = client.get_payout_record(&group_id, &creator);
+    assert_eq!(
+        record.shares,
+        vec![&env, PayoutShare { recipient: creator.clone(), amount: 2 * CONTRIBUTION }]
+    );
+}
+
+#[test]
+fn test_payout_redirected_to_another_address() {
+    let (env, client, [creator, member2]) = setup_test_env();
+    let group_id = create_group(&client, &creator, &member2);
+
+    let wallet = Address::generate(&env);
+    let splits = vec![&env, PayoutSplit { recipient: wallet.clone(), bps: 10_000 }];
+    client.set_payout_splits(&member2, &group_id, &splits);
+
+    complete_cycle(&env, &client, group_id, &[&creator, &member2]);
+    complete_cycle(&env, &client, group_id, &[&creator, &member2]);
+
+    let record = client.get_payout_record(&group_id, &member2);
+    assert_eq!(record.member, member2);
+    assert_eq!(
+        record.shares,
+        vec![&env, PayoutShare { recipient: wallet, amount: 2 * CONTRIBUTION }]
+    );
+}
+
+#[test]
+fn test_payout_split_by_percentage() {
+    let (env, client, [creator, member2]) = setup_test_env();
+    let group_id = create_group(&client, &creator, &member2);
+
+    let vault = Address::generate(&env);
+    let splits = vec![
+        &env,
+        PayoutSplit { recipient: member2.clone(), bps: 7_000 },
+        PayoutSplit { recipient: vault.clone(), bps: 3_000 },
+    ];
+    client.set_payout_splits(&member2, &group_id, &splits);
+    assert_eq!(client.get_payout_splits(&group_id, &member2), splits);
+
+    complete_cycle(&env, &client, group_id, &[&creator, &member2]);
+    complete_cycle(&env, &client, group_id, &[&creator, &member2]);
+
+    let record = client.get_payout_record(&group_id, &member2);
+    assert_eq!(
+        record.shares,
+        vec![
+            &env,
+            PayoutShare { recipient: member2.clone(), amount: 140_000_000 },
+            PayoutShare { recipient: vault.clone(), amount: 60_000_000 },
+        ]
+    );
+
+    let expected_topics: Vec<Val> = (symbol_short!("payshare"), group_id).into_val(&env);
+    let shares: std::vec::Vec<(Address, Address, i128)> = env
+        .events()
+        .all()
+        .iter()
+        .filter(|(_, topics, _)| *topics == expected_topics)
+        .map(|(_, _, data)| -> PayoutShareEvent { data.into_val(&env) })
+        .filter(|share| share.cycle == 2)
+        .map(|share| (share.member, share.recipient, share.amount))
+        .collect();
+    assert_eq!(
+        shares,
+        [(member2.clone(), member2.clone(), 140_000_000), (member2, vault, 60_000_000)]
+    );
+}
+
+#[test]
+fn test_split_rounding_remainder_goes_to_first_address() {
+    let (env, client, [creator, member2]) = setup_test_env();
+    let group_id = client.create_group(&creator, &1_000i128, &CYCLE, &2u32, &DAY, &5u32);
+    client.join_group(&member2, &group_id);
+
+    let a = Address::generate(&env);
+    let b = Address::generate(&env);
+    let c = Address::generate(&env);
+    let splits = vec![
+        &env,
+        PayoutSplit { recipient: a.clone(), bps: 3_333 },
+        PayoutSplit { recipient: b.clone(), bps: 3_333 },
+        PayoutSplit { recipient: c.clone(), bps: 3_334 },
+    ];
+    client.set_payout_splits(&member2, &group_id, &splits);
+
+    complete_cycle(&env, &client, group_id, &[&creator, &member2]);
+    complete_cycle(&env, &client, group_id, &[&creator, &member2]);
+
+    let shares = client.get_payout_record(&group_id, &member2).shares;
+    assert_eq!(shares.get(0).unwrap().amount, 668);
+    assert_eq!(shares.get(1).unwrap().amount, 666);
+    assert_eq!(shares.get(2).unwrap().amount, 666);
+}
+
+#[test]
+fn test_invalid_splits_rejected() {
+    let (env, client, [creator, member2]) = setup_test_env();
+    let group_id = create_group(&client, &creator, &member2);
+    let wallet = Address::generate(&env);
+
+    let under = vec![&env, PayoutSplit { recipient: wallet.clone(), bps: 9_999 }];
+    let result = client.try_set_payout_splits(&member2, &group_id, &under);
+    assert_eq!(result, Err(Ok(AjoError::InvalidPayoutSplit)));
+
+    let zero = vec![
+        &env,
+        PayoutSplit { recipient: wallet.clone(), bps: 10_000 },
+        PayoutSplit { recipient: member2.clone(), bps: 0 },
+    ];
+    let result = client.try_set_payout_splits(&member2, &group_id, &zero);
+    assert_eq!(result, Err(Ok(AjoError::InvalidPayoutSplit)));
+
+    let mut too_many = Vec::new(&env);
+    for _ in 0..6 {
+        too_many.push_back(PayoutSplit { recipient: wallet.clone(), bps: 1_000 });
+    }
+    too_many.push_back(PayoutSplit { recipient: wallet.clone(), bps: 4_000 });
+    let result = client.try_set_payout_splits(&member2, &group_id, &too_many);
+    assert_eq!(result, Err(Ok(AjoError::InvalidPayoutSplit)));
+}
+
+#[test]
+fn test_splits_locked_once_payout_cycle_starts() {
+    let (env, client, [creator, member2]) = setup_test_env();
+    let group_id = create_group(&client, &creator, &member2);
+    let wallet = Address::generate(&env);
+    let splits = vec![&env, PayoutSplit { recipient: wallet.clone(), bps: 10_000 }];
+
+    // The creator's payout cycle is the first one, so it has already started
+    let result = client.try_set_payout_splits(&creator, &group_id, &splits);
+    assert_eq!(result, Err(Ok(AjoError::PayoutSplitLocked)));
+
+    // Member2 can change their mind until cycle 2 starts
+    client.set_payout_splits(&member2, &group_id, &splits);
+    client.set_payout_splits(&member2, &group_id, &Vec::new(&env));
+    assert!(client.get_payout_splits(&group_id, &member2).is_empty());
+
+    complete_cycle(&env, &client, group_id, &[&creator, &member2]);
+
+    let result = client.try_set_payout_splits(&member2, &group_id, &splits);
+    assert_eq!(result, Err(Ok(AjoError::PayoutSplitLocked)));
+}
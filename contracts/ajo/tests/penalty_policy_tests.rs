@@ -0,0 +1,200 @@
+#![cfg(test)]
+
+mod common;
+
+use soroban_ajo::{AjoContractClient, AjoError, PenaltyPolicy};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events, Ledger},
+    Address, Env, IntoVal, Val, Vec,
+};
+
+use common::setup_test_env;
+
+const CONTRIBUTION: i128 = 100_000_000;
+const CYCLE: u64 = 604_800;
+const GRACE: u64 = 86400;
+
+/// Creates a 3-member group (5% penalty) with the given policy
+fn create_group_with_policy(
+    client: &AjoContractClient,
+    creator: &Address,
+    member2: &Address,
+    member3: &Address,
+    policy: PenaltyPolicy,
+) -> u64 {
+    let group_id = client.create_group(creator, &CONTRIBUTION, &CYCLE, &3u32, &GRACE, &5u32);
+    client.set_penalty_policy(&group_id, &policy);
+    client.join_group(member2, &group_id);
+    client.join_group(member3, &group_id);
+    group_id
+}
+
+/// Runs one cycle where `late` pays during the grace period and everyone else on time
+fn run_cycle_with_late_member(
+    env: &Env,
+    client: &AjoContractClient,
+    group_id: u64,
+    on_time: &[&Address],
+    late: &Address,
+) {
+    for member in on_time {
+        client.contribute(member, &group_id);
+    }
+    env.ledger().with_mut(|li| {
+        li.timestamp += CYCLE + 3600;
+    });
+    client.contribute(late, &group_id);
+    env.ledger().with_mut(|li| {
+        li.timestamp += GRACE;
+    });
+    client.execute_payout(&group_id);
+}
+
+/// Counts events emitted by the last invocation with the given topics
+fn count_events(env: &Env, topics: Vec<Val>) -> u32 {
+    env.events()
+        .all()
+        .iter()
+        .filter(|(_, event_topics, _)| *event_topics == topics)
+        .count() as u32
+}
+
+#[test]
+fn test_default_policy_pays_next_recipient() {
+    let (env, client, [creator, member2, member3]) = setup_test_env();
+
+    let group_id = client.create_group(&creator, &CONTRIBUTION, &CYCLE, &3u32, &GRACE, &5u32);
+    client.join_group(&member2, &group_id);
+    client.join_group(&member3, &group_id);
+    assert_eq!(client.get_group(&group_id).penalty_policy, PenaltyPolicy::NextRecipient);
+
+    run_cycle_with_late_member(&env, &client, group_id, &[&creator, &member2], &member3);
+
+    let record = client.get_payout_record(&group_id, &creator);
+    assert_eq!(record.amount, 3 * CONTRIBUTION + 5_000_000);
+}
+
+#[test]
+fn test_on_time_contributors_split_penalties() {
+    let (env, client, [creator, member2, member3]) = setup_test_env();
+    let group_id =
+        create_group_with_policy(&client, &creator, &member2, &member3, PenaltyPolicy::OnTimeContributors);
+
+    run_cycle_with_late_member(&env, &client, group_id, &[&creator, &member2], &member3);
+
+    // One event per on-time contributor
+    let topics: Vec<Val> = (symbol_short!("penshare"), group_id).into_val(&env);
+    assert_eq!(count_events(&env, topics), 2);
+
+    // Recipient gets only the base pot; penalties split between the on-time pair
+    assert_eq!(client.get_payout_record(&group_id, &creator).amount, 3 * CONTRIBUTION);
+    assert_eq!(client.get_penalty_earnings(&group_id, &creator), 2_500_000);
+    assert_eq!(client.get_penalty_earnings(&group_id, &member2), 2_500_000);
+    assert_eq!(client.get_penalty_earnings(&group_id, &member3), 0);
+}
+
+#[test]
+fn test_on_time_split_remainder_goes_to_first_member() {
+    let (env, client, [creator, member2, member3]) = setup_test_env();
+
+    // 3 stroop penalty (3% of 100) split between two on-time members
+    let group_id = client.create_group(&creator, &100i128, &CYCLE, &3u32, &GRACE, &3u32);
+    client.set_penalty_policy(&group_id, &PenaltyPolicy::OnTimeContributors);
+    client.join_group(&member2, &group_id);
+    client.join_group(&member3, &group_id);
+
+    run_cycle_with_late_member(&env, &client, group_id, &[&creator, &member2], &member3);
+
+    assert_eq!(client.get_penalty_earnings(&group_id, &creator), 2);
+    assert_eq!(client.get_penalty_earnings(&group_id, &member2), 1);
+}
+
+#[test]
+fn test_group_reserve_paid_out_at_completion() {
+    let (env, client, [creator, member2, member3]) = setup_test_env();
+    let group_id =
+        create_group_with_policy(&client, &creator, &member2, &member3, PenaltyPolicy::GroupReserve);
+
+    run_cycle_with_late_member(&env, &client, group_id, &[&creator, &member2], &member3);
+    assert_eq!(client.get_group_reserve(&group_id), 5_000_000);
+    assert_eq!(client.get_group_status(&group_id).penalty_reserve, 5_000_000);
+    assert_eq!(client.get_payout_record(&group_id, &creator).amount, 3 * CONTRIBUTION);
+
+    run_cycle_with_late_member(&env, &client, group_id, &[&creator, &member3], &member2);
+    assert_eq!(client.get_group_reserve(&group_id), 10_000_000);
+
+    run_cycle_with_late_member(&env, &client, group_id, &[&member2, &member3], &creator);
+
+    // Reserve of 15 XLM split equally between the three members on completion
+    let topics: Vec<Val> = (symbol_short!("penshare"), group_id).into_val(&env);
+    assert_eq!(count_events(&env, topics), 3);
+    assert!(client.is_complete(&group_id));
+    assert_eq!(client.get_group_reserve(&group_id), 0);
+    assert_eq!(client.get_penalty_earnings(&group_id, &creator), 5_000_000);
+    assert_eq!(client.get_penalty_earnings(&group_id, &member2), 5_000_000);
+    assert_eq!(client.get_penalty_earnings(&group_id, &member3), 5_000_000);
+}
+
+#[test]
+fn test_insurance_pool_policy() {
+    let (env, client, [creator, member2, member3]) = setup_test_env();
+    let token = Address::generate(&env);
+    let group_id = client.create_group(&creator, &CONTRIBUTION, &CYCLE, &3u32, &GRACE, &5u32);
+    client.set_contribution_token(&group_id, &token);
+    client.set_penalty_policy(&group_id, &PenaltyPolicy::InsurancePool);
+    client.join_group(&member2, &group_id);
+    client.join_group(&member3, &group_id);
+
+    run_cycle_with_late_member(&env, &client, group_id, &[&creator, &member2], &member3);
+
+    let topics: Vec<Val> = (symbol_short!("penpooled"), group_id).into_val(&env);
+    assert_eq!(count_events(&env, topics), 1);
+    assert_eq!(client.get_insurance_fund_balance(&token), 5_000_000);
+    assert_eq!(client.get_treasury_balance(), 0);
+    assert_eq!(client.get_payout_record(&group_id, &creator).amount, 3 * CONTRIBUTION);
+}
+
+#[test]
+fn test_insurance_pool_policy_requires_token() {
+    let (_env, client, [creator, _member2, _member3]) = setup_test_env();
+    let group_id = client.create_group(&creator, &CONTRIBUTION, &CYCLE, &3u32, &GRACE, &5u32);
+
+    let result = client.try_set_penalty_policy(&group_id, &PenaltyPolicy::InsurancePool);
+    assert_eq!(result, Err(Ok(AjoError::NoContributionToken)));
+}
+
+#[test]
+fn test_treasury_policy_accumulates_across_groups() {
+    let (env, client, [creator, member2, member3]) = setup_test_env();
+    let group1 = create_group_with_policy(&client, &creator, &member2, &member3, PenaltyPolicy::Treasury);
+    let group2 = create_group_with_policy(&client, &creator, &member2, &member3, PenaltyPolicy::Treasury);
+
+    for group_id in [group1, group2] {
+        client.contribute(&creator, &group_id);
+        client.contribute(&member2, &group_id);
+    }
+    env.ledger().with_mut(|li| {
+        li.timestamp += CYCLE + 3600;
+    });
+    for group_id in [group1, group2] {
+        client.contribute(&member3, &group_id);
+    }
+    env.ledger().with_mut(|li| {
+        li.timestamp += GRACE;
+    });
+    client.execute_payout(&group1);
+    client.execute_payout(&group2);
+
+    assert_eq!(client.get_treasury_balance(), 10_000_000);
+}
+
+#[test]
+fn test_penalty_policy_locked_after_join() {
+    let (_env, client, [creator, member2, member3]) = setup_test_env();
+    let group_id =
+        create_group_with_policy(&client, &creator, &member2, &member3, PenaltyPolicy::Treasury);
+
+    let result = client.try_set_penalty_policy(&group_id, &PenaltyPolicy::NextRecipient);
+    assert_eq!(result, Err(Ok(AjoError::GroupTermsLocked)));
+}
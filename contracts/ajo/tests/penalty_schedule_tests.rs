@@ -0,0 +1,174 @@
+#![cfg(test)]
+
+mod common;
+
+use soroban_ajo::{
+    AjoContractClient, AjoError, LinearPenalty, PenaltySchedule, PenaltyTier,
+    SteppedPenalty,
+};
+use soroban_sdk::{testutils::Ledger, vec, Address, Env};
+
+use common::setup_test_env;
+
+const CONTRIBUTION: i128 = 100_000_000;
+const CYCLE: u64 = 604_800;
+const HOUR: u64 = 3600;
+const DAY: u64 = 86400;
+
+/// Creates a 2-member group with a 3-day grace period and the given schedule
+fn create_group_with_schedule(
+    client: &AjoContractClient,
+    creator: &Address,
+    member2: &Address,
+    schedule: &PenaltySchedule,
+) -> u64 {
+    let group_id = client.create_group(creator, &CONTRIBUTION, &CYCLE, &2u32, &(3 * DAY), &5u32);
+    client.set_penalty_schedule(&group_id, schedule);
+    client.join_group(member2, &group_id);
+    group_id
+}
+
+fn stepped_schedule(env: &Env) -> PenaltySchedule {
+    // 2% during the first day late, 5% after
+    PenaltySchedule::Stepped(SteppedPenalty {
+        tiers: vec![
+            env,
+            PenaltyTier { late_after: 0, rate_bps: 200 },
+            PenaltyTier { late_after: DAY, rate_bps: 500 },
+        ],
+        cap_bps: 10_000,
+    })
+}
+
+#[test]
+fn test_default_schedule_is_flat() {
+    let (env, client, [creator, member2]) = setup_test_env();
+
+    let group_id = client.create_group(&creator, &CONTRIBUTION, &CYCLE, &2u32, &DAY, &5u32);
+    client.join_group(&member2, &group_id);
+    assert_eq!(client.get_group(&group_id).penalty_schedule, PenaltySchedule::Flat);
+
+    let start = env.ledger().timestamp();
+    assert_eq!(client.preview_penalty(&group_id, &(start + CYCLE + 60)), 5_000_000);
+    assert_eq!(client.preview_penalty(&group_id, &(start + CYCLE + 23 * HOUR)), 5_000_000);
+}
+
+#[test]
+fn test_linear_penalty_accrues_per_hour() {
+    let (env, client, [creator, member2]) = setup_test_env();
+
+    // 0.25% per started hour, capped at 10%
+    let schedule = PenaltySchedule::Linear(LinearPenalty { bps_per_hour: 25, cap_bps: 1_000 });
+    let group_id = create_group_with_schedule(&client, &creator, &member2, &schedule);
+    let cycle_end = env.ledger().timestamp() + CYCLE;
+
+    assert_eq!(client.preview_penalty(&group_id, &(cycle_end - 1)), 0);
+    assert_eq!(client.preview_penalty(&group_id, &(cycle_end + 60)), 250_000);
+    assert_eq!(client.preview_penalty(&group_id, &(cycle_end + 2 * HOUR + 1)), 750_000);
+
+    // 40 hours reaches the cap; anything later stays capped
+    assert_eq!(client.preview_penalty(&group_id, &(cycle_end + 39 * HOUR)), 10_000_000);
+    assert_eq!(client.preview_penalty(&group_id, &(cycle_end + 2 * DAY)), 10_000_000);
+}
+
+#[test]
+fn test_stepped_penalty_tiers() {
+    let (env, client, [creator, member2]) = setup_test_env();
+
+    let group_id = create_group_with_schedule(&client, &creator, &member2, &stepped_schedule(&env));
+    let cycle_end = env.ledger().timestamp() + CYCLE;
+
+    assert_eq!(client.preview_penalty(&group_id, &(cycle_end + 60)), 2_000_000);
+    assert_eq!(client.preview_penalty(&group_id, &(cycle_end + 23 * HOUR)), 2_000_000);
+    assert_eq!(client.preview_penalty(&group_id, &(cycle_end + DAY)), 5_000_000);
+    assert_eq!(client.preview_penalty(&group_id, &(cycle_end + 2 * DAY)), 5_000_000);
+}
+
+#[test]
+fn test_stepped_penalty_respects_cap() {
+    let (env, client, [creator, member2]) = setup_test_env();
+
+    let schedule = PenaltySchedule::Stepped(SteppedPenalty {
+        tiers: vec![
+            &env,
+            PenaltyTier { late_after: 0, rate_bps: 200 },
+            PenaltyTier { late_after: DAY, rate_bps: 800 },
+        ],
+        cap_bps: 300,
+    });
+    let group_id = create_group_with_schedule(&client, &creator, &member2, &schedule);
+    let cycle_end = env.ledger().timestamp() + CYCLE;
+
+    assert_eq!(client.preview_penalty(&group_id, &(cycle_end + 2 * DAY)), 3_000_000);
+}
+
+#[test]
+fn test_contribution_record_reflects_schedule() {
+    let (env, client, [creator, member2]) = setup_test_env();
+
+    let group_id = create_group_with_schedule(&client, &creator, &member2, &stepped_schedule(&env));
+
+    client.contribute(&creator, &group_id);
+
+    // Member2 pays 30 hours late, in the second tier
+    env.ledger().with_mut(|li| {
+        li.timestamp += CYCLE + 30 * HOUR;
+    });
+    client.contribute(&member2, &group_id);
+
+    let record = client.get_contribution_detail(&group_id, &1u32, &member2);
+    assert!(record.is_late);
+    assert_eq!(record.penalty_amount, 5_000_000);
+    assert_eq!(client.get_cycle_penalty_pool(&group_id, &1u32), 5_000_000);
+    assert_eq!(client.get_member_penalty_record(&group_id, &member2).total_penalties, 5_000_000);
+}
+
+#[test]
+fn test_preview_after_grace_period_fails() {
+    let (env, client, [creator, member2]) = setup_test_env();
+
+    let group_id = create_group_with_schedule(&client, &creator, &member2, &stepped_schedule(&env));
+    let grace_end = env.ledger().timestamp() + CYCLE + 3 * DAY;
+
+    let result = client.try_preview_penalty(&group_id, &grace_end);
+    assert_eq!(result, Err(Ok(AjoError::GracePeriodExpired)));
+}
+
+#[test]
+fn test_invalid_schedules_rejected() {
+    let (env, client, [creator, _]) = setup_test_env();
+
+    let group_id = client.create_group(&creator, &CONTRIBUTION, &CYCLE, &2u32, &DAY, &5u32);
+
+    // Tiers must start at zero
+    let not_from_zero = PenaltySchedule::Stepped(SteppedPenalty {
+        tiers: vec![&env, PenaltyTier { late_after: HOUR, rate_bps: 200 }],
+        cap_bps: 500,
+    });
+    // Tiers must be strictly ascending
+    let out_of_order = PenaltySchedule::Stepped(SteppedPenalty {
+        tiers: vec![
+            &env,
+            PenaltyTier { late_after: 0, rate_bps: 200 },
+            PenaltyTier { late_after: 0, rate_bps: 500 },
+        ],
+        cap_bps: 500,
+    });
+    let empty = PenaltySchedule::Stepped(SteppedPenalty { tiers: vec![&env], cap_bps: 500 });
+    let cap_too_high = PenaltySchedule::Linear(LinearPenalty { bps_per_hour: 10, cap_bps: 10_001 });
+
+    for schedule in [not_from_zero, out_of_order, empty, cap_too_high] {
+        let result = client.try_set_penalty_schedule(&group_id, &schedule);
+        assert_eq!(result, Err(Ok(AjoError::InvalidGroupTerms)));
+    }
+}
+
+#[test]
+fn test_schedule_locked_after_join() {
+    let (env, client, [creator, member2]) = setup_test_env();
+
+    let group_id = create_group_with_schedule(&client, &creator, &member2, &stepped_schedule(&env));
+
+    let result = client.try_set_penalty_schedule(&group_id, &PenaltySchedule::Flat);
+    assert_eq!(result, Err(Ok(AjoError::GroupTermsLocked)));
+}
@@ -48,7 +48,7 @@ fn test_create_group_with_grace_period_and_penalty() {
 
 #[test]
 fn test_on_time_contribution() {
-    let (env, client, creator, member2, _) = setup_test_env();
+    let (_env, client, creator, member2, _) = setup_test_env();
 
     let contribution = 100_000_000i128;
     let cycle_duration = 604_800u64;
@@ -133,7 +133,7 @@ fn test_late_contribution_with_penalty() {
 
     // Check contribution detail
     let contrib_detail = client.get_contribution_detail(&group_id, &1u32, &member2);
-    assert_eq!(contrib_detail.is_late, true);
+    assert!(contrib_detail.is_late);
     assert_eq!(contrib_detail.penalty_amount, expected_penalty);
 }
 
@@ -206,7 +206,7 @@ fn test_payout_includes_penalties() {
 
     // Advance time past grace period
     env.ledger().with_mut(|li| {
-        li.timestamp = li.timestamp + grace_period;
+        li.timestamp += grace_period;
     });
 
     // Execute payout
@@ -329,8 +329,8 @@ fn test_group_status_includes_penalty_info() {
 
     // Get status during cycle
     let status = client.get_group_status(&group_id);
-    assert_eq!(status.is_cycle_active, true);
-    assert_eq!(status.is_in_grace_period, false);
+    assert!(status.is_cycle_active);
+    assert!(!status.is_in_grace_period);
     assert_eq!(status.cycle_penalty_pool, 0);
 
     // Advance to grace period
@@ -339,8 +339,8 @@ fn test_group_status_includes_penalty_info() {
     });
 
     let status = client.get_group_status(&group_id);
-    assert_eq!(status.is_cycle_active, false);
-    assert_eq!(status.is_in_grace_period, true);
+    assert!(!status.is_cycle_active);
+    assert!(status.is_in_grace_period);
 }
 
 #[test]
@@ -0,0 +1,170 @@
+#![cfg(test)]
+
+mod common;
+
+use soroban_ajo::{AjoContractClient, AjoError, MemberAmountEvent, PrepaidEvent};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events, Ledger},
+    Address, Env, IntoVal, Val, Vec,
+};
+
+use common::setup_test_env;
+
+const CONTRIBUTION: i128 = 100_000_000;
+const CYCLE: u64 = 604_800;
+const DAY: u64 = 86400;
+
+/// Creates a full 3-member group with a one-day grace period
+fn create_group(
+    client: &AjoContractClient,
+    creator: &Address,
+    member2: &Address,
+    member3: &Address,
+) -> u64 {
+    let group_id = client.create_group(creator, &CONTRIBUTION, &CYCLE, &3u32, &DAY, &5u32);
+    client.join_group(member2, &group_id);
+    client.join_group(member3, &group_id);
+    group_id
+}
+
+/// Helper function to advance past the grace period and execute the payout
+fn finish_cycle(env: &Env, client: &AjoContractClient, group_id: u64) {
+    env.ledger().with_mut(|li| {
+        li.timestamp += CYCLE + DAY + 1;
+    });
+    client.execute_payout(&group_id);
+}
+
+#[test]
+fn test_prepaid_cycles_count_as_contributed() {
+    let (env, client, [creator, member2, member3]) = setup_test_env();
+    let group_id = create_group(&client, &creator, &member2, &member3);
+
+    client.contribute(&member3, &group_id);
+    assert_eq!(client.prepay(&member3, &group_id, &2u32), 3);
+    assert_eq!(client.get_prepaid_through(&group_id, &member3), 3);
+
+    client.contribute(&creator, &group_id);
+    client.contribute(&member2, &group_id);
+    finish_cycle(&env, &client, group_id);
+
+    // Member3 is already counted for cycle 2
+    let status = client.get_group_status(&group_id);
+    assert_eq!(status.current_cycle, 2);
+    assert_eq!(status.contributions_received, 1);
+    assert!(!status.pending_contributors.contains(&member3));
+
+    let record = client.get_contribution_detail(&group_id, &2u32, &member3);
+    assert!(record.is_prepaid);
+    assert!(!record.is_late);
+    assert_eq!(record.amount_paid, CONTRIBUTION);
+
+    let result = client.try_contribute(&member3, &group_id);
+    assert_eq!(result, Err(Ok(AjoError::AlreadyContributed)));
+
+    client.contribute(&creator, &group_id);
+    client.contribute(&member2, &group_id);
+    finish_cycle(&env, &client, group_id);
+
+    client.contribute(&creator, &group_id);
+    client.contribute(&member2, &group_id);
+    finish_cycle(&env, &client, group_id);
+
+    assert!(client.is_complete(&group_id));
+}
+
+#[test]
+fn test_prepaid_cycle_recognized_as_on_time_when_it_starts() {
+    let (env, client, [creator, member2, member3]) = setup_test_env();
+    let group_id = create_group(&client, &creator, &member2, &member3);
+
+    client.contribute(&member3, &group_id);
+    client.prepay(&member3, &group_id, &1u32);
+
+    // Only the cycle already paid has been counted so far
+    assert_eq!(client.get_member_penalty_record(&group_id, &member3).on_time_count, 1);
+
+    client.contribute(&creator, &group_id);
+    client.contribute(&member2, &group_id);
+    finish_cycle(&env, &client, group_id);
+
+    let expected_topics: Vec<Val> = (symbol_short!("contrib"), group_id).into_val(&env);
+    let contribution = env
+        .events()
+        .all()
+        .iter()
+        .filter(|(_, topics, _)| *topics == expected_topics)
+        .map(|(_, _, data)| -> MemberAmountEvent { data.into_val(&env) })
+        .find(|contribution| contribution.cycle == 2 && contribution.member == member3)
+        .expect("prepaid contribution event emitted");
+    assert_eq!(contribution.amount, CONTRIBUTION);
+
+    assert_eq!(client.get_member_penalty_record(&group_id, &member3).on_time_count, 2);
+}
+
+#[test]
+fn test_prepay_extends_from_last_prepaid_cycle() {
+    let (_env, client, [creator, member2, member3]) = setup_test_env();
+    let group_id = create_group(&client, &creator, &member2, &member3);
+
+    assert_eq!(client.prepay(&member2, &group_id, &1u32), 2);
+    assert_eq!(client.prepay(&member2, &group_id, &1u32), 3);
+
+    // The current cycle still has to be paid normally
+    let statuses = client.get_contribution_status(&group_id, &1u32);
+    assert!(!statuses.get(1).unwrap().1);
+}
+
+#[test]
+fn test_invalid_prepayment_rejected() {
+    let (_env, client, [creator, member2, member3]) = setup_test_env();
+    let group_id = create_group(&client, &creator, &member2, &member3);
+
+    let result = client.try_prepay(&member2, &group_id, &0u32);
+    assert_eq!(result, Err(Ok(AjoError::InvalidPrepayment)));
+
+    // Only cycles 2 and 3 are left to prepay
+    let result = client.try_prepay(&member2, &group_id, &3u32);
+    assert_eq!(result, Err(Ok(AjoError::InvalidPrepayment)));
+
+    let outsider = Address::generate(&client.env);
+    let result = client.try_prepay(&outsider, &group_id, &1u32);
+    assert_eq!(result, Err(Ok(AjoError::NotMember)));
+}
+
+#[test]
+fn test_cancellation_refunds_prepaid_cycles() {
+    let (_env, client, [creator, member2, member3]) = setup_test_env();
+    let group_id = create_group(&client, &creator, &member2, &member3);
+
+    client.contribute(&member2, &group_id);
+    client.prepay(&member2, &group_id, &2u32);
+    client.prepay(&member3, &group_id, &1u32);
+
+    client.cancel_group(&creator, &group_id);
+
+    assert_eq!(client.get_refund_record(&group_id, &member2).amount, 3 * CONTRIBUTION);
+    assert_eq!(client.get_refund_record(&group_id, &member3).amount, CONTRIBUTION);
+    assert!(client.try_get_refund_record(&group_id, &creator).is_err());
+}
+
+#[test]
+fn test_prepaid_event() {
+    let (env, client, [creator, member2, member3]) = setup_test_env();
+    let group_id = create_group(&client, &creator, &member2, &member3);
+
+    client.prepay(&member2, &group_id, &2u32);
+
+    let expected_topics: Vec<Val> = (symbol_short!("prepaid"), group_id).into_val(&env);
+    let event = env
+        .events()
+        .all()
+        .iter()
+        .find(|(_, topics, _)| *topics == expected_topics)
+        .expect("prepaid event emitted");
+    let prepaid: PrepaidEvent = event.2.into_val(&env);
+    assert_eq!(prepaid.member, member2);
+    assert_eq!((prepaid.first_cycle, prepaid.last_cycle), (2, 3));
+    assert_eq!(prepaid.amount, 2 * CONTRIBUTION);
+}
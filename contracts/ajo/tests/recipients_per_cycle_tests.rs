@@ -0,0 +1,117 @@
+#![cfg(test)]
+
+mod common;
+
+use soroban_ajo::{AjoContractClient, AjoError, LimitSetEvent};
+use soroban_sdk::{
+    symbol_short,
+    testutils::Events,
+    Address, IntoVal, Val, Vec,
+};
+
+use common::{complete_cycle, setup_test_env};
+
+const CONTRIBUTION: i128 = 100_000_000;
+const CYCLE: u64 = 604_800;
+const DAY: u64 = 86400;
+
+/// Creates a full group paying two recipients per cycle
+fn create_group(client: &AjoContractClient, creator: &Address, others: &[&Address]) -> u64 {
+    let max_members = others.len() as u32 + 1;
+    let group_id = client.create_group(creator, &CONTRIBUTION, &CYCLE, &max_members, &DAY, &5u32);
+    client.set_recipients_per_cycle(&group_id, &2u32);
+    for member in others {
+        client.join_group(member, &group_id);
+    }
+    group_id
+}
+
+#[test]
+fn test_two_recipients_per_cycle() {
+    let (env, client, [creator, member2, member3, member4]) = setup_test_env();
+    let group_id = create_group(&client, &creator, &[&member2, &member3, &member4]);
+    let members = [&creator, &member2, &member3, &member4];
+
+    complete_cycle(&env, &client, group_id, &members);
+    assert_eq!(client.get_payout_record(&group_id, &creator).amount, 2 * CONTRIBUTION);
+    assert_eq!(client.get_payout_record(&group_id, &member2).amount, 2 * CONTRIBUTION);
+    assert!(client.try_get_payout_record(&group_id, &member3).is_err());
+
+    let group = client.get_group(&group_id);
+    assert_eq!(group.payout_index, 2);
+    assert_eq!(group.current_cycle, 2);
+    assert_eq!(client.get_group_status(&group_id).next_recipient, member3);
+
+    complete_cycle(&env, &client, group_id, &members);
+    assert!(client.is_complete(&group_id));
+    assert_eq!(client.get_payout_record(&group_id, &member3).cycle, 2);
+    assert_eq!(client.get_payout_record(&group_id, &member4).amount, 2 * CONTRIBUTION);
+
+    let expected_topics: Vec<Val> = (symbol_short!("payees"), group_id).into_val(&env);
+    let event = env
+        .events()
+        .all()
+        .iter()
+        .find(|(_, topics, _)| *topics == expected_topics)
+        .expect("recipients per cycle event emitted");
+    let recipients: LimitSetEvent = event.2.into_val(&env);
+    assert_eq!(recipients.value, 2);
+}
+
+#[test]
+fn test_short_final_cycle_shares_the_rest_of_the_pot() {
+    let (env, client, [creator, member2, member3, _member4]) = setup_test_env();
+    let group_id = create_group(&client, &creator, &[&member2, &member3]);
+    let members = [&creator, &member2, &member3];
+
+    complete_cycle(&env, &client, group_id, &members);
+    assert_eq!(client.get_payout_record(&group_id, &creator).amount, 150_000_000);
+    assert_eq!(client.get_payout_record(&group_id, &member2).amount, 150_000_000);
+
+    // The final cycle pays one recipient the same share and splits the rest
+    complete_cycle(&env, &client, group_id, &members);
+    assert!(client.is_complete(&group_id));
+    assert_eq!(client.get_group(&group_id).current_cycle, 2);
+    assert_eq!(client.get_payout_record(&group_id, &member3).amount, 150_000_000);
+    assert_eq!(client.get_group(&group_id).pot_surplus, 0);
+    assert_eq!(client.get_group_reserve(&group_id), 0);
+    for member in members {
+        assert_eq!(client.get_penalty_earnings(&group_id, member), 50_000_000);
+    }
+}
+
+#[test]
+fn test_current_recipients_cannot_swap() {
+    let (env, client, [creator, member2, member3, member4]) = setup_test_env();
+    let group_id = create_group(&client, &creator, &[&member2, &member3, &member4]);
+
+    // Both seats paid in the first cycle are already current recipients
+    let result = client.try_propose_swap(&group_id, &member2, &member3);
+    assert_eq!(result, Err(Ok(AjoError::AlreadyReceivedPayout)));
+
+    client.propose_swap(&group_id, &member3, &member4);
+    client.accept_swap(&group_id, &member3, &member4);
+    assert_eq!(client.get_group(&group_id).members.get(2).unwrap(), member4);
+
+    complete_cycle(&env, &client, group_id, &[&creator, &member2, &member3, &member4]);
+    let result = client.try_propose_swap(&group_id, &member3, &member4);
+    assert_eq!(result, Err(Ok(AjoError::AlreadyReceivedPayout)));
+}
+
+#[test]
+fn test_invalid_recipients_per_cycle_rejected() {
+    let (_env, client, [creator, member2, _member3, _member4]) = setup_test_env();
+    let group_id = client.create_group(&creator, &CONTRIBUTION, &CYCLE, &3u32, &DAY, &5u32);
+
+    let result = client.try_set_recipients_per_cycle(&group_id, &0u32);
+    assert_eq!(result, Err(Ok(AjoError::InvalidGroupTerms)));
+    let result = client.try_set_recipients_per_cycle(&group_id, &4u32);
+    assert_eq!(result, Err(Ok(AjoError::InvalidGroupTerms)));
+
+    client.set_recipients_per_cycle(&group_id, &3u32);
+    assert_eq!(client.get_group(&group_id).recipients_per_cycle, 3);
+
+    client.join_group(&member2, &group_id);
+    let result = client.try_set_recipients_per_cycle(&group_id, &2u32);
+    assert_eq!(result, Err(Ok(AjoError::GroupTermsLocked)));
+}
@@ -0,0 +1,151 @@
+#![cfg(test)]
+
+mod common;
+
+use soroban_ajo::{AjoContractClient, AjoError, GroupRenewedEvent, GroupState};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Events, Ledger},
+    vec, Address, Env, IntoVal, Val, Vec,
+};
+
+use common::{complete_cycle, setup_test_env};
+
+const CONTRIBUTION: i128 = 100_000_000;
+const CYCLE: u64 = 604_800;
+const DAY: u64 = 86400;
+
+/// Creates a full group with the given members after the creator
+fn create_group(client: &AjoContractClient, creator: &Address, others: &[&Address]) -> u64 {
+    let max_members = others.len() as u32 + 1;
+    let group_id = client.create_group(creator, &CONTRIBUTION, &CYCLE, &max_members, &DAY, &5u32);
+    for member in others {
+        client.join_group(member, &group_id);
+    }
+    group_id
+}
+
+/// Runs every cycle of the group to completion
+fn complete_group(env: &Env, client: &AjoContractClient, group_id: u64, members: &[&Address]) {
+    for _ in 0..members.len() {
+        complete_cycle(env, client, group_id, members);
+    }
+}
+
+#[test]
+fn test_opted_in_members_continue_into_next_round() {
+    let (env, client, [creator, member2, member3]) = setup_test_env();
+    let group_id = create_group(&client, &creator, &[&member2, &member3]);
+
+    client.opt_in_next_round(&member3, &group_id);
+    client.opt_in_next_round(&creator, &group_id);
+    client.opt_in_next_round(&creator, &group_id);
+    assert_eq!(client.get_renewal_opt_ins(&group_id), vec![&env, member3.clone(), creator.clone()]);
+
+    // Member3 pays the first cycle late
+    client.contribute(&creator, &group_id);
+    client.contribute(&member2, &group_id);
+    env.ledger().with_mut(|li| {
+        li.timestamp += CYCLE + 1;
+    });
+    client.contribute(&member3, &group_id);
+    env.ledger().with_mut(|li| {
+        li.timestamp += DAY;
+    });
+    client.execute_payout(&group_id);
+    for _ in 0..2 {
+        complete_cycle(&env, &client, group_id, &[&creator, &member2, &member3]);
+    }
+
+    let group = client.get_group(&group_id);
+    assert_eq!(group.state, GroupState::Complete);
+    let next_id = group.next_group_id.expect("group renewed");
+
+    // Same terms and payout order, without the member who left
+    let next = client.get_group(&next_id);
+    assert_eq!(next.previous_group_id, Some(group_id));
+    assert_eq!(next.creator, creator);
+    assert_eq!(next.members, vec![&env, creator.clone(), member3.clone()]);
+    assert_eq!(next.contribution_amount, CONTRIBUTION);
+    assert_eq!(next.max_members, 3);
+    assert_eq!(next.state, GroupState::Active);
+    assert_eq!(next.current_cycle, 1);
+
+    // Reputation carries over
+    let record = client.get_member_penalty_record(&next_id, &member3);
+    assert_eq!(record.group_id, next_id);
+    assert_eq!(record.late_count, 1);
+
+    // The next round is open to new members
+    client.join_group(&member2, &next_id);
+
+    let expected_topics: Vec<Val> = (symbol_short!("renewed"), group_id).into_val(&env);
+    let event = env
+        .events()
+        .all()
+        .iter()
+        .find(|(_, topics, _)| *topics == expected_topics)
+        .expect("renewal event emitted");
+    let renewed: GroupRenewedEvent = event.2.into_val(&env);
+    assert_eq!((renewed.next_group_id, renewed.members), (next_id, 2));
+}
+
+#[test]
+fn test_no_renewal_without_opt_ins() {
+    let (env, client, [creator, member2, _member3]) = setup_test_env();
+    let group_id = create_group(&client, &creator, &[&member2]);
+
+    complete_group(&env, &client, group_id, &[&creator, &member2]);
+    assert!(client.is_complete(&group_id));
+    assert_eq!(client.get_group(&group_id).next_group_id, None);
+}
+
+#[test]
+fn test_first_opted_in_member_leads_when_creator_leaves() {
+    let (env, client, [creator, member2, member3]) = setup_test_env();
+    let group_id = create_group(&client, &creator, &[&member2, &member3]);
+
+    client.opt_in_next_round(&member3, &group_id);
+    client.opt_in_next_round(&member2, &group_id);
+    complete_group(&env, &client, group_id, &[&creator, &member2, &member3]);
+
+    let next = client.get_group(&client.get_group(&group_id).next_group_id.unwrap());
+    assert_eq!(next.creator, member2);
+    assert_eq!(next.members, vec![&env, member2, member3]);
+}
+
+#[test]
+fn test_reshuffled_order_stays_funded() {
+    let (env, client, [creator, member2, _member3]) = setup_test_env();
+    let group_id = client.create_group(&creator, &CONTRIBUTION, &CYCLE, &2u32, &DAY, &5u32);
+    client.set_max_contribution_tier(&group_id, &3u32);
+    client.set_renewal_shuffle(&group_id, &true);
+    client.join_group(&member2, &group_id);
+    client.set_seat_tier(&member2, &group_id, &1u32, &3u32);
+
+    client.opt_in_next_round(&creator, &group_id);
+    client.opt_in_next_round(&member2, &group_id);
+    complete_group(&env, &client, group_id, &[&creator, &member2]);
+
+    // However the seats are shuffled, the larger tier is paid last
+    let next = client.get_group(&client.get_group(&group_id).next_group_id.unwrap());
+    assert!(next.shuffle_on_renewal);
+    assert_eq!(next.members, vec![&env, creator, member2]);
+    assert_eq!(next.seat_tiers, vec![&env, 1u32, 3u32]);
+}
+
+#[test]
+fn test_invalid_opt_ins_rejected() {
+    let (env, client, [creator, member2, member3]) = setup_test_env();
+    let group_id = create_group(&client, &creator, &[&member2]);
+
+    let result = client.try_opt_in_next_round(&member3, &group_id);
+    assert_eq!(result, Err(Ok(AjoError::NotMember)));
+
+    let result = client.try_set_renewal_shuffle(&group_id, &true);
+    assert_eq!(result, Err(Ok(AjoError::GroupTermsLocked)));
+
+    complete_group(&env, &client, group_id, &[&creator, &member2]);
+    let result = client.try_opt_in_next_round(&creator, &group_id);
+    assert_eq!(result, Err(Ok(AjoError::GroupComplete)));
+}
@@ -0,0 +1,216 @@
+#![cfg(test)]
+
+mod common;
+
+use soroban_ajo::{AjoContractClient, AjoError, MemberPairEvent};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events, Ledger},
+    vec, Address, Env, IntoVal, Val, Vec,
+};
+
+use common::setup_test_env;
+
+const CONTRIBUTION: i128 = 100_000_000;
+const CYCLE: u64 = 604_800;
+const DAY: u64 = 86400;
+
+/// Creates a full 3-member group with a one-day grace period and a 5% flat penalty
+fn create_group(
+    client: &AjoContractClient,
+    creator: &Address,
+    member2: &Address,
+    member3: &Address,
+) -> u64 {
+    let group_id = client.create_group(creator, &CONTRIBUTION, &CYCLE, &3u32, &DAY, &5u32);
+    client.join_group(member2, &group_id);
+    client.join_group(member3, &group_id);
+    group_id
+}
+
+/// Advances past the grace period and executes the payout
+fn finish_cycle(env: &Env, client: &AjoContractClient, group_id: u64) {
+    env.ledger().with_mut(|li| {
+        li.timestamp += CYCLE + DAY + 1;
+    });
+    client.execute_payout(&group_id);
+}
+
+#[test]
+fn test_transfer_keeps_payout_position() {
+    let (env, client, [creator, member2, member3]) = setup_test_env();
+    let group_id = create_group(&client, &creator, &member2, &member3);
+    let newcomer = Address::generate(&env);
+
+    client.transfer_seat(&group_id, &member2, &newcomer);
+
+    assert_eq!(
+        client.list_members(&group_id),
+        vec![&env, creator.clone(), newcomer.clone(), member3.clone()]
+    );
+    assert!(!client.is_member(&group_id, &member2));
+
+    for member in [&creator, &newcomer, &member3] {
+        client.contribute(member, &group_id);
+    }
+    finish_cycle(&env, &client, group_id);
+
+    // The newcomer inherits member2's turn in cycle 2
+    let status = client.get_group_status(&group_id);
+    assert_eq!(status.next_recipient, newcomer);
+}
+
+#[test]
+fn test_transfer_moves_history_and_payout_entitlement() {
+    let (env, client, [creator, member2, member3]) = setup_test_env();
+    let group_id = create_group(&client, &creator, &member2, &member3);
+
+    // Creator is paid in cycle 1, then hands the seat over
+    client.contribute(&creator, &group_id);
+    client.contribute(&member2, &group_id);
+    env.ledger().with_mut(|li| {
+        li.timestamp += CYCLE + 3600;
+    });
+    client.contribute(&member3, &group_id);
+    finish_cycle(&env, &client, group_id);
+
+    let newcomer = Address::generate(&env);
+    client.transfer_seat(&group_id, &creator, &newcomer);
+
+    let record = client.get_payout_record(&group_id, &newcomer);
+    assert_eq!(record.member, newcomer);
+    assert_eq!(record.cycle, 1);
+    assert!(client.try_get_payout_record(&group_id, &creator).is_err());
+
+    let detail = client.get_contribution_detail(&group_id, &1u32, &newcomer);
+    assert_eq!(detail.member, newcomer);
+    assert_eq!(detail.payer, newcomer);
+    assert_eq!(client.get_member_penalty_record(&group_id, &newcomer).on_time_count, 1);
+
+    // Late record moves too
+    let member3_replacement = Address::generate(&env);
+    client.transfer_seat(&group_id, &member3, &member3_replacement);
+    let penalty_record = client.get_member_penalty_record(&group_id, &member3_replacement);
+    assert_eq!(penalty_record.member, member3_replacement);
+    assert_eq!(penalty_record.late_count, 1);
+
+    // The paid seat can't be paid again, the rest of the group carries on
+    for member in [&newcomer, &member2, &member3_replacement] {
+        client.contribute(member, &group_id);
+    }
+    finish_cycle(&env, &client, group_id);
+    for member in [&newcomer, &member2, &member3_replacement] {
+        client.contribute(member, &group_id);
+    }
+    finish_cycle(&env, &client, group_id);
+
+    assert!(client.is_complete(&group_id));
+    assert_eq!(client.get_payout_record(&group_id, &member3_replacement).cycle, 3);
+}
+
+#[test]
+fn test_transfer_carries_outstanding_obligation() {
+    let (env, client, [creator, member2, member3]) = setup_test_env();
+    let group_id = create_group(&client, &creator, &member2, &member3);
+
+    client.contribute_partial(&member2, &group_id, &40_000_000);
+    client.prepay(&member2, &group_id, &1u32);
+
+    let newcomer = Address::generate(&env);
+    client.transfer_seat(&group_id, &member2, &newcomer);
+
+    // Part-paid installment carries over, but the newcomer pays the balance
+    let detail = client.get_contribution_detail(&group_id, &1u32, &newcomer);
+    assert_eq!(detail.amount_paid, 40_000_000);
+    assert!(!detail.has_paid);
+    assert_eq!(client.get_prepaid_through(&group_id, &newcomer), 2);
+
+    let statuses = client.get_contribution_status(&group_id, &2u32);
+    assert!(statuses.get(1).unwrap().1);
+
+    client.contribute_partial(&newcomer, &group_id, &60_000_000);
+    assert!(client.get_contribution_detail(&group_id, &1u32, &newcomer).has_paid);
+}
+
+#[test]
+fn test_sponsored_contribution_stays_refundable_to_sponsor() {
+    let (env, client, [creator, member2, member3]) = setup_test_env();
+    let group_id = create_group(&client, &creator, &member2, &member3);
+    let sponsor = Address::generate(&env);
+
+    client.contribute(&member2, &group_id);
+    client.contribute_for(&sponsor, &member3, &group_id);
+
+    let newcomer2 = Address::generate(&env);
+    let newcomer3 = Address::generate(&env);
+    client.transfer_seat(&group_id, &member2, &newcomer2);
+    client.transfer_seat(&group_id, &member3, &newcomer3);
+
+    client.cancel_group(&creator, &group_id);
+
+    assert_eq!(client.get_refund_record(&group_id, &newcomer2).amount, CONTRIBUTION);
+    assert_eq!(client.get_refund_record(&group_id, &sponsor).amount, CONTRIBUTION);
+    assert!(client.try_get_refund_record(&group_id, &member2).is_err());
+}
+
+#[test]
+fn test_transfer_requires_creator_approval_when_configured() {
+    let (env, client, [creator, member2, member3]) = setup_test_env();
+    let group_id = client.create_group(&creator, &CONTRIBUTION, &CYCLE, &3u32, &DAY, &5u32);
+    client.set_seat_transfer_approval(&group_id, &true);
+    client.join_group(&member2, &group_id);
+    client.join_group(&member3, &group_id);
+    assert!(client.get_group(&group_id).seat_transfer_needs_approval);
+
+    let newcomer = Address::generate(&env);
+    client.transfer_seat(&group_id, &member2, &newcomer);
+
+    let signers: std::vec::Vec<Address> = env.auths().into_iter().map(|(addr, _)| addr).collect();
+    assert!(signers.contains(&member2));
+    assert!(signers.contains(&newcomer));
+    assert!(signers.contains(&creator));
+}
+
+#[test]
+fn test_transfer_without_approval_needs_only_both_parties() {
+    let (env, client, [creator, member2, member3]) = setup_test_env();
+    let group_id = create_group(&client, &creator, &member2, &member3);
+
+    let newcomer = Address::generate(&env);
+    client.transfer_seat(&group_id, &member2, &newcomer);
+
+    let signers: std::vec::Vec<Address> = env.auths().into_iter().map(|(addr, _)| addr).collect();
+    assert_eq!(signers, [member2, newcomer]);
+}
+
+#[test]
+fn test_invalid_transfers_rejected() {
+    let (env, client, [creator, member2, member3]) = setup_test_env();
+    let group_id = create_group(&client, &creator, &member2, &member3);
+
+    let outsider = Address::generate(&env);
+    let result = client.try_transfer_seat(&group_id, &outsider, &member2);
+    assert_eq!(result, Err(Ok(AjoError::NotMember)));
+
+    let result = client.try_transfer_seat(&group_id, &member2, &member3);
+    assert_eq!(result, Err(Ok(AjoError::AlreadyMember)));
+}
+
+#[test]
+fn test_seat_transfer_event() {
+    let (env, client, [creator, member2, member3]) = setup_test_env();
+    let group_id = create_group(&client, &creator, &member2, &member3);
+
+    let newcomer = Address::generate(&env);
+    client.transfer_seat(&group_id, &member2, &newcomer);
+
+    let expected_topics: Vec<Val> = (symbol_short!("seatxfer"), group_id).into_val(&env);
+    let event = env
+        .events()
+        .all()
+        .iter()
+        .find(|(_, topics, _)| *topics == expected_topics)
+        .expect("seat transfer event emitted");
+    let transfer: MemberPairEvent = event.2.into_val(&env);
+    assert_eq!((transfer.from, transfer.to), (member2, newcomer));
+}
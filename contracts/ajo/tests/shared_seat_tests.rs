@@ -0,0 +1,206 @@
+#![cfg(test)]
+
+mod common;
+
+use soroban_ajo::{
+    AjoContractClient, AjoError, PayoutShare, PayoutSplit, PenaltyPolicy,
+    SeatSharedEvent,
+};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events, Ledger},
+    vec, Address, Env, IntoVal, Val, Vec,
+};
+
+use common::{complete_cycle, setup_test_env};
+
+const CONTRIBUTION: i128 = 100_000_000;
+const CYCLE: u64 = 604_800;
+const DAY: u64 = 86400;
+
+/// Creates a full 3-seat group whose last seat is shared by `half_a` and `half_b`
+fn create_group(
+    env: &Env,
+    client: &AjoContractClient,
+    creator: &Address,
+    member2: &Address,
+    half_a: &Address,
+    half_b: &Address,
+) -> u64 {
+    let group_id = client.create_group(creator, &CONTRIBUTION, &CYCLE, &3u32, &DAY, &5u32);
+    client.join_group(member2, &group_id);
+    let seat = client.join_shared_seat(&group_id, &vec![env, half_a.clone(), half_b.clone()]);
+    assert_eq!(seat, 2);
+    group_id
+}
+
+#[test]
+fn test_co_holders_share_one_seat() {
+    let (env, client, [creator, member2, half_a, half_b]) = setup_test_env();
+    let group_id = create_group(&env, &client, &creator, &member2, &half_a, &half_b);
+
+    assert_eq!(
+        client.list_members(&group_id),
+        vec![&env, creator.clone(), member2.clone(), half_a.clone()]
+    );
+    let holders = vec![&env, half_a.clone(), half_b.clone()];
+    assert_eq!(client.get_seat_holders(&group_id, &half_a), holders);
+    assert_eq!(client.get_seat_holders(&group_id, &half_b), holders);
+    assert!(client.get_seat_holders(&group_id, &creator).is_empty());
+    assert!(client.is_member(&group_id, &half_b));
+
+    let expected_topics: Vec<Val> = (symbol_short!("seatshare"), group_id).into_val(&env);
+    let event = env
+        .events()
+        .all()
+        .iter()
+        .find(|(_, topics, _)| *topics == expected_topics)
+        .expect("shared seat event emitted");
+    let shared: SeatSharedEvent = event.2.into_val(&env);
+    assert_eq!(shared.holders, holders);
+}
+
+#[test]
+fn test_shared_seat_complete_only_when_all_co_holders_pay() {
+    let (env, client, [creator, member2, half_a, half_b]) = setup_test_env();
+    let group_id = create_group(&env, &client, &creator, &member2, &half_a, &half_b);
+
+    client.contribute(&creator, &group_id);
+    client.contribute(&member2, &group_id);
+    client.contribute(&half_a, &group_id);
+
+    let record = client.get_contribution_detail(&group_id, &1u32, &half_a);
+    assert!(record.has_paid);
+    assert_eq!(record.amount_paid, CONTRIBUTION / 2);
+
+    let statuses = client.get_contribution_status(&group_id, &1u32);
+    assert!(!statuses.get(2).unwrap().1);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp += CYCLE + DAY + 1;
+    });
+    let result = client.try_execute_payout(&group_id);
+    assert_eq!(result, Err(Ok(AjoError::IncompleteContributions)));
+}
+
+#[test]
+fn test_shared_seat_payout_split_between_co_holders() {
+    let (env, client, [creator, member2, half_a, half_b]) = setup_test_env();
+    let group_id = create_group(&env, &client, &creator, &member2, &half_a, &half_b);
+    let members = [&creator, &member2, &half_a, &half_b];
+
+    complete_cycle(&env, &client, group_id, &members);
+    complete_cycle(&env, &client, group_id, &members);
+    complete_cycle(&env, &client, group_id, &members);
+    assert!(client.is_complete(&group_id));
+
+    let expected_shares = vec![
+        &env,
+        PayoutShare { recipient: half_a.clone(), amount: 3 * CONTRIBUTION / 2 },
+        PayoutShare { recipient: half_b.clone(), amount: 3 * CONTRIBUTION / 2 },
+    ];
+    for holder in [&half_a, &half_b] {
+        let record = client.get_payout_record(&group_id, holder);
+        assert_eq!(record.member, *holder);
+        assert_eq!(record.cycle, 3);
+        assert_eq!(record.amount, 3 * CONTRIBUTION);
+        assert_eq!(record.shares, expected_shares);
+    }
+}
+
+#[test]
+fn test_uneven_split_remainder_falls_to_first_holder() {
+    let (env, client, [creator, member2, half_a, half_b]) = setup_test_env();
+    let group_id = client.create_group(&creator, &1_001i128, &CYCLE, &3u32, &DAY, &5u32);
+    client.join_group(&member2, &group_id);
+    client.join_shared_seat(&group_id, &vec![&env, half_a.clone(), half_b.clone()]);
+
+    client.contribute(&half_a, &group_id);
+    client.contribute(&half_b, &group_id);
+    assert_eq!(client.get_contribution_detail(&group_id, &1u32, &half_a).amount_paid, 501);
+    assert_eq!(client.get_contribution_detail(&group_id, &1u32, &half_b).amount_paid, 500);
+}
+
+#[test]
+fn test_on_time_penalties_shared_with_each_co_holder() {
+    let (env, client, [creator, member2, half_a, half_b]) = setup_test_env();
+    let group_id = client.create_group(&creator, &CONTRIBUTION, &CYCLE, &3u32, &DAY, &5u32);
+    client.set_penalty_policy(&group_id, &PenaltyPolicy::OnTimeContributors);
+    client.join_group(&member2, &group_id);
+    client.join_shared_seat(&group_id, &vec![&env, half_a.clone(), half_b.clone()]);
+
+    for member in [&creator, &half_a, &half_b] {
+        client.contribute(member, &group_id);
+    }
+    env.ledger().with_mut(|li| {
+        li.timestamp += CYCLE + 3600;
+    });
+    client.contribute(&member2, &group_id);
+    env.ledger().with_mut(|li| {
+        li.timestamp += DAY;
+    });
+    client.execute_payout(&group_id);
+
+    // member2's 5 XLM penalty is split three ways, remainder to the first
+    assert_eq!(client.get_penalty_earnings(&group_id, &creator), 1_666_668);
+    assert_eq!(client.get_penalty_earnings(&group_id, &half_a), 1_666_666);
+    assert_eq!(client.get_penalty_earnings(&group_id, &half_b), 1_666_666);
+    assert_eq!(client.get_penalty_earnings(&group_id, &member2), 0);
+}
+
+#[test]
+fn test_cancel_refunds_each_co_holder() {
+    let (env, client, [creator, member2, half_a, half_b]) = setup_test_env();
+    let group_id = create_group(&env, &client, &creator, &member2, &half_a, &half_b);
+
+    client.contribute(&half_a, &group_id);
+    client.contribute(&half_b, &group_id);
+    client.cancel_group(&creator, &group_id);
+
+    assert_eq!(client.get_refund_record(&group_id, &half_a).amount, CONTRIBUTION / 2);
+    assert_eq!(client.get_refund_record(&group_id, &half_b).amount, CONTRIBUTION / 2);
+}
+
+#[test]
+fn test_invalid_shared_seats_rejected() {
+    let (env, client, [creator, member2, half_a, half_b]) = setup_test_env();
+    let group_id = client.create_group(&creator, &CONTRIBUTION, &CYCLE, &4u32, &DAY, &5u32);
+    client.join_group(&member2, &group_id);
+
+    let result = client.try_join_shared_seat(&group_id, &vec![&env, half_a.clone()]);
+    assert_eq!(result, Err(Ok(AjoError::MaxMembersBelowMinimum)));
+
+    let mut too_many = Vec::new(&env);
+    for _ in 0..5 {
+        too_many.push_back(Address::generate(&env));
+    }
+    let result = client.try_join_shared_seat(&group_id, &too_many);
+    assert_eq!(result, Err(Ok(AjoError::MaxMembersAboveLimit)));
+
+    let result = client.try_join_shared_seat(&group_id, &vec![&env, half_a.clone(), half_a.clone()]);
+    assert_eq!(result, Err(Ok(AjoError::AlreadyMember)));
+
+    let result = client.try_join_shared_seat(&group_id, &vec![&env, half_a.clone(), member2.clone()]);
+    assert_eq!(result, Err(Ok(AjoError::AlreadyMember)));
+
+    // Co-holders can't take a seat of their own
+    client.join_shared_seat(&group_id, &vec![&env, half_a.clone(), half_b.clone()]);
+    let result = client.try_join_group(&half_b, &group_id);
+    assert_eq!(result, Err(Ok(AjoError::AlreadyMember)));
+}
+
+#[test]
+fn test_shared_seat_splits_and_transfers_rejected() {
+    let (env, client, [creator, member2, half_a, half_b]) = setup_test_env();
+    let group_id = create_group(&env, &client, &creator, &member2, &half_a, &half_b);
+
+    let wallet = Address::generate(&env);
+    let splits = vec![&env, PayoutSplit { recipient: wallet.clone(), bps: 10_000 }];
+    let result = client.try_set_payout_splits(&half_a, &group_id, &splits);
+    assert_eq!(result, Err(Ok(AjoError::InvalidPayoutSplit)));
+
+    let result = client.try_transfer_seat(&group_id, &half_a, &wallet);
+    assert_eq!(result, Err(Ok(AjoError::GroupTermsLocked)));
+    let result = client.try_transfer_seat(&group_id, &member2, &half_b);
+    assert_eq!(result, Err(Ok(AjoError::AlreadyMember)));
+}
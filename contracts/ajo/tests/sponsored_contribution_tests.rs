@@ -0,0 +1,139 @@
+#![cfg(test)]
+
+mod common;
+
+use soroban_ajo::{AjoContractClient, AjoError, SponsoredEvent};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Events, Ledger},
+    Address, IntoVal, Val, Vec,
+};
+
+use common::setup_test_env;
+
+const CONTRIBUTION: i128 = 100_000_000;
+const CYCLE: u64 = 604_800;
+const DAY: u64 = 86400;
+
+/// Creates a full 2-member group with a one-day grace period and a 5% flat penalty
+fn create_group(client: &AjoContractClient, creator: &Address, member2: &Address) -> u64 {
+    let group_id = client.create_group(creator, &CONTRIBUTION, &CYCLE, &2u32, &DAY, &5u32);
+    client.join_group(member2, &group_id);
+    group_id
+}
+
+#[test]
+fn test_sponsor_pays_and_member_gets_credit() {
+    let (_env, client, [creator, member2, sponsor]) = setup_test_env();
+    let group_id = create_group(&client, &creator, &member2);
+
+    client.contribute_for(&sponsor, &member2, &group_id);
+
+    let record = client.get_contribution_detail(&group_id, &1u32, &member2);
+    assert!(record.has_paid);
+    assert_eq!(record.member, member2);
+    assert_eq!(record.payer, sponsor);
+    assert_eq!(record.amount_paid, CONTRIBUTION);
+
+    let penalty_record = client.get_member_penalty_record(&group_id, &member2);
+    assert_eq!(penalty_record.on_time_count, 1);
+
+    let result = client.try_contribute(&member2, &group_id);
+    assert_eq!(result, Err(Ok(AjoError::AlreadyContributed)));
+}
+
+#[test]
+fn test_own_contribution_records_member_as_payer() {
+    let (_env, client, [creator, member2, _]) = setup_test_env();
+    let group_id = create_group(&client, &creator, &member2);
+
+    client.contribute(&member2, &group_id);
+
+    let record = client.get_contribution_detail(&group_id, &1u32, &member2);
+    assert_eq!(record.payer, member2);
+}
+
+#[test]
+fn test_sponsored_late_contribution_penalizes_member() {
+    let (env, client, [creator, member2, sponsor]) = setup_test_env();
+    let group_id = create_group(&client, &creator, &member2);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp += CYCLE + 3600;
+    });
+    client.contribute_for(&sponsor, &member2, &group_id);
+
+    let penalty_record = client.get_member_penalty_record(&group_id, &member2);
+    assert_eq!(penalty_record.late_count, 1);
+    assert_eq!(penalty_record.total_penalties, 5_000_000);
+}
+
+#[test]
+fn test_sponsor_cannot_pay_for_non_member() {
+    let (_env, client, [creator, member2, sponsor]) = setup_test_env();
+    let group_id = create_group(&client, &creator, &member2);
+
+    let result = client.try_contribute_for(&sponsor, &sponsor, &group_id);
+    assert_eq!(result, Err(Ok(AjoError::NotMember)));
+}
+
+#[test]
+fn test_sponsor_cannot_finish_member_installments() {
+    let (_env, client, [creator, member2, sponsor]) = setup_test_env();
+    let group_id = create_group(&client, &creator, &member2);
+
+    client.contribute_partial(&member2, &group_id, &40_000_000);
+
+    let result = client.try_contribute_for(&sponsor, &member2, &group_id);
+    assert_eq!(result, Err(Ok(AjoError::ContributionPayerMismatch)));
+}
+
+#[test]
+fn test_refund_goes_to_sponsor() {
+    let (_env, client, [creator, member2, sponsor]) = setup_test_env();
+    let group_id = create_group(&client, &creator, &member2);
+
+    client.contribute(&creator, &group_id);
+    client.contribute_for(&sponsor, &member2, &group_id);
+
+    client.cancel_group(&creator, &group_id);
+
+    assert_eq!(client.get_refund_record(&group_id, &creator).amount, CONTRIBUTION);
+    assert_eq!(client.get_refund_record(&group_id, &sponsor).amount, CONTRIBUTION);
+    assert!(client.try_get_refund_record(&group_id, &member2).is_err());
+}
+
+#[test]
+fn test_refunds_grouped_by_payer() {
+    let (_env, client, [creator, member2, _]) = setup_test_env();
+    let group_id = create_group(&client, &creator, &member2);
+
+    // The creator pays for both seats
+    client.contribute(&creator, &group_id);
+    client.contribute_for(&creator, &member2, &group_id);
+
+    client.cancel_group(&creator, &group_id);
+
+    assert_eq!(client.get_refund_record(&group_id, &creator).amount, 2 * CONTRIBUTION);
+}
+
+#[test]
+fn test_sponsored_event() {
+    let (env, client, [creator, member2, sponsor]) = setup_test_env();
+    let group_id = create_group(&client, &creator, &member2);
+
+    client.contribute_for(&sponsor, &member2, &group_id);
+
+    let expected_topics: Vec<Val> = (symbol_short!("sponsored"), group_id).into_val(&env);
+    let event = env
+        .events()
+        .all()
+        .iter()
+        .find(|(_, topics, _)| *topics == expected_topics)
+        .expect("sponsored event emitted");
+    let sponsored: SponsoredEvent = event.2.into_val(&env);
+    assert_eq!(sponsored.cycle, 1);
+    assert_eq!(sponsored.payer, sponsor);
+    assert_eq!(sponsored.member, member2);
+    assert_eq!(sponsored.amount, CONTRIBUTION);
+}
@@ -0,0 +1,137 @@
+#![cfg(test)]
+
+mod common;
+
+use soroban_ajo::{AjoContractClient, AjoError, SwapEvent};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events},
+    vec, Address, IntoVal, Val, Vec,
+};
+
+use common::{complete_cycle, setup_test_env};
+
+const CONTRIBUTION: i128 = 100_000_000;
+const CYCLE: u64 = 604_800;
+const DAY: u64 = 86400;
+
+/// Creates a full 4-member group with a one-day grace period
+fn create_group(client: &AjoContractClient, members: &[&Address]) -> u64 {
+    let group_id = client.create_group(members[0], &CONTRIBUTION, &CYCLE, &4u32, &DAY, &5u32);
+    for member in &members[1..] {
+        client.join_group(member, &group_id);
+    }
+    group_id
+}
+
+#[test]
+fn test_swap_exchanges_payout_positions() {
+    let (env, client, [creator, member2, member3, member4]) = setup_test_env();
+    let members = [&creator, &member2, &member3, &member4];
+    let group_id = create_group(&client, &members);
+
+    client.propose_swap(&group_id, &member4, &member2);
+    let proposal = client.get_swap_proposal(&group_id, &member4).unwrap();
+    assert_eq!(proposal.to, member2);
+
+    client.accept_swap(&group_id, &member4, &member2);
+    assert!(client.get_swap_proposal(&group_id, &member4).is_none());
+
+    let status = client.get_group_status(&group_id);
+    assert_eq!(
+        status.payout_order,
+        vec![&env, creator.clone(), member4.clone(), member3.clone(), member2.clone()]
+    );
+
+    complete_cycle(&env, &client, group_id, &members);
+    complete_cycle(&env, &client, group_id, &members);
+
+    // Member4 took member2's turn in cycle 2
+    assert_eq!(client.get_payout_record(&group_id, &member4).cycle, 2);
+    let status = client.get_group_status(&group_id);
+    assert_eq!(status.next_recipient, member3);
+    assert_eq!(status.payout_order, vec![&env, member3, member2]);
+}
+
+#[test]
+fn test_swap_event() {
+    let (env, client, [creator, member2, member3, member4]) = setup_test_env();
+    let group_id = create_group(&client, &[&creator, &member2, &member3, &member4]);
+
+    client.propose_swap(&group_id, &member4, &member2);
+    client.accept_swap(&group_id, &member4, &member2);
+
+    let expected_topics: Vec<Val> = (symbol_short!("swapped"), group_id).into_val(&env);
+    let event = env
+        .events()
+        .all()
+        .iter()
+        .find(|(_, topics, _)| *topics == expected_topics)
+        .expect("swap event emitted");
+    let swap: SwapEvent = event.2.into_val(&env);
+    assert_eq!((swap.from, swap.to), (member4, member2));
+    assert_eq!((swap.from_cycle, swap.to_cycle), (2, 4));
+}
+
+#[test]
+fn test_cannot_swap_with_current_or_paid_recipient() {
+    let (env, client, [creator, member2, member3, member4]) = setup_test_env();
+    let members = [&creator, &member2, &member3, &member4];
+    let group_id = create_group(&client, &members);
+
+    // The creator is this cycle's recipient
+    let result = client.try_propose_swap(&group_id, &member3, &creator);
+    assert_eq!(result, Err(Ok(AjoError::AlreadyReceivedPayout)));
+
+    complete_cycle(&env, &client, group_id, &members);
+
+    // The creator has been paid and member2 is now the current recipient
+    let result = client.try_propose_swap(&group_id, &creator, &member4);
+    assert_eq!(result, Err(Ok(AjoError::AlreadyReceivedPayout)));
+    let result = client.try_propose_swap(&group_id, &member2, &member4);
+    assert_eq!(result, Err(Ok(AjoError::AlreadyReceivedPayout)));
+
+    client.propose_swap(&group_id, &member3, &member4);
+}
+
+#[test]
+fn test_accept_rechecks_eligibility() {
+    let (env, client, [creator, member2, member3, member4]) = setup_test_env();
+    let members = [&creator, &member2, &member3, &member4];
+    let group_id = create_group(&client, &members);
+
+    client.propose_swap(&group_id, &member2, &member4);
+
+    // Member2 becomes the current recipient before the swap is accepted
+    complete_cycle(&env, &client, group_id, &members);
+
+    let result = client.try_accept_swap(&group_id, &member2, &member4);
+    assert_eq!(result, Err(Ok(AjoError::AlreadyReceivedPayout)));
+}
+
+#[test]
+fn test_accept_requires_matching_proposal() {
+    let (_env, client, [creator, member2, member3, member4]) = setup_test_env();
+    let group_id = create_group(&client, &[&creator, &member2, &member3, &member4]);
+
+    let result = client.try_accept_swap(&group_id, &member2, &member3);
+    assert_eq!(result, Err(Ok(AjoError::Unauthorized)));
+
+    // Proposed to member4, so member3 can't accept it
+    client.propose_swap(&group_id, &member2, &member4);
+    let result = client.try_accept_swap(&group_id, &member2, &member3);
+    assert_eq!(result, Err(Ok(AjoError::Unauthorized)));
+}
+
+#[test]
+fn test_invalid_swap_participants_rejected() {
+    let (env, client, [creator, member2, member3, member4]) = setup_test_env();
+    let group_id = create_group(&client, &[&creator, &member2, &member3, &member4]);
+
+    let outsider = Address::generate(&env);
+    let result = client.try_propose_swap(&group_id, &member2, &outsider);
+    assert_eq!(result, Err(Ok(AjoError::NotMember)));
+
+    let result = client.try_propose_swap(&group_id, &member2, &member2);
+    assert_eq!(result, Err(Ok(AjoError::NotMember)));
+}
@@ -0,0 +1,224 @@
+#![cfg(test)]
+
+mod common;
+
+use soroban_ajo::{
+    AjoContractClient, AjoError, GroupKind, PenaltyEvent, SavingsCycleClosedEvent,
+};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Events, Ledger},
+    Address, Env, IntoVal, Val, Vec,
+};
+
+use common::{complete_cycle, setup_test_env};
+
+const CONTRIBUTION: i128 = 100_000_000;
+const CYCLE: u64 = 604_800;
+const DAY: u64 = 86400;
+
+/// Creates a full 2-member group saving for 3 cycles
+fn create_savings_group(
+    client: &AjoContractClient,
+    creator: &Address,
+    member2: &Address,
+    early_withdrawal_bps: u32,
+) -> u64 {
+    let group_id = client.create_group(creator, &CONTRIBUTION, &CYCLE, &2u32, &DAY, &5u32);
+    client.set_target_savings(&group_id, &3u32, &early_withdrawal_bps);
+    client.join_group(member2, &group_id);
+    group_id
+}
+
+/// Advances past the grace period and closes the cycle
+fn close_cycle(env: &Env, client: &AjoContractClient, group_id: u64) {
+    env.ledger().with_mut(|li| {
+        li.timestamp += CYCLE + DAY + 1;
+    });
+    client.execute_payout(&group_id);
+}
+
+#[test]
+fn test_savers_withdraw_their_own_balance_at_maturity() {
+    let (env, client, [creator, member2]) = setup_test_env();
+    let group_id = create_savings_group(&client, &creator, &member2, 1_000);
+
+    let group = client.get_group(&group_id);
+    assert_eq!(group.kind, GroupKind::TargetSavings);
+    assert_eq!(group.savings_cycles, 3);
+    assert_eq!(group.early_withdrawal_bps, 1_000);
+
+    for _ in 0..3 {
+        client.contribute(&creator, &group_id);
+        client.contribute(&member2, &group_id);
+        close_cycle(&env, &client, group_id);
+    }
+
+    assert!(client.is_complete(&group_id));
+    assert!(client.try_get_payout_record(&group_id, &creator).is_err());
+    assert_eq!(client.get_savings_balance(&group_id, &creator), 3 * CONTRIBUTION);
+
+    assert_eq!(client.withdraw_savings(&creator, &group_id), 3 * CONTRIBUTION);
+    assert_eq!(client.withdraw_savings(&member2, &group_id), 3 * CONTRIBUTION);
+
+    let record = client.get_payout_record(&group_id, &creator);
+    assert_eq!(record.amount, 3 * CONTRIBUTION);
+    assert_eq!(record.cycle, 3);
+    assert_eq!(client.get_payout_record(&group_id, &member2).amount, 3 * CONTRIBUTION);
+
+    let result = client.try_withdraw_savings(&creator, &group_id);
+    assert_eq!(result, Err(Ok(AjoError::AlreadyReceivedPayout)));
+
+    let expected_topics: Vec<Val> = (symbol_short!("savewdraw"), group_id).into_val(&env);
+    let event = env
+        .events()
+        .all()
+        .iter()
+        .find(|(_, topics, _)| *topics == expected_topics)
+        .expect("savings withdrawal event emitted");
+    let withdrawal: PenaltyEvent = event.2.into_val(&env);
+    assert_eq!(withdrawal.cycle, 3);
+    assert_eq!((withdrawal.member, withdrawal.amount, withdrawal.penalty), (creator, 3 * CONTRIBUTION, 0));
+}
+
+#[test]
+fn test_missed_contribution_does_not_block_the_cycle() {
+    let (env, client, [creator, member2]) = setup_test_env();
+    let group_id = create_savings_group(&client, &creator, &member2, 1_000);
+
+    client.contribute(&creator, &group_id);
+    env.ledger().with_mut(|li| {
+        li.timestamp += CYCLE + DAY - 1;
+    });
+    let result = client.try_execute_payout(&group_id);
+    assert_eq!(result, Err(Ok(AjoError::OutsideCycleWindow)));
+
+    close_cycle(&env, &client, group_id);
+    assert_eq!(client.get_group(&group_id).current_cycle, 2);
+
+    let record = client.get_member_penalty_record(&group_id, &member2);
+    assert_eq!(record.late_count, 1);
+    assert_eq!(client.get_savings_balance(&group_id, &member2), 0);
+
+    let expected_topics: Vec<Val> = (symbol_short!("saveclose"), group_id).into_val(&env);
+    let event = env
+        .events()
+        .all()
+        .iter()
+        .find(|(_, topics, _)| *topics == expected_topics)
+        .expect("cycle closed event emitted");
+    let closed: SavingsCycleClosedEvent = event.2.into_val(&env);
+    assert_eq!((closed.cycle, closed.missed), (1, 1));
+}
+
+#[test]
+fn test_penalties_shared_at_maturity() {
+    let (env, client, [creator, member2]) = setup_test_env();
+    let group_id = create_savings_group(&client, &creator, &member2, 1_000);
+
+    // Member2 pays the first cycle late and is penalized 5%
+    client.contribute(&creator, &group_id);
+    env.ledger().with_mut(|li| {
+        li.timestamp += CYCLE + 1;
+    });
+    client.contribute(&member2, &group_id);
+    env.ledger().with_mut(|li| {
+        li.timestamp += DAY;
+    });
+    client.execute_payout(&group_id);
+    assert_eq!(client.get_group_reserve(&group_id), 5_000_000);
+
+    for _ in 0..2 {
+        client.contribute(&creator, &group_id);
+        client.contribute(&member2, &group_id);
+        close_cycle(&env, &client, group_id);
+    }
+
+    assert_eq!(client.get_group_reserve(&group_id), 0);
+    assert_eq!(client.withdraw_savings(&creator, &group_id), 3 * CONTRIBUTION + 2_500_000);
+    assert_eq!(client.withdraw_savings(&member2, &group_id), 3 * CONTRIBUTION + 2_500_000);
+}
+
+#[test]
+fn test_early_withdrawal_forfeits_to_remaining_savers() {
+    let (env, client, [creator, member2]) = setup_test_env();
+    let group_id = create_savings_group(&client, &creator, &member2, 1_000);
+
+    client.contribute(&creator, &group_id);
+    client.contribute(&member2, &group_id);
+    close_cycle(&env, &client, group_id);
+
+    assert_eq!(client.withdraw_savings(&member2, &group_id), 90_000_000);
+    assert_eq!(client.get_group_reserve(&group_id), 10_000_000);
+
+    let result = client.try_contribute(&member2, &group_id);
+    assert_eq!(result, Err(Ok(AjoError::AlreadyReceivedPayout)));
+
+    for _ in 0..2 {
+        client.contribute(&creator, &group_id);
+        close_cycle(&env, &client, group_id);
+    }
+
+    // Member2 left the group, so their missed cycles aren't counted against them
+    assert_eq!(client.get_member_penalty_record(&group_id, &member2).late_count, 0);
+    assert_eq!(client.withdraw_savings(&creator, &group_id), 3 * CONTRIBUTION + 10_000_000);
+}
+
+#[test]
+fn test_prepay_limited_to_savings_cycles() {
+    let (_env, client, [creator, member2]) = setup_test_env();
+    let group_id = create_savings_group(&client, &creator, &member2, 1_000);
+
+    let result = client.try_prepay(&creator, &group_id, &3u32);
+    assert_eq!(result, Err(Ok(AjoError::InvalidPrepayment)));
+
+    client.contribute(&creator, &group_id);
+    assert_eq!(client.prepay(&creator, &group_id, &2u32), 3);
+    assert_eq!(client.get_savings_balance(&group_id, &creator), 3 * CONTRIBUTION);
+}
+
+#[test]
+fn test_savings_group_cannot_be_cancelled_after_a_cycle_closes() {
+    let (env, client, [creator, member2]) = setup_test_env();
+    let group_id = create_savings_group(&client, &creator, &member2, 1_000);
+
+    client.contribute(&creator, &group_id);
+    close_cycle(&env, &client, group_id);
+
+    let result = client.try_cancel_group(&creator, &group_id);
+    assert_eq!(result, Err(Ok(AjoError::CannotCancelAfterPayout)));
+}
+
+#[test]
+fn test_invalid_savings_terms_rejected() {
+    let (_env, client, [creator, member2]) = setup_test_env();
+    let group_id = client.create_group(&creator, &CONTRIBUTION, &CYCLE, &2u32, &DAY, &5u32);
+
+    let result = client.try_set_target_savings(&group_id, &0u32, &1_000u32);
+    assert_eq!(result, Err(Ok(AjoError::InvalidCycleSchedule)));
+    let result = client.try_set_target_savings(&group_id, &101u32, &1_000u32);
+    assert_eq!(result, Err(Ok(AjoError::InvalidCycleSchedule)));
+    let result = client.try_set_target_savings(&group_id, &3u32, &10_001u32);
+    assert_eq!(result, Err(Ok(AjoError::InvalidPenaltyRate)));
+
+    client.join_group(&member2, &group_id);
+    let result = client.try_set_target_savings(&group_id, &3u32, &1_000u32);
+    assert_eq!(result, Err(Ok(AjoError::GroupTermsLocked)));
+
+    // Rotating groups have no savings to withdraw
+    let result = client.try_withdraw_savings(&creator, &group_id);
+    assert_eq!(result, Err(Ok(AjoError::InvalidGroupKind)));
+}
+
+#[test]
+fn test_paid_rotating_member_cannot_withdraw_savings() {
+    let (env, client, [creator, member2]) = setup_test_env();
+    let group_id = client.create_group(&creator, &CONTRIBUTION, &CYCLE, &2u32, &DAY, &5u32);
+    client.join_group(&member2, &group_id);
+    complete_cycle(&env, &client, group_id, &[&creator, &member2]);
+    assert_eq!(client.get_payout_record(&group_id, &creator).cycle, 1);
+
+    // The group kind is checked before the payout, so the error says why
+    let result = client.try_withdraw_savings(&creator, &group_id);
+    assert_eq!(result, Err(Ok(AjoError::InvalidGroupKind)));
+}
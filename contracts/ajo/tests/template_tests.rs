@@ -0,0 +1,200 @@
+#![cfg(test)]
+
+mod common;
+
+use soroban_ajo::{
+    AjoError, CycleSchedule, GroupKind, GroupMetadata,
+    GroupSourceEvent, GroupState, GroupTemplate, GroupTerms, OrganizerCommission, PenaltyPolicy,
+    PenaltySchedule, YieldPolicy,
+};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Events, Ledger},
+    vec, Address, Env, IntoVal, String, Val, Vec,
+};
+
+use common::setup_test_env;
+
+const CONTRIBUTION: i128 = 100_000_000;
+const CYCLE: u64 = 604_800;
+const DAY: u64 = 86400;
+
+/// Builds metadata with the given name
+fn metadata(env: &Env, name: &str) -> GroupMetadata {
+    GroupMetadata {
+        name: String::from_str(env, name),
+        description: String::from_str(env, "Weekly market savings"),
+        rules: String::from_str(env, "Pay by Friday"),
+    }
+}
+
+/// Builds the terms of a 4-member weekly group with an organizer commission
+fn terms() -> GroupTerms {
+    GroupTerms {
+        contribution_amount: CONTRIBUTION,
+        cycle_duration: CYCLE,
+        contribution_interval: 0,
+        max_members: 4,
+        grace_period: DAY,
+        penalty_rate: 10,
+        commission: OrganizerCommission::BasisPoints(250),
+        penalty_policy: PenaltyPolicy::GroupReserve,
+        penalty_schedule: PenaltySchedule::Flat,
+        cycle_schedule: CycleSchedule::Anchored,
+        keeper_bounty: 1_000_000,
+        token: None,
+        seat_transfer_needs_approval: true,
+        max_seats_per_member: 2,
+        max_contribution_tier: 1,
+        recipients_per_cycle: 2,
+        insurance_premium_bps: 0,
+        vault: None,
+        yield_policy: YieldPolicy::Recipient,
+        kind: GroupKind::Rotating,
+        savings_cycles: 0,
+        early_withdrawal_bps: 0,
+        shuffle_on_renewal: false,
+    }
+}
+
+/// Builds a template with [`terms`]
+fn template(creator: &Address) -> GroupTemplate {
+    GroupTemplate { creator: creator.clone(), terms: terms() }
+}
+
+#[test]
+fn test_create_group_from_template() {
+    let (env, client, [creator, member2]) = setup_test_env();
+
+    let template_id = client.save_template(&template(&creator), &Some(metadata(&env, "Market traders")));
+    assert_eq!(client.get_template(&template_id), template(&creator));
+    assert_eq!(client.get_template_metadata(&template_id), metadata(&env, "Market traders"));
+
+    // Anyone can start a group from a saved template
+    let group_id = client.create_group_from_template(&member2, &template_id);
+    let group = client.get_group(&group_id);
+    assert_eq!(group.creator, member2);
+    assert_eq!(group.members, vec![&env, member2.clone()]);
+    assert_eq!(group.contribution_amount, CONTRIBUTION);
+    assert_eq!(group.cycle_duration, CYCLE);
+    assert_eq!(group.max_members, 4);
+    assert_eq!(group.grace_period, DAY);
+    assert_eq!(group.penalty_rate, 10);
+    assert_eq!(group.commission, OrganizerCommission::BasisPoints(250));
+    assert_eq!(group.penalty_policy, PenaltyPolicy::GroupReserve);
+    assert_eq!(group.cycle_schedule, CycleSchedule::Anchored);
+    assert_eq!(group.keeper_bounty, 1_000_000);
+    assert!(group.seat_transfer_needs_approval);
+    assert_eq!(group.max_seats_per_member, 2);
+    assert_eq!(group.recipients_per_cycle, 2);
+    assert_eq!(client.get_group_metadata(&group_id), metadata(&env, "Market traders"));
+
+    let expected_topics: Vec<Val> = (symbol_short!("fromtmpl"), group_id).into_val(&env);
+    let event = env
+        .events()
+        .all()
+        .iter()
+        .find(|(_, topics, _)| *topics == expected_topics)
+        .expect("group from template event emitted");
+    let source: GroupSourceEvent = event.2.into_val(&env);
+    assert_eq!(source.source_id, template_id);
+}
+
+#[test]
+fn test_templates_listed_per_creator() {
+    let (env, client, [creator, member2]) = setup_test_env();
+
+    let first = client.save_template(&template(&creator), &Some(metadata(&env, "First")));
+    let other = client.save_template(&template(&member2), &None);
+    let second = client.save_template(&template(&creator), &None);
+
+    assert_eq!(client.get_creator_templates(&creator), vec![&env, first, second]);
+    assert_eq!(client.get_creator_templates(&member2), vec![&env, other]);
+
+    // A template without metadata creates a group without metadata
+    let group_id = client.create_group_from_template(&creator, &second);
+    let result = client.try_get_group_metadata(&group_id);
+    assert_eq!(result, Err(Ok(AjoError::GroupNotFound)));
+}
+
+#[test]
+fn test_invalid_templates_rejected() {
+    let (env, client, [creator, _member2]) = setup_test_env();
+
+    let cases = [
+        (GroupTerms { contribution_amount: 0, ..terms() }, AjoError::ContributionAmountZero),
+        (GroupTerms { penalty_rate: 101, ..terms() }, AjoError::InvalidPenaltyRate),
+        (GroupTerms { max_seats_per_member: 5, ..terms() }, AjoError::InvalidGroupTerms),
+        (
+            GroupTerms { commission: OrganizerCommission::Fixed(-1), ..terms() },
+            AjoError::InvalidCommission,
+        ),
+        (
+            GroupTerms { cycle_schedule: CycleSchedule::Calendar(vec![&env, DAY]), ..terms() },
+            AjoError::InvalidCycleSchedule,
+        ),
+    ];
+    for (terms, error) in cases {
+        let template = GroupTemplate { creator: creator.clone(), terms };
+        let result = client.try_save_template(&template, &None);
+        assert_eq!(result, Err(Ok(error)));
+    }
+
+    let long_name = "A name that is far too long to fit within the fifty character limit";
+    let result = client.try_save_template(&template(&creator), &Some(metadata(&env, long_name)));
+    assert_eq!(result, Err(Ok(AjoError::MetadataTooLong)));
+
+    // A missing template is reported like a missing group
+    let result = client.try_create_group_from_template(&creator, &7u64);
+    assert_eq!(result, Err(Ok(AjoError::GroupNotFound)));
+}
+
+#[test]
+fn test_template_and_clone_give_the_same_terms() {
+    let (env, client, [creator, member2]) = setup_test_env();
+    let group_id = client.create_group_with_terms(&creator, &terms());
+
+    let clone_id = client.clone_group(&member2, &group_id);
+    let template_id = client.save_template(&template(&creator), &None);
+    let from_template = client.create_group_from_template(&member2, &template_id);
+
+    let mut clone = client.get_group(&clone_id);
+    clone.id = from_template;
+    assert_eq!(client.get_group(&from_template), clone);
+    assert_eq!(client.get_template(&template_id).terms, terms());
+    assert_eq!(client.get_group(&from_template).members, vec![&env, member2]);
+}
+
+#[test]
+fn test_clone_group_copies_settings_and_metadata() {
+    let (env, client, [creator, member2]) = setup_test_env();
+    let group_id = client.create_group(&creator, &CONTRIBUTION, &CYCLE, &2u32, &DAY, &5u32);
+    client.set_organizer_commission(&group_id, &OrganizerCommission::BasisPoints(250));
+    client.set_recipients_per_cycle(&group_id, &2u32);
+    let name = metadata(&env, "Original");
+    client.set_group_metadata(&group_id, &name.name, &name.description, &name.rules);
+    client.join_group(&member2, &group_id);
+    client.contribute(&creator, &group_id);
+    client.contribute(&member2, &group_id);
+    env.ledger().with_mut(|li| {
+        li.timestamp += CYCLE + DAY + 1;
+    });
+    client.execute_payout(&group_id);
+    assert!(client.is_complete(&group_id));
+
+    // A completed group can be cloned into a fresh one
+    let clone_id = client.clone_group(&member2, &group_id);
+    let clone = client.get_group(&clone_id);
+    assert_eq!(clone.creator, member2);
+    assert_eq!(clone.members, vec![&env, member2.clone()]);
+    assert_eq!(clone.commission, OrganizerCommission::BasisPoints(250));
+    assert_eq!(clone.recipients_per_cycle, 2);
+    assert_eq!(clone.state, GroupState::Active);
+    assert_eq!(clone.current_cycle, 1);
+    assert_eq!(clone.payout_index, 0);
+    assert_eq!(clone.previous_group_id, None);
+    assert_eq!(client.get_group_metadata(&clone_id), name);
+
+    let result = client.try_clone_group(&creator, &99u64);
+    assert_eq!(result, Err(Ok(AjoError::GroupNotFound)));
+}
@@ -0,0 +1,333 @@
+#![cfg(test)]
+
+mod common;
+
+use soroban_ajo::{
+    AjoContractClient, AjoError, CrankSkippedEvent, Vault, VaultAmountEvent,
+    VaultWithdrawalEvent, YieldPolicy,
+};
+use soroban_sdk::{
+    contract, contractimpl, symbol_short,
+    testutils::{Address as _, Events, Ledger},
+    vec, Address, Env, IntoVal, Val, Vec,
+};
+
+const CONTRIBUTION: i128 = 100_000_000;
+const CYCLE: u64 = 604_800;
+const DAY: u64 = 86400;
+
+/// Vault that holds deposits per owner and lets tests add yield or losses
+#[contract]
+pub struct MockVault;
+
+#[contractimpl]
+impl Vault for MockVault {
+    fn deposit(env: Env, from: Address, amount: i128) {
+        let balance = Self::balance(env.clone(), from.clone());
+        env.storage().persistent().set(&from, &(balance + amount));
+    }
+
+    fn withdraw(env: Env, to: Address, amount: i128) -> i128 {
+        let balance = Self::balance(env.clone(), to.clone());
+        let paid = amount.min(balance);
+        env.storage().persistent().set(&to, &(balance - paid));
+        paid
+    }
+
+    fn balance(env: Env, owner: Address) -> i128 {
+        env.storage().persistent().get(&owner).unwrap_or(0)
+    }
+}
+
+#[contractimpl]
+impl MockVault {
+    /// Changes an owner's balance by `amount`: positive for yield, negative for a loss
+    pub fn accrue(env: Env, owner: Address, amount: i128) {
+        let balance = Self::balance(env.clone(), owner.clone());
+        env.storage().persistent().set(&owner, &(balance + amount));
+    }
+}
+
+/// Vault that rejects every deposit
+mod broken_vault {
+    use soroban_ajo::Vault;
+    use soroban_sdk::{contract, contractimpl, Address, Env};
+
+    #[contract]
+    pub struct BrokenVault;
+
+    #[contractimpl]
+    impl Vault for BrokenVault {
+        fn deposit(_env: Env, _from: Address, _amount: i128) {
+            panic!("deposits are closed");
+        }
+
+        fn withdraw(_env: Env, _to: Address, _amount: i128) -> i128 {
+            0
+        }
+
+        fn balance(_env: Env, _owner: Address) -> i128 {
+            0
+        }
+    }
+}
+
+use broken_vault::BrokenVault;
+
+/// Vault that takes deposits but rejects every withdrawal
+mod frozen_vault {
+    use soroban_ajo::Vault;
+    use soroban_sdk::{contract, contractimpl, Address, Env};
+
+    #[contract]
+    pub struct FrozenVault;
+
+    #[contractimpl]
+    impl Vault for FrozenVault {
+        fn deposit(_env: Env, _from: Address, _amount: i128) {}
+
+        fn withdraw(_env: Env, _to: Address, _amount: i128) -> i128 {
+            panic!("withdrawals are frozen");
+        }
+
+        fn balance(_env: Env, _owner: Address) -> i128 {
+            0
+        }
+    }
+}
+
+use frozen_vault::FrozenVault;
+
+struct TestEnv {
+    env: Env,
+    client: AjoContractClient<'static>,
+    vault: MockVaultClient<'static>,
+    creator: Address,
+    member2: Address,
+}
+
+/// Helper function to create a test environment, contract and mock vault
+fn setup_test_env() -> TestEnv {
+    let (env, client, [creator, member2]) = common::setup_test_env();
+    let vault_id = env.register_contract(None, MockVault);
+    let vault = MockVaultClient::new(&env, &vault_id);
+
+    TestEnv { env, client, vault, creator, member2 }
+}
+
+/// Creates a full 2-member group that deposits into the mock vault
+fn create_group(t: &TestEnv, policy: YieldPolicy) -> u64 {
+    let group_id = t.client.create_group(&t.creator, &CONTRIBUTION, &CYCLE, &2u32, &DAY, &5u32);
+    t.client.set_vault(&group_id, &t.vault.address, &policy);
+    t.client.join_group(&t.member2, &group_id);
+    group_id
+}
+
+/// Helper function to contribute for both members and execute the payout
+fn complete_cycle(t: &TestEnv, group_id: u64, accrued: i128) {
+    t.client.contribute(&t.creator, &group_id);
+    t.client.contribute(&t.member2, &group_id);
+    if accrued != 0 {
+        t.vault.accrue(&t.client.address, &accrued);
+    }
+    t.env.ledger().with_mut(|li| {
+        li.timestamp += CYCLE + DAY + 1;
+    });
+    t.client.execute_payout(&group_id);
+}
+
+#[test]
+fn test_contributions_deposited_into_vault() {
+    let t = setup_test_env();
+    let group_id = create_group(&t, YieldPolicy::Recipient);
+
+    t.client.contribute(&t.creator, &group_id);
+    t.client.contribute_partial(&t.member2, &group_id, &(CONTRIBUTION / 4));
+
+    let position = t.client.get_vault_position(&group_id).unwrap();
+    assert_eq!(position.vault, t.vault.address);
+    assert_eq!(position.principal, CONTRIBUTION + CONTRIBUTION / 4);
+    assert_eq!(t.vault.balance(&t.client.address), CONTRIBUTION + CONTRIBUTION / 4);
+
+    let expected_topics: Vec<Val> = (symbol_short!("vaultin"), group_id).into_val(&t.env);
+    let deposits: std::vec::Vec<i128> = t
+        .env
+        .events()
+        .all()
+        .iter()
+        .filter(|(_, topics, _)| *topics == expected_topics)
+        .map(|(_, _, data)| -> VaultAmountEvent { data.into_val(&t.env) })
+        .filter(|deposit| deposit.cycle == 1)
+        .map(|deposit| deposit.amount)
+        .collect();
+    assert_eq!(deposits, [CONTRIBUTION, CONTRIBUTION / 4]);
+}
+
+#[test]
+fn test_yield_paid_to_recipient() {
+    let t = setup_test_env();
+    let group_id = create_group(&t, YieldPolicy::Recipient);
+
+    complete_cycle(&t, group_id, 10_000_000);
+
+    let record = t.client.get_payout_record(&group_id, &t.creator);
+    assert_eq!(record.amount, 2 * CONTRIBUTION + 10_000_000);
+    assert!(t.client.get_vault_position(&group_id).is_none());
+    assert_eq!(t.vault.balance(&t.client.address), 0);
+
+    let expected_topics: Vec<Val> = (symbol_short!("vaultout"), group_id).into_val(&t.env);
+    let event = t
+        .env
+        .events()
+        .all()
+        .iter()
+        .find(|(_, topics, _)| *topics == expected_topics)
+        .expect("vault withdrawal event emitted");
+    let withdrawal: VaultWithdrawalEvent = event.2.into_val(&t.env);
+    assert_eq!(withdrawal.cycle, 1);
+    assert_eq!((withdrawal.withdrawn, withdrawal.vault_yield), (2 * CONTRIBUTION + 10_000_000, 10_000_000));
+}
+
+#[test]
+fn test_yield_kept_in_group_reserve() {
+    let t = setup_test_env();
+    let group_id = create_group(&t, YieldPolicy::GroupReserve);
+
+    complete_cycle(&t, group_id, 10_000_000);
+    assert_eq!(t.client.get_payout_record(&group_id, &t.creator).amount, 2 * CONTRIBUTION);
+    assert_eq!(t.client.get_group_reserve(&group_id), 10_000_000);
+
+    // Released equally to members when the group completes
+    complete_cycle(&t, group_id, 0);
+    assert_eq!(t.client.get_penalty_earnings(&group_id, &t.creator), 5_000_000);
+    assert_eq!(t.client.get_penalty_earnings(&group_id, &t.member2), 5_000_000);
+}
+
+#[test]
+fn test_vault_loss_reduces_payout() {
+    let t = setup_test_env();
+    let group_id = create_group(&t, YieldPolicy::GroupReserve);
+
+    complete_cycle(&t, group_id, -20_000_000);
+
+    let record = t.client.get_payout_record(&group_id, &t.creator);
+    assert_eq!(record.amount, 2 * CONTRIBUTION - 20_000_000);
+    assert_eq!(t.client.get_group_reserve(&group_id), 0);
+}
+
+#[test]
+fn test_groups_sharing_a_vault_keep_their_own_yield() {
+    let t = setup_test_env();
+    let first = create_group(&t, YieldPolicy::Recipient);
+    let second = create_group(&t, YieldPolicy::Recipient);
+
+    t.client.contribute(&t.creator, &first);
+    t.client.contribute(&t.member2, &first);
+    t.vault.accrue(&t.client.address, &(2 * CONTRIBUTION));
+
+    // The second group joins the vault after the first group's yield accrued
+    t.client.contribute(&t.creator, &second);
+    t.client.contribute(&t.member2, &second);
+
+    t.env.ledger().with_mut(|li| {
+        li.timestamp += CYCLE + DAY + 1;
+    });
+    t.client.execute_payout(&first);
+    t.client.execute_payout(&second);
+
+    assert_eq!(t.client.get_payout_record(&first, &t.creator).amount, 4 * CONTRIBUTION);
+    assert_eq!(t.client.get_payout_record(&second, &t.creator).amount, 2 * CONTRIBUTION);
+    assert_eq!(t.vault.balance(&t.client.address), 0);
+}
+
+#[test]
+fn test_rejected_deposit_keeps_funds_in_contract() {
+    let t = setup_test_env();
+    let broken = t.env.register_contract(None, BrokenVault);
+    let group_id = t.client.create_group(&t.creator, &CONTRIBUTION, &CYCLE, &2u32, &DAY, &5u32);
+    t.client.set_vault(&group_id, &broken, &YieldPolicy::Recipient);
+    t.client.join_group(&t.member2, &group_id);
+
+    t.client.contribute(&t.creator, &group_id);
+    assert!(t.client.get_contribution_detail(&group_id, &1u32, &t.creator).has_paid);
+    assert!(t.client.get_vault_position(&group_id).is_none());
+
+    let expected_topics: Vec<Val> = (symbol_short!("vaultfail"), group_id).into_val(&t.env);
+    let event = t
+        .env
+        .events()
+        .all()
+        .iter()
+        .find(|(_, topics, _)| *topics == expected_topics)
+        .expect("failed deposit event emitted");
+    let failed: VaultAmountEvent = event.2.into_val(&t.env);
+    assert_eq!(failed.cycle, 1);
+
+    t.client.contribute(&t.member2, &group_id);
+    t.env.ledger().with_mut(|li| {
+        li.timestamp += CYCLE + DAY + 1;
+    });
+    t.client.execute_payout(&group_id);
+    assert_eq!(t.client.get_payout_record(&group_id, &t.creator).amount, 2 * CONTRIBUTION);
+}
+
+#[test]
+fn test_crank_many_skips_group_whose_vault_fails() {
+    let t = setup_test_env();
+    let frozen = t.env.register_contract(None, FrozenVault);
+    let stuck = t.client.create_group(&t.creator, &CONTRIBUTION, &CYCLE, &2u32, &DAY, &5u32);
+    t.client.set_vault(&stuck, &frozen, &YieldPolicy::Recipient);
+    t.client.join_group(&t.member2, &stuck);
+    let before = create_group(&t, YieldPolicy::Recipient);
+    let after = create_group(&t, YieldPolicy::Recipient);
+
+    for group_id in [before, stuck, after] {
+        t.client.contribute(&t.creator, &group_id);
+        t.client.contribute(&t.member2, &group_id);
+    }
+    t.env.ledger().with_mut(|li| {
+        li.timestamp += CYCLE + DAY + 1;
+    });
+
+    // The frozen vault fails its payout mid-batch without undoing the others
+    let keeper = Address::generate(&t.env);
+    let cranked = t.client.crank_many(&keeper, &vec![&t.env, before, stuck, after]);
+    assert_eq!(cranked, vec![&t.env, before, after]);
+
+    let skipped: Vec<Val> = (symbol_short!("skipped"), stuck).into_val(&t.env);
+    let event = t
+        .env
+        .events()
+        .all()
+        .iter()
+        .find(|(_, topics, _)| *topics == skipped)
+        .expect("skip event emitted");
+    let skip: CrankSkippedEvent = event.2.into_val(&t.env);
+    assert_eq!(skip.reason, AjoError::TransferFailed as u32);
+
+    assert_eq!(t.client.get_group(&before).current_cycle, 2);
+    assert_eq!(t.client.get_group(&stuck).current_cycle, 1);
+    assert_eq!(t.client.get_group(&after).current_cycle, 2);
+}
+
+#[test]
+fn test_cancel_withdraws_from_vault() {
+    let t = setup_test_env();
+    let group_id = create_group(&t, YieldPolicy::Recipient);
+
+    t.client.contribute(&t.member2, &group_id);
+    t.client.cancel_group(&t.creator, &group_id);
+
+    assert!(t.client.get_vault_position(&group_id).is_none());
+    assert_eq!(t.vault.balance(&t.client.address), 0);
+    assert_eq!(t.client.get_refund_record(&group_id, &t.member2).amount, CONTRIBUTION);
+}
+
+#[test]
+fn test_vault_locked_after_join() {
+    let t = setup_test_env();
+    let group_id = create_group(&t, YieldPolicy::Recipient);
+
+    let result = t.client.try_set_vault(&group_id, &t.vault.address, &YieldPolicy::GroupReserve);
+    assert_eq!(result, Err(Ok(AjoError::GroupTermsLocked)));
+}
@@ -181,7 +181,7 @@ main() {
         fi
     fi
     
-    WASM_PATH="target/wasm32-unknown-unknown/release/soroban_ajo.wasm"
+    WASM_PATH="../target/wasm32-unknown-unknown/release/soroban_ajo.wasm"
     
     if [ ! -f "$WASM_PATH" ]; then
         print_error "Build succeeded but WASM file not found at $WASM_PATH"